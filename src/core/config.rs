@@ -1,9 +1,11 @@
 use crate::core::types::*;
 use crate::core::decisions::*;
+use crate::core::state::{ActiveIncident, EventType, GameState, EventVisibility, IncidentResponseStatus, IncidentTimelineEntry};
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Deserialize)]
 pub struct TomlRoot {
@@ -12,6 +14,8 @@ pub struct TomlRoot {
 
 #[derive(Debug, Deserialize)]
 pub struct DecisionConfig {
+    #[serde(default)]
+    pub id: Option<String>,  // Defaults to `turn_<n>` - give one to put several decisions on a turn
     pub turn: u32,
     pub title: String,
     pub context: String,
@@ -21,9 +25,18 @@ pub struct DecisionConfig {
     pub is_time_sensitive: bool,
     #[serde(default)]
     pub decision_category: Option<String>,
+    #[serde(default)]
+    pub prerequisites: Vec<String>,  // Decision ids that must already have been made
     pub choice: Vec<ChoiceConfig>,
 }
 
+impl DecisionConfig {
+    /// The id this decision loads under
+    pub fn decision_id(&self) -> String {
+        self.id.clone().unwrap_or_else(|| format!("turn_{}", self.turn))
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ChoiceConfig {
     pub id: String,
@@ -33,6 +46,8 @@ pub struct ChoiceConfig {
     pub impact: ImpactConfigWrapper,
     #[serde(default)]
     pub prerequisites: Option<PrerequisitesConfig>,
+    #[serde(default)]
+    pub consequence: Vec<ConsequenceConfig>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -40,6 +55,20 @@ pub struct PrerequisitesConfig {
     pub min_budget: Option<f64>,
     pub min_political_capital: Option<f64>,
     pub min_team_capacity: Option<f64>,
+    #[serde(default)]
+    pub required_compliance: Vec<ComplianceFramework>,
+    #[serde(default)]
+    pub blocked_by: Vec<String>,
+}
+
+/// `[[decision.choice.consequence]]` - the bill that arrives on `trigger_turn`
+#[derive(Debug, Deserialize)]
+pub struct ConsequenceConfig {
+    pub trigger_turn: u32,
+    pub event_type: EventType,
+    pub description: String,
+    #[serde(default)]
+    pub impact: Option<ImpactConfigWrapper>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -78,6 +107,53 @@ pub struct ImpactConfigWrapper {
     pub reputation_impact: Option<ReputationDeltaConfig>,
     #[serde(default)]
     pub narrative_impact: Option<NarrativeImpactConfig>,
+    #[serde(default)]
+    pub spawns_incident: Option<IncidentConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IncidentConfig {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub severity: IncidentSeverity,
+    #[serde(default)]
+    pub deadline_turns: Option<u32>,
+    #[serde(default)]
+    pub public_disclosure_required: bool,
+    #[serde(default)]
+    pub customer_impact_count: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IncidentCatalogRoot {
+    #[serde(default)]
+    pub incident: Vec<CatalogIncidentConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CatalogIncidentConfig {
+    #[serde(flatten)]
+    pub incident: IncidentConfig,
+    pub trigger: String,  // Risk vector name, or "TechnicalDebt"
+    pub threshold: f64,
+    #[serde(default)]
+    pub max_coverage: Option<f64>,
+    #[serde(default)]
+    pub after_turn: u32,
+    #[serde(default)]
+    pub every_turns: Option<u32>,
+    #[serde(default)]
+    pub repeatable: bool,
+    #[serde(default)]
+    pub initial_status: Option<IncidentResponseStatus>,  // Detected, Investigating or Containing
+    #[serde(default)]
+    pub root_cause_identified: bool,
+    pub alert: String,
+    #[serde(default)]
+    pub detected_by: Option<String>,
+    #[serde(default)]
+    pub detection: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -124,64 +200,205 @@ pub struct NarrativeImpactConfig {
     pub integrity_penalty: f64,
     pub creates_inconsistency: bool,
     pub reason: String,
+    #[serde(default)]
+    pub buries_incident: Option<BuriedIncidentConfig>,
+    #[serde(default)]
+    pub delays_escalation: Option<DelayedEscalationConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BuriedIncidentConfig {
+    pub incident_id: String,
+    pub actual_severity: IncidentSeverity,
+    pub reported_severity: IncidentSeverity,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DelayedEscalationConfig {
+    pub incident_id: String,
+    pub turns: u32,
+}
+
+/// Why a decision directory that exists couldn't be used - the game falls back to built-in decisions
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoaderProblem {
+    UnreadableDir(PathBuf),
+    UnreadableFile(PathBuf),
+    InvalidToml { path: PathBuf, message: String },
 }
 
+impl fmt::Display for LoaderProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoaderProblem::UnreadableDir(dir) => write!(f, "Couldn't read decision directory {}", dir.display()),
+            LoaderProblem::UnreadableFile(path) => write!(f, "Couldn't read {}", path.display()),
+            LoaderProblem::InvalidToml { path, message } => write!(f, "{} is not valid decision data: {}", path.display(), message),
+        }
+    }
+}
+
+/// Scenario data is the modder's - the detail goes to the screen, not into an opaque error
+impl From<LoaderProblem> for GameError {
+    fn from(problem: LoaderProblem) -> Self {
+        GameError::ContentError(problem.to_string())
+    }
+}
+
+#[derive(Default)]
 pub struct DecisionLoader {
-    pub decisions: HashMap<u32, Decision>,
+    pub decisions: BTreeMap<String, Decision>,
+    turns: BTreeMap<u32, Vec<String>>,  // Load order within a turn breaks ties
 }
 
 impl DecisionLoader {
-    pub fn new() -> Result<Self> {
-        // Try to load from data/decisions directory
-        let data_dir = Path::new("data/decisions");
-        
-        if data_dir.exists() {
-            return Self::load_from_dir(data_dir);
+    /// Load `data/decisions` from the working directory or next to the executable
+    ///
+    /// No directory at all is fine - that's the built-in campaign. A directory that
+    /// can't be read comes back as a `LoaderProblem`; a file that won't parse is
+    /// skipped and handed back alongside everything that did load.
+    pub fn new() -> std::result::Result<(Self, Vec<LoaderProblem>), LoaderProblem> {
+        let mut candidates = vec![PathBuf::from("data/decisions")];
+        if let Some(exe_dir) = std::env::current_exe().ok().as_deref().and_then(Path::parent) {
+            candidates.push(exe_dir.join("data/decisions"));
         }
-        
-        // Try relative to executable
-        if let Ok(exe_path) = std::env::current_exe() {
-            if let Some(exe_dir) = exe_path.parent() {
-                let alt_dir = exe_dir.join("data/decisions");
-                if alt_dir.exists() {
-                    return Self::load_from_dir(&alt_dir);
-                }
+
+        match candidates.iter().find(|dir| dir.exists()) {
+            Some(dir) => Self::load_dir(dir),
+            None => Ok((Self::fallback(), Vec::new())),
+        }
+    }
+
+    /// Empty loader - every turn falls through to `DecisionFactory`
+    pub fn fallback() -> Self {
+        Self::default()
+    }
+
+    /// A loader holding exactly these decisions, in this order
+    pub fn from_decisions(decisions: impl IntoIterator<Item = Decision>) -> Self {
+        let mut loader = Self::fallback();
+        for decision in decisions {
+            loader.insert(decision);
+        }
+        loader
+    }
+
+    /// Add a decision - a later one with the same id replaces the earlier, wherever it was scheduled
+    pub fn insert(&mut self, decision: Decision) {
+        if let Some(previous) = self.decisions.get(&decision.id)
+            && let Some(ids) = self.turns.get_mut(&previous.turn)
+        {
+            ids.retain(|id| id != &decision.id);
+        }
+        self.turns.entry(decision.turn).or_default().push(decision.id.clone());
+        self.decisions.insert(decision.id.clone(), decision);
+    }
+
+    /// Load one directory, all or nothing - the first broken file is the `LoaderProblem`
+    pub fn from_dir(dir: &Path) -> std::result::Result<Self, LoaderProblem> {
+        let (loader, skipped) = Self::load_dir(dir)?;
+        match skipped.into_iter().next() {
+            Some(problem) => Err(problem),
+            None => Ok(loader),
+        }
+    }
+
+    /// Load every file in `dir` that parses - the ones that don't come back as problems, in file order
+    pub fn load_dir(dir: &Path) -> std::result::Result<(Self, Vec<LoaderProblem>), LoaderProblem> {
+        if !dir.exists() {
+            return Ok((Self::fallback(), Vec::new()));
+        }
+
+        let mut loader = Self::fallback();
+        let mut skipped = Vec::new();
+
+        for path in Self::toml_files(dir)? {
+            match Self::load_file(&path) {
+                Ok(loaded) => loaded.into_iter().for_each(|d| loader.insert(d)),
+                Err(problem) => skipped.push(problem),
             }
         }
-        
-        // Return empty loader (will fall back to DecisionFactory)
-        Ok(Self { 
-            decisions: HashMap::new() 
-        })
+
+        Ok((loader, skipped))
     }
-    
-    fn load_from_dir(dir: &Path) -> Result<Self> {
-        let mut decisions: HashMap<u32, Decision> = HashMap::new();
-        
-        let entries = fs::read_dir(dir).map_err(|_| GameError::SystemFailure)?;
-        
-        for entry in entries {
-            let entry = entry.map_err(|_| GameError::SystemFailure)?;
-            let path = entry.path();
-            
+
+    /// Decision files in `dir`, sorted so later files override earlier ones predictably
+    fn toml_files(dir: &Path) -> std::result::Result<Vec<PathBuf>, LoaderProblem> {
+        let unreadable = |_| LoaderProblem::UnreadableDir(dir.to_path_buf());
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir).map_err(unreadable)? {
+            let path = entry.map_err(unreadable)?.path();
             if path.extension().and_then(|s| s.to_str()) == Some("toml") {
-                let content = fs::read_to_string(&path)
-                    .map_err(|_| GameError::SystemFailure)?;
-                
-                let root: TomlRoot = toml::from_str(&content)
-                    .map_err(|_| GameError::StateCorruption)?;
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// Every decision in one file, or why the file can't be used
+    fn load_file(path: &Path) -> std::result::Result<Vec<Decision>, LoaderProblem> {
+        Self::parse_toml(path)?
+            .decision
+            .into_iter()
+            .map(|config| {
+                Self::convert_decision(config)
+                    .map_err(|e| LoaderProblem::InvalidToml { path: path.to_path_buf(), message: e.to_string() })
+            })
+            .collect()
+    }
+
+    /// Lint every decision file in `dir` - catches vectors the loader would silently drop
+    pub fn validate_dir(dir: &Path) -> Result<Vec<DecisionWarning>> {
+        let mut warnings = Vec::new();
+        
+        for path in Self::toml_files(dir)? {
+            for decision_config in Self::parse_toml(&path)?.decision {
+                let decision_id = decision_config.decision_id();
                 
-                for decision_config in root.decision {
-                    let decision = Self::convert_decision(decision_config)?;
-                    decisions.insert(decision.turn, decision);
+                for choice in &decision_config.choice {
+                    let names = choice.impact.risk_delta.as_ref()
+                        .and_then(|r| r.changes.as_ref())
+                        .into_iter()
+                        .flat_map(|changes| changes.keys());
+                    
+                    for name in names {
+                        if RiskVector::from_name(name).is_none() {
+                            warnings.push(DecisionWarning {
+                                decision_id: decision_id.clone(),
+                                choice_id: choice.id.clone(),
+                                kind: DecisionWarningKind::UnknownRiskVector(name.clone()),
+                            });
+                        }
+                    }
                 }
+                
+                warnings.extend(Self::convert_decision(decision_config)?.validate());
             }
         }
         
-        Ok(Self { decisions })
+        Ok(warnings)
+    }
+    
+    /// Run `Decision::validate` over everything loaded, in turn order
+    pub fn validate(&self) -> Vec<DecisionWarning> {
+        self.turns.values()
+            .flatten()
+            .flat_map(|id| self.decisions[id].validate())
+            .collect()
+    }
+    
+    fn parse_toml(path: &Path) -> std::result::Result<TomlRoot, LoaderProblem> {
+        let content = fs::read_to_string(path)
+            .map_err(|_| LoaderProblem::UnreadableFile(path.to_path_buf()))?;
+
+        toml::from_str(&content).map_err(|e| LoaderProblem::InvalidToml {
+            path: path.to_path_buf(),
+            message: e.message().to_string(),
+        })
     }
     
     fn convert_decision(config: DecisionConfig) -> Result<Decision> {
+        let id = config.decision_id();
         let choices = config.choice.into_iter()
             .map(Self::convert_choice)
             .collect();
@@ -202,7 +419,7 @@ impl DecisionLoader {
             .unwrap_or(DecisionCategory::StrategicDirection);
         
         Ok(Decision {
-            id: format!("turn_{}", config.turn),
+            id,
             turn: config.turn,
             title: config.title,
             context: config.context,
@@ -210,7 +427,7 @@ impl DecisionLoader {
             is_board_pressure: config.is_board_pressure,
             is_time_sensitive: config.is_time_sensitive,
             decision_category,
-            prerequisites: Vec::new(),
+            prerequisites: config.prerequisites,
         })
     }
     
@@ -231,11 +448,20 @@ impl DecisionLoader {
                 min_budget: prereq_config.min_budget.unwrap_or(0.0),
                 min_political_capital: prereq_config.min_political_capital.unwrap_or(0.0),
                 min_team_capacity: prereq_config.min_team_capacity.unwrap_or(0.0),
-                required_compliance: Vec::new(),
-                blocked_by: Vec::new(),
+                required_compliance: prereq_config.required_compliance,
+                blocked_by: prereq_config.blocked_by,
             })
             .unwrap_or_default();
 
+        let consequences = config.consequence.into_iter()
+            .map(|consequence| DelayedConsequence {
+                trigger_turn: consequence.trigger_turn,
+                event_type: consequence.event_type,
+                description: consequence.description,
+                additional_impact: consequence.impact.map(|impact| Self::convert_impact(&config.id, impact)),
+            })
+            .collect();
+
         Choice {
             id: config.id.clone(),
             label: config.label,
@@ -247,15 +473,16 @@ impl DecisionLoader {
                 political_note: config.impact_preview.political_note,
                 risk_indicator,
                 compliance_impact: ComplianceImpact {
-                    framework_progress: HashMap::new(),
+                    framework_progress: BTreeMap::new(),
                     new_findings: Vec::new(),
                     resolved_findings: Vec::new(),
+                    accepted_findings: Vec::new(),
                 },
                 team_impact: config.impact_preview.team_impact.unwrap_or_default(),
             },
             impact_data: Some(Self::convert_impact(&config.id, config.impact)),
             prerequisites,
-            consequences: Vec::new(),
+            consequences,
         }
     }
     
@@ -268,17 +495,7 @@ impl DecisionLoader {
             
             if let Some(changes) = risk_delta_config.changes {
                 for (vector_name, change) in changes {
-                    let vector = match vector_name.as_str() {
-                        "DataExposure" => RiskVector::DataExposure,
-                        "AccessControl" => RiskVector::AccessControl,
-                        "Detection" => RiskVector::Detection,
-                        "VendorRisk" => RiskVector::VendorRisk,
-                        "InsiderThreat" => RiskVector::InsiderThreat,
-                        "SupplyChain" => RiskVector::SupplyChain,
-                        "CloudMisconfiguration" => RiskVector::CloudMisconfiguration,
-                        "APIAbuse" => RiskVector::APIAbuse,
-                        _ => continue,
-                    };
+                    let Some(vector) = RiskVector::from_name(&vector_name) else { continue };
                     
                     risk_delta.add_change(
                         vector,
@@ -346,16 +563,186 @@ impl DecisionLoader {
             impact.narrative_impact = Some(NarrativeImpact {
                 integrity_penalty: narrative_config.integrity_penalty,
                 creates_inconsistency: narrative_config.creates_inconsistency,
-                buries_incident: None,
-                delays_escalation: None,
+                buries_incident: narrative_config.buries_incident
+                    .map(|b| (b.incident_id, b.actual_severity, b.reported_severity)),
+                delays_escalation: narrative_config.delays_escalation.map(|d| (d.incident_id, d.turns)),
                 reason: narrative_config.reason,
             });
         }
         
+        impact.spawns_incident = config.spawns_incident.map(Self::convert_incident);
+        
         impact
     }
     
-    pub fn get_decision(&self, turn: u32) -> Option<&Decision> {
-        self.decisions.get(&turn)
+    fn convert_incident(config: IncidentConfig) -> ActiveIncident {
+        ActiveIncident {
+            id: config.id,
+            title: config.title,
+            description: config.description,
+            severity: config.severity,
+            turn_detected: 0,  // Stamped when the incident spawns
+            turn_deadline: config.deadline_turns,
+            escalated_to_board: false,
+            escalation_turn: None,
+            response_status: IncidentResponseStatus::Detected,
+            assigned_team: Vec::new(),
+            capacity_consumed: 0.0,
+            containment_percent: 0.0,
+            root_cause_identified: false,
+            public_disclosure_required: config.public_disclosure_required,
+            customer_impact_count: config.customer_impact_count,
+            timeline: Vec::new(),
+        }
+    }
+    
+    /// The decision this turn offers - of those whose prerequisites `state` meets, the most
+    /// specific (most prerequisites) wins, and load order breaks ties
+    ///
+    /// Files are written against the standard sixteen-turn run: a file's turn and the
+    /// delay before each of its consequences stretch to fit `state.campaign`.
+    pub fn get_decision(&self, turn: u32, state: &GameState) -> Option<Decision> {
+        let campaign = &state.campaign;
+        let chosen = self.turns.iter()
+            .filter(|(standard, _)| campaign.stretch(**standard) == turn)
+            .flat_map(|(_, ids)| ids)
+            .filter_map(|id| self.decisions.get(id))
+            .filter(|d| d.prerequisites_met(state))
+            .rev()
+            .max_by_key(|d| d.prerequisites.len())?;
+
+        let mut decision = chosen.clone();
+        decision.turn = turn;
+        for consequence in decision.choices.iter_mut().flat_map(|c| c.consequences.iter_mut()) {
+            consequence.trigger_turn = turn + campaign.stretch(consequence.trigger_turn.saturating_sub(chosen.turn));
+        }
+        Some(decision)
     }
-}
\ No newline at end of file
+
+    /// Whether any loaded file defines a decision with this id, on whatever turn
+    pub fn defines(&self, decision_id: &str) -> bool {
+        self.decisions.contains_key(decision_id)
+    }
+}
+
+/// Per-file verdict on a decision directory, for tooling - `Err` carries the reason the game would skip it
+pub fn validate_decisions_dir(path: &Path) -> Vec<(PathBuf, std::result::Result<(), String>)> {
+    match DecisionLoader::toml_files(path) {
+        Ok(files) => files.into_iter()
+            .map(|file| {
+                let verdict = DecisionLoader::load_file(&file).map(|_| ()).map_err(|p| p.to_string());
+                (file, verdict)
+            })
+            .collect(),
+        Err(problem) => vec![(path.to_path_buf(), Err(problem.to_string()))],
+    }
+}
+
+/// The catalog that ships with the game - `data/incidents.toml` can replace it
+const BUILTIN_INCIDENTS: &str = include_str!("../../data/incidents.toml");
+
+/// What has to run hot before an incident fires
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IncidentTrigger {
+    Risk(RiskVector),
+    TechnicalDebt,
+}
+
+/// One way risk turns into an incident
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncidentTemplate {
+    pub trigger: IncidentTrigger,
+    pub threshold: f64,
+    pub max_coverage: Option<f64>,  // Only while mitigation is this thin
+    pub after_turn: u32,
+    pub every_turns: Option<u32>,
+    pub repeatable: bool,           // Each firing gets a turn-suffixed id
+    pub alert: String,
+    pub incident: ActiveIncident,   // Deadline is an offset; turns are stamped on spawn
+}
+
+/// Risk-materialization incidents, in the order they're checked
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncidentCatalog {
+    pub incidents: Vec<IncidentTemplate>,
+}
+
+impl Default for IncidentCatalog {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl IncidentCatalog {
+    /// The shipped catalog - covered by a test, so a broken edit fails the build's tests, not a run
+    pub fn builtin() -> Self {
+        Self::from_toml_str(BUILTIN_INCIDENTS, Path::new("data/incidents.toml"))
+            .unwrap_or(Self { incidents: Vec::new() })
+    }
+
+    /// `data/incidents.toml` if a modder dropped one in, otherwise the built-in catalog
+    pub fn load() -> std::result::Result<Self, LoaderProblem> {
+        let path = Path::new("data/incidents.toml");
+        if path.exists() { Self::from_file(path) } else { Ok(Self::builtin()) }
+    }
+
+    pub fn from_file(path: &Path) -> std::result::Result<Self, LoaderProblem> {
+        let content = fs::read_to_string(path)
+            .map_err(|_| LoaderProblem::UnreadableFile(path.to_path_buf()))?;
+        Self::from_toml_str(&content, path)
+    }
+
+    /// Parse a catalog - `path` is only used to say where a problem came from
+    pub fn from_toml_str(content: &str, path: &Path) -> std::result::Result<Self, LoaderProblem> {
+        let invalid = |message: String| LoaderProblem::InvalidToml { path: path.to_path_buf(), message };
+
+        let root: IncidentCatalogRoot = toml::from_str(content)
+            .map_err(|e| invalid(e.message().to_string()))?;
+
+        let incidents = root.incident.into_iter()
+            .map(|config| {
+                let trigger = match config.trigger.as_str() {
+                    "TechnicalDebt" => IncidentTrigger::TechnicalDebt,
+                    name => IncidentTrigger::Risk(RiskVector::from_name(name).ok_or_else(|| {
+                        invalid(format!("incident '{}' has unknown trigger '{}'", config.incident.id, name))
+                    })?),
+                };
+                if let Some(status) = config.initial_status
+                    && !matches!(status, IncidentResponseStatus::Detected
+                        | IncidentResponseStatus::Investigating
+                        | IncidentResponseStatus::Containing)
+                {
+                    return Err(invalid(format!("incident '{}' can't start out {:?}", config.incident.id, status)));
+                }
+                Ok(Self::convert_template(config, trigger))
+            })
+            .collect::<std::result::Result<Vec<_>, LoaderProblem>>()?;
+
+        Ok(Self { incidents })
+    }
+
+    fn convert_template(config: CatalogIncidentConfig, trigger: IncidentTrigger) -> IncidentTemplate {
+        let mut incident = DecisionLoader::convert_incident(config.incident);
+        incident.root_cause_identified = config.root_cause_identified;
+        incident.response_status = config.initial_status.unwrap_or(IncidentResponseStatus::Detected);
+        if let (Some(actor), Some(action)) = (config.detected_by, config.detection) {
+            incident.timeline.push(IncidentTimelineEntry {
+                turn: 0,
+                action,
+                actor,
+                visibility: EventVisibility::Internal,
+            });
+        }
+
+        IncidentTemplate {
+            trigger,
+            threshold: config.threshold,
+            max_coverage: config.max_coverage,
+            after_turn: config.after_turn,
+            every_turns: config.every_turns,
+            repeatable: config.repeatable,
+            alert: config.alert,
+            incident,
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use crate::core::types::*;
+use crate::core::state::*;
+
+/// Sub-structs the console can dump
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateSection {
+    Player,
+    Risk,
+    Business,
+    Narrative,
+    Budget,
+    PoliticalCapital,
+    Team,
+    Compliance,
+    Threats,
+    Board,
+    Incidents,
+    Objectives,
+    TechnicalDebt,
+}
+
+impl StateSection {
+    pub const ALL: [StateSection; 13] = [
+        StateSection::Player,
+        StateSection::Risk,
+        StateSection::Business,
+        StateSection::Narrative,
+        StateSection::Budget,
+        StateSection::PoliticalCapital,
+        StateSection::Team,
+        StateSection::Compliance,
+        StateSection::Threats,
+        StateSection::Board,
+        StateSection::Incidents,
+        StateSection::Objectives,
+        StateSection::TechnicalDebt,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            StateSection::Player => "player",
+            StateSection::Risk => "risk",
+            StateSection::Business => "business",
+            StateSection::Narrative => "narrative",
+            StateSection::Budget => "budget",
+            StateSection::PoliticalCapital => "capital",
+            StateSection::Team => "team",
+            StateSection::Compliance => "compliance",
+            StateSection::Threats => "threats",
+            StateSection::Board => "board",
+            StateSection::Incidents => "incidents",
+            StateSection::Objectives => "objectives",
+            StateSection::TechnicalDebt => "debt",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// One parsed console line
+#[derive(Debug, Clone, PartialEq)]
+pub enum DebugCommand {
+    Help,
+    Show(StateSection),
+    SetRisk(RiskVector, f64),
+    TriggerIncident { id: String, severity: IncidentSeverity },
+    JumpToTurn(u32),
+}
+
+impl DebugCommand {
+    pub const USAGE: &'static str = "help | show <section> | set-risk <vector> <0-100> | incident <id> [critical|high|medium|low] | turn <n>";
+
+    /// Parse a console line - anything unrecognized is an `InvalidAction`
+    pub fn parse(line: &str) -> Result<Self> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words.as_slice() {
+            ["help"] => Ok(DebugCommand::Help),
+            ["show", section] => StateSection::from_name(section)
+                .map(DebugCommand::Show)
+                .ok_or(GameError::InvalidAction),
+            ["set-risk", vector, value] => {
+                let vector = RiskVector::from_name(vector).ok_or(GameError::InvalidAction)?;
+                let value: f64 = value.parse().map_err(|_| GameError::InvalidAction)?;
+                if !(0.0..=100.0).contains(&value) {
+                    return Err(GameError::InvalidAction);
+                }
+                Ok(DebugCommand::SetRisk(vector, value))
+            }
+            ["incident", id] => Ok(DebugCommand::TriggerIncident {
+                id: id.to_string(),
+                severity: IncidentSeverity::High,
+            }),
+            ["incident", id, severity] => Ok(DebugCommand::TriggerIncident {
+                id: id.to_string(),
+                severity: parse_severity(severity).ok_or(GameError::InvalidAction)?,
+            }),
+            ["turn", turn] => turn.parse()
+                .ok()
+                .filter(|t| *t > 0)
+                .map(DebugCommand::JumpToTurn)
+                .ok_or(GameError::InvalidAction),
+            _ => Err(GameError::InvalidAction),
+        }
+    }
+}
+
+fn parse_severity(name: &str) -> Option<IncidentSeverity> {
+    match name.to_ascii_lowercase().as_str() {
+        "critical" => Some(IncidentSeverity::Critical),
+        "high" => Some(IncidentSeverity::High),
+        "medium" => Some(IncidentSeverity::Medium),
+        "low" => Some(IncidentSeverity::Low),
+        _ => None,
+    }
+}
+
+impl GameState {
+    /// Dump one sub-struct for inspection
+    pub fn debug_section(&self, section: StateSection) -> String {
+        match section {
+            StateSection::Player => format!("{:#?}", self.player),
+            StateSection::Risk => format!("{:#?}", self.risk),
+            StateSection::Business => format!("{:#?}", self.business),
+            StateSection::Narrative => format!("{:#?}", self.narrative),
+            StateSection::Budget => format!("{:#?}", self.budget),
+            StateSection::PoliticalCapital => format!("{:#?}", self.political_capital),
+            StateSection::Team => format!("{:#?}", self.team),
+            StateSection::Compliance => format!("{:#?}", self.compliance),
+            StateSection::Threats => format!("{:#?}", self.threat_landscape),
+            StateSection::Board => format!("{:#?}", self.board),
+            StateSection::Incidents => format!("{:#?}", self.active_incidents),
+            StateSection::Objectives => format!("{:#?}", self.quarterly_objectives),
+            StateSection::TechnicalDebt => format!("{:#?}", self.technical_debt),
+        }
+    }
+
+    /// Run a console command - every mutation is logged so a tampered run is obvious
+    pub fn run_debug_command(&mut self, command: &DebugCommand) -> Result<String> {
+        let output = match command {
+            DebugCommand::Help => {
+                let sections: Vec<_> = StateSection::ALL.iter().map(|s| s.name()).collect();
+                return Ok(format!("{}\nsections: {}", DebugCommand::USAGE, sections.join(", ")));
+            }
+            DebugCommand::Show(section) => return Ok(self.debug_section(*section)),
+            DebugCommand::SetRisk(vector, value) => {
+                let metric = self.risk.vectors.get_mut(vector).ok_or(GameError::StateCorruption)?;
+                metric.current_level = *value;
+                self.risk.calculate_cascade_effects();
+                format!("{:?} forced to {:.1}", vector, value)
+            }
+            DebugCommand::TriggerIncident { id, severity } => {
+                if self.active_incidents.iter().any(|i| &i.id == id) {
+                    return Err(GameError::InvalidAction);
+                }
+                self.trigger_incident(ActiveIncident {
+                    id: id.clone(),
+                    title: format!("Debug incident: {}", id),
+                    description: "Injected from the debug console.".to_string(),
+                    severity: *severity,
+                    turn_detected: self.turn,
+                    turn_deadline: Some(self.turn + 2),
+                    escalated_to_board: false,
+                    escalation_turn: None,
+                    response_status: IncidentResponseStatus::Detected,
+                    assigned_team: Vec::new(),
+                    capacity_consumed: 0.0,
+                    containment_percent: 0.0,
+                    root_cause_identified: false,
+                    public_disclosure_required: false,
+                    customer_impact_count: None,
+                    timeline: Vec::new(),
+                });
+                format!("Incident '{}' triggered at {:?}", id, severity)
+            }
+            DebugCommand::JumpToTurn(turn) => {
+                self.turn = *turn;
+                self.quarter = self.campaign.quarter_for(*turn);
+                self.update_phase();
+                format!("Jumped to turn {} (Q{})", turn, self.quarter)
+            }
+        };
+
+        self.add_event(
+            EventType::DebugOverride,
+            format!("Debug console: {}", output),
+            None,
+            EventVisibility::Internal,
+        );
+        if let Some(event) = self.events.last_mut() {
+            event.metadata.insert("debug_command".to_string(), format!("{:?}", command));
+        }
+
+        Ok(output)
+    }
+}
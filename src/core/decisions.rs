@@ -1,6 +1,8 @@
 use crate::core::types::*;
 use crate::core::state::*;
-use std::collections::HashMap;
+use crate::core::config::DecisionLoader;
+use std::collections::BTreeMap;
+use std::time::Duration;
 use serde::{Deserialize, Serialize};
 
 /// A decision point in the game - where careers are made or broken
@@ -29,6 +31,32 @@ pub enum DecisionCategory {
     PoliticalNavigation,
 }
 
+impl DecisionCategory {
+    /// How hard a board with this priority pushes this kind of call onto the agenda
+    pub fn board_weight(&self, priority: BoardPriority) -> f64 {
+        use DecisionCategory::*;
+        match (priority, self) {
+            // Ship first, secure later - velocity-vs-security tradeoffs everywhere
+            (BoardPriority::GrowthAtAllCosts, StrategicDirection) => 2.0,
+            (BoardPriority::GrowthAtAllCosts, VendorSelection | RiskAcceptance) => 1.5,
+            (BoardPriority::GrowthAtAllCosts, ComplianceApproach) => 0.5,
+            (BoardPriority::CostReduction, BudgetAllocation) => 2.0,
+            (BoardPriority::CostReduction, VendorSelection) => 1.5,
+            (BoardPriority::RiskMitigation, IncidentResponse) => 2.0,
+            (BoardPriority::RiskMitigation, RiskAcceptance | PoliticalNavigation) => 1.5,
+            (BoardPriority::ComplianceFirst, ComplianceApproach) => 2.0,
+            (BoardPriority::ComplianceFirst, RiskAcceptance) => 1.5,
+            (BoardPriority::CustomerTrust, IncidentResponse) => 2.0,
+            (BoardPriority::CustomerTrust, PoliticalNavigation) => 1.5,
+            // Pre-IPO, everything is a future S-1 disclosure
+            (BoardPriority::IpoPreparation, ComplianceApproach | PoliticalNavigation) => 2.0,
+            (BoardPriority::IpoPreparation, RiskAcceptance) => 1.5,
+            (BoardPriority::IpoPreparation, StrategicDirection) => 0.5,
+            _ => 1.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Choice {
     pub id: String,
@@ -61,7 +89,153 @@ impl Default for ChoicePrerequisites {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Whether a choice can be picked right now
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChoiceAvailability {
+    Available,
+    Unavailable(Vec<UnmetPrerequisite>),
+}
+
+impl ChoiceAvailability {
+    pub fn is_available(&self) -> bool {
+        matches!(self, ChoiceAvailability::Available)
+    }
+}
+
+/// One reason a choice is off the table
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnmetPrerequisite {
+    Budget { required: f64, available: f64 },
+    CategoryBudget { category: BudgetCategory, required: f64, available: f64 },  // The choice's own bill
+    PoliticalCapital { required: f64, available: f64 },
+    TeamCapacity { required: f64, available: f64 },
+    BlockedBy(String),                       // An earlier decision closed this door
+    MissingCertification(ComplianceFramework),
+    FindingNotOpen(String),                  // Already closed out, or never raised
+    IncidentNotOpen(String),                 // Resolved since the decision came up
+}
+
+impl std::fmt::Display for UnmetPrerequisite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnmetPrerequisite::Budget { required, available } =>
+                write!(f, "needs ${:.2}M budget (${:.2}M available)", required, available),
+            UnmetPrerequisite::CategoryBudget { category, required, available } =>
+                write!(f, "costs ${:.2}M from {:?} (${:.2}M left there)", required, category, available),
+            UnmetPrerequisite::PoliticalCapital { required, available } =>
+                write!(f, "needs {:.0} political capital ({:.0} available)", required, available),
+            UnmetPrerequisite::TeamCapacity { required, available } =>
+                write!(f, "needs {:.0} team capacity ({:.0} available)", required, available),
+            UnmetPrerequisite::BlockedBy(decision_id) =>
+                write!(f, "ruled out by an earlier decision ({})", decision_id),
+            UnmetPrerequisite::MissingCertification(framework) =>
+                write!(f, "requires {:?} certification", framework),
+            UnmetPrerequisite::FindingNotOpen(finding_id) =>
+                write!(f, "finding {} is no longer open", finding_id),
+            UnmetPrerequisite::IncidentNotOpen(incident_id) =>
+                write!(f, "incident {} is no longer open", incident_id),
+        }
+    }
+}
+
+impl UnmetPrerequisite {
+    /// The error `apply_choice` answers with when this is what stands in the way
+    pub fn error(&self) -> GameError {
+        match self {
+            UnmetPrerequisite::Budget { .. } | UnmetPrerequisite::CategoryBudget { .. } => GameError::InsufficientBudget,
+            UnmetPrerequisite::PoliticalCapital { .. } => GameError::InsufficientPoliticalCapital,
+            UnmetPrerequisite::TeamCapacity { .. } => GameError::TeamCapacityExceeded,
+            UnmetPrerequisite::BlockedBy(_)
+            | UnmetPrerequisite::FindingNotOpen(_)
+            | UnmetPrerequisite::IncidentNotOpen(_) => GameError::InvalidAction,
+            UnmetPrerequisite::MissingCertification(_) => GameError::ComplianceViolation,
+        }
+    }
+}
+
+impl Choice {
+    /// Whether the current state lets the player pick this at all
+    pub fn is_available(&self, state: &GameState) -> bool {
+        self.unmet(state).is_empty()
+    }
+
+    /// The stated prerequisites, plus anything `apply_choice` would trip over halfway through
+    pub fn unmet(&self, state: &GameState) -> Vec<UnmetPrerequisite> {
+        let mut unmet = self.prerequisites.unmet(state);
+        let Some(impact) = &self.impact_data else {
+            return unmet;
+        };
+
+        let (cost, category) = (impact.budget_cost, impact.budget_category);
+        if cost > 0.0 && !state.budget.can_spend(cost, category) {
+            let available = state.budget.balance(category).min(state.budget.available());
+            unmet.push(UnmetPrerequisite::CategoryBudget { category, required: cost, available });
+        }
+
+        let finding = |id: &String| state.compliance.open_findings.iter().find(|f| f.id == *id);
+        let compliance = &impact.compliance_impact;
+        for id in &compliance.accepted_findings {
+            if !finding(id).is_some_and(|f| matches!(f.status, FindingStatus::Open | FindingStatus::InProgress)) {
+                unmet.push(UnmetPrerequisite::FindingNotOpen(id.clone()));
+            }
+        }
+        for id in &compliance.resolved_findings {
+            if !finding(id).is_some_and(|f| f.status != FindingStatus::Resolved) {
+                unmet.push(UnmetPrerequisite::FindingNotOpen(id.clone()));
+            }
+        }
+
+        if let Some((incident_id, _)) = &impact.works_incident {
+            let open = state.active_incidents.iter()
+                .any(|i| i.id == *incident_id && i.response_status != IncidentResponseStatus::Closed);
+            if !open {
+                unmet.push(UnmetPrerequisite::IncidentNotOpen(incident_id.clone()));
+            }
+        }
+
+        unmet
+    }
+}
+
+impl ChoicePrerequisites {
+    /// Every prerequisite the current state fails
+    pub fn unmet(&self, state: &GameState) -> Vec<UnmetPrerequisite> {
+        let mut unmet = Vec::new();
+
+        let budget = state.budget.available();
+        if self.min_budget > 0.0 && budget < self.min_budget {
+            unmet.push(UnmetPrerequisite::Budget { required: self.min_budget, available: budget });
+        }
+
+        let capital = state.political_capital.total;
+        if self.min_political_capital > 0.0 && capital < self.min_political_capital {
+            unmet.push(UnmetPrerequisite::PoliticalCapital { required: self.min_political_capital, available: capital });
+        }
+
+        let capacity = state.team.available_capacity();
+        if self.min_team_capacity > 0.0 && capacity < self.min_team_capacity {
+            unmet.push(UnmetPrerequisite::TeamCapacity { required: self.min_team_capacity, available: capacity });
+        }
+
+        for decision_id in &self.blocked_by {
+            if state.decisions_made.contains(decision_id) {
+                unmet.push(UnmetPrerequisite::BlockedBy(decision_id.clone()));
+            }
+        }
+
+        for framework in &self.required_compliance {
+            let certified = state.compliance.frameworks.get(framework)
+                .is_some_and(|status| status.is_certified());
+            if !certified {
+                unmet.push(UnmetPrerequisite::MissingCertification(*framework));
+            }
+        }
+
+        unmet
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DelayedConsequence {
     pub trigger_turn: u32,
     pub event_type: EventType,
@@ -89,7 +263,331 @@ pub enum RiskIndicator {
     Significant,   // Red
 }
 
+/// Team capacity spent to get a straight answer about one option
+pub const CONSULT_CAPACITY_COST: f64 = 2.0;
+
+/// What your team tells you when you ask before committing
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsultationReport {
+    pub risk_indicator: RiskIndicator,
+    pub audit_hint: String,
+}
+
+impl GameState {
+    /// Due diligence - burn some team capacity to learn which way the risk really goes
+    pub fn consult_team(&mut self, choice: &Choice) -> Result<ConsultationReport> {
+        if !self.team.allocate_capacity(CONSULT_CAPACITY_COST) {
+            return Err(GameError::TeamCapacityExceeded);
+        }
+
+        let audit_hint = match choice.impact_data.as_ref().map(|i| i.audit_trail) {
+            Some(AuditTrail::Clean) => "'This one will hold up under scrutiny.'",
+            Some(AuditTrail::Flagged) => "'Legal might have questions about this one.'",
+            Some(AuditTrail::Toxic) => "'If this ever comes out in discovery, we're done.'",
+            None => "'Honestly? Hard to say.'",
+        };
+
+        Ok(ConsultationReport {
+            risk_indicator: choice.impact_preview.risk_indicator,
+            audit_hint: audit_hint.to_string(),
+        })
+    }
+}
+
+impl GameState {
+    /// Predicted satisfaction change per board member if this choice is taken
+    pub fn predict_board_reaction(&self, choice: &Choice) -> Vec<(BoardMemberRole, f64)> {
+        let Some(impact) = &choice.impact_data else {
+            return Vec::new();
+        };
+
+        self.board.iter()
+            .map(|member| (member.role, member.predict_reaction(impact)))
+            .collect()
+    }
+}
+
+/// Env var that turns on the countdown for time-sensitive decisions (seconds)
+pub const TIME_PRESSURE_ENV: &str = "CISO_TIME_PRESSURE_SECS";
+
+/// Board confidence lost when the clock makes the call for you
+const TIMEOUT_CONFIDENCE_PENALTY: f64 = 5.0;
+/// Political capital lost when the clock makes the call for you
+const TIMEOUT_CAPITAL_PENALTY: f64 = 5.0;
+
+/// Countdown for time-sensitive decisions - off unless you ask for it
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TimePressure {
+    pub timeout: Option<Duration>,
+}
+
+impl TimePressure {
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout: Some(timeout) }
+    }
+
+    /// Read the timeout from `CISO_TIME_PRESSURE_SECS`; missing, zero, or garbage disables it
+    pub fn from_env() -> Self {
+        let timeout = std::env::var(TIME_PRESSURE_ENV).ok()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs);
+
+        Self { timeout }
+    }
+
+    /// How long the player gets on this decision, if the clock applies at all
+    pub fn limit_for(&self, decision: &Decision) -> Option<Duration> {
+        if decision.is_time_sensitive {
+            self.timeout
+        } else {
+            None
+        }
+    }
+
+    /// The choice forced on the player once `elapsed` runs past the limit
+    pub fn resolve(&self, decision: &Decision, elapsed: Duration) -> Option<usize> {
+        match self.limit_for(decision) {
+            Some(limit) if elapsed >= limit => decision.default_choice_index(),
+            _ => None,
+        }
+    }
+}
+
+/// Env var that turns off the "are you sure?" step - set to `0` or `false` if you trust your Enter key
+pub const CONFIRM_CHOICES_ENV: &str = "CISO_CONFIRM_CHOICES";
+
+/// One more beat between picking a choice and committing it
+///
+/// The gate is the same for every choice, so it never hints at what's hidden
+/// behind one - it only stops a fat-fingered Enter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChoiceConfirmation {
+    pub enabled: bool,
+}
+
+impl Default for ChoiceConfirmation {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl ChoiceConfirmation {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    /// On unless `CISO_CONFIRM_CHOICES` is `0`, `false`, or `off`
+    pub fn from_env() -> Self {
+        let enabled = std::env::var(CONFIRM_CHOICES_ENV)
+            .map(|v| !matches!(v.trim().to_ascii_lowercase().as_str(), "0" | "false" | "off"))
+            .unwrap_or(true);
+
+        Self { enabled }
+    }
+
+    /// Ask `confirm` about the choice at `index`, then apply it - `None` if the player backed out
+    ///
+    /// Backing out leaves the state untouched.
+    pub fn commit(
+        &self,
+        decision: &mut Decision,
+        index: usize,
+        state: &mut GameState,
+        confirm: impl FnOnce(&Choice) -> Result<bool>,
+    ) -> Result<Option<DecisionImpact>> {
+        let choice = decision.choices.get(index).ok_or(GameError::InvalidAction)?;
+        if self.enabled && !confirm(choice)? {
+            return Ok(None);
+        }
+        decision.apply_choice_by_index(index, state).map(Some)
+    }
+}
+
+impl GameState {
+    /// The clock ran out - the default happened to you, and everyone noticed
+    pub fn record_decision_timeout(&mut self, decision: &Decision, choice_label: &str) {
+        self.business.board_confidence_percent =
+            (self.business.board_confidence_percent - TIMEOUT_CONFIDENCE_PENALTY).max(0.0);
+        self.political_capital.total = (self.political_capital.total - TIMEOUT_CAPITAL_PENALTY).max(0.0);
+
+        self.add_event(
+            EventType::DecisionTimedOut,
+            format!(
+                "No decision on '{}' in time. Defaulted to: {}",
+                decision.title, choice_label
+            ),
+            Some(decision.id.clone()),
+            EventVisibility::Management,
+        );
+    }
+}
+
+/// Compliance a remediated finding gives back to its framework
+const REMEDIATION_COMPLIANCE_GAIN: f64 = 5.0;
+/// Integrity lost for signing off on a Critical finding instead of fixing it
+const CRITICAL_ACCEPTANCE_PENALTY: f64 = 8.0;
+
+/// Risk level past which an under-mitigated vector lands on your desk for a formal call
+pub const RISK_ACCEPTANCE_LEVEL: f64 = 70.0;
+/// Coverage below which nobody can pretend the vector is managed
+pub const RISK_ACCEPTANCE_MAX_COVERAGE: f64 = 30.0;
+/// What funding the mitigation project costs
+const VECTOR_MITIGATION_COST: f64 = 0.12;
+
+/// Operational turns between debt paydown reviews
+pub const DEBT_PAYDOWN_INTERVAL: u32 = 4;
+/// Debt points one targeted paydown clears
+const DEBT_PAYDOWN_DECISION_POINTS: f64 = 20.0;
+/// What a targeted paydown sprint costs
+const DEBT_PAYDOWN_DECISION_COST: f64 = 0.08;
+/// Paydown choices offered - the biggest piles
+const DEBT_PAYDOWN_OPTIONS: usize = 3;
+
+/// ARR at which sales starts pitching the EU market
+pub const EU_EXPANSION_ARR: f64 = 15.0;
+/// ARR at which product wants to take card payments directly
+pub const PAYMENTS_ARR: f64 = 18.0;
+const EU_EXPANSION_ID: &str = "eu_expansion";
+const PAYMENTS_ID: &str = "payments_in_house";
+
+/// Team capacity below which the CTO starts asking about backfills - what you inherited
+pub const HIRING_CAPACITY_FLOOR: f64 = 18.0;
+/// Headcount budget for a mid-level engineer
+const ENGINEER_HIRE_COST: f64 = 0.15;
+/// Headcount budget for a senior architect
+const SENIOR_HIRE_COST: f64 = 0.25;
+/// Who answers the job posting - picked by turn so replays hire the same people
+const CANDIDATE_NAMES: [&str; 6] = ["Priya Natarajan", "Tomas Herrera", "Aiko Tanaka", "Dev Okafor", "Lena Fischer", "Sam Whitlock"];
+
+/// Containment an all-hands push buys
+const FULL_RESPONSE_CONTAINMENT: f64 = 30.0;
+/// Team capacity an all-hands push burns
+const FULL_RESPONSE_CAPACITY: f64 = 10.0;
+/// Containment a skeleton crew manages
+const LEAN_RESPONSE_CONTAINMENT: f64 = 15.0;
+/// Team capacity a skeleton crew burns
+const LEAN_RESPONSE_CAPACITY: f64 = 5.0;
+/// Team capacity the post-mortem write-up takes
+const POSTMORTEM_CAPACITY: f64 = 5.0;
+
+/// Net risk movement a `Neutral` preview may hide before we call it a mismatch
+const NEUTRAL_RISK_TOLERANCE: f64 = 15.0;
+
+/// Something about a choice that looks like an authoring mistake
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecisionWarning {
+    pub decision_id: String,
+    pub choice_id: String,
+    pub kind: DecisionWarningKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecisionWarningKind {
+    MissingImpactData,
+    RiskDirectionMismatch { preview: RiskIndicator, net_risk_change: f64 },
+    ArrSignMismatch { preview: f64, actual: f64 },
+    BudgetCostMismatch { preview: f64, actual: f64 },
+    UnknownRiskVector(String),
+}
+
 impl Decision {
+    /// Whether every decision this one depends on has already been made
+    pub fn prerequisites_met(&self, state: &GameState) -> bool {
+        self.prerequisites.iter().all(|id| state.decisions_made.contains(id))
+    }
+
+    /// Per choice index, whether the current state allows it and what's missing if not
+    pub fn available_choices(&self, state: &GameState) -> Vec<(usize, ChoiceAvailability)> {
+        self.choices.iter()
+            .enumerate()
+            .map(|(i, choice)| {
+                let unmet = choice.unmet(state);
+                let availability = if unmet.is_empty() {
+                    ChoiceAvailability::Available
+                } else {
+                    ChoiceAvailability::Unavailable(unmet)
+                };
+                (i, availability)
+            })
+            .collect()
+    }
+
+    /// Check each choice's preview against its real impact
+    ///
+    /// Some gaps are deliberate deception - these are warnings, not errors.
+    pub fn validate(&self) -> Vec<DecisionWarning> {
+        let mut warnings = Vec::new();
+
+        for choice in &self.choices {
+            let mut warn = |kind| warnings.push(DecisionWarning {
+                decision_id: self.id.clone(),
+                choice_id: choice.id.clone(),
+                kind,
+            });
+
+            let Some(impact) = &choice.impact_data else {
+                warn(DecisionWarningKind::MissingImpactData);
+                continue;
+            };
+            let preview = &choice.impact_preview;
+
+            // Same weighting the board uses: mitigation counts half
+            let net_risk_change: f64 = impact.risk_delta.changes.values()
+                .map(|c| c.level_delta - c.mitigation_delta * 0.5)
+                .sum();
+            let direction_ok = match preview.risk_indicator {
+                RiskIndicator::Reduces => net_risk_change <= 0.0,
+                RiskIndicator::Neutral => net_risk_change.abs() < NEUTRAL_RISK_TOLERANCE,
+                RiskIndicator::Increases | RiskIndicator::Significant => net_risk_change >= 0.0,
+            };
+            if !direction_ok {
+                warn(DecisionWarningKind::RiskDirectionMismatch {
+                    preview: preview.risk_indicator,
+                    net_risk_change,
+                });
+            }
+
+            let actual_arr = impact.business_delta.arr_change;
+            if preview.estimated_arr_change * actual_arr < 0.0 {
+                warn(DecisionWarningKind::ArrSignMismatch {
+                    preview: preview.estimated_arr_change,
+                    actual: actual_arr,
+                });
+            }
+
+            if (preview.budget_cost - impact.budget_cost).abs() > 0.01 {
+                warn(DecisionWarningKind::BudgetCostMismatch {
+                    preview: preview.budget_cost,
+                    actual: impact.budget_cost,
+                });
+            }
+        }
+
+        warnings
+    }
+
+    /// What happens if nobody decides - by convention the last option, the path of least resistance
+    pub fn default_choice_index(&self) -> Option<usize> {
+        self.choices.len().checked_sub(1)
+    }
+
+    /// The default the clock can actually force - the last option `state` can still afford
+    pub fn default_available_choice(&self, state: &GameState) -> Option<usize> {
+        self.available_choices(state).into_iter()
+            .rev()
+            .find(|(_, availability)| *availability == ChoiceAvailability::Available)
+            .map(|(i, _)| i)
+    }
+
+    /// Apply the choice at `index` - for frontends that think in menu positions, not ids
+    pub fn apply_choice_by_index(&mut self, index: usize, state: &mut GameState) -> Result<DecisionImpact> {
+        let choice_id = self.choices.get(index)
+            .map(|c| c.id.clone())
+            .ok_or(GameError::InvalidAction)?;
+        self.apply_choice(&choice_id, state)
+    }
+
     /// Apply a chosen option to the game state, returning the full impact
     pub fn apply_choice(&mut self, choice_id: &str, state: &mut GameState) -> Result<DecisionImpact> {
         // Find the choice
@@ -97,19 +595,9 @@ impl Decision {
             .find(|c| c.id == choice_id)
             .ok_or(GameError::InvalidAction)?;
         
-        // Check prerequisites
-        if choice.prerequisites.min_budget > 0.0 && state.budget.available() < choice.prerequisites.min_budget {
-            return Err(GameError::InsufficientBudget);
-        }
-        
-        if choice.prerequisites.min_political_capital > 0.0 && 
-           state.political_capital.total < choice.prerequisites.min_political_capital {
-            return Err(GameError::InsufficientPoliticalCapital);
-        }
-        
-        if choice.prerequisites.min_team_capacity > 0.0 &&
-           state.team.available_capacity() < choice.prerequisites.min_team_capacity {
-            return Err(GameError::TeamCapacityExceeded);
+        // Check prerequisites - including doors earlier decisions closed
+        if let Some(unmet) = choice.unmet(state).first() {
+            return Err(unmet.error());
         }
         
         // Get the full impact data
@@ -118,6 +606,7 @@ impl Decision {
         
         // Apply the impact to state
         state.risk.apply_delta(&impact.risk_delta);
+        state.refresh_mttd();
         state.business.apply_delta(&impact.business_delta);
         
         // Handle budget
@@ -145,29 +634,50 @@ impl Decision {
         }
         
         // Apply reputation changes
-        state.player.reputation.industry_standing += impact.reputation_impact.industry_delta;
-        state.player.reputation.board_credibility += impact.reputation_impact.board_delta;
-        state.player.reputation.team_morale += impact.reputation_impact.team_delta;
-        state.player.reputation.vendor_relationships += impact.reputation_impact.vendor_delta;
+        let reputation_before = state.player.reputation.clone();
+        let reputation = &mut state.player.reputation;
+        reputation.industry_standing = (reputation.industry_standing + impact.reputation_impact.industry_delta).clamp(0.0, 100.0);
+        reputation.board_credibility = (reputation.board_credibility + impact.reputation_impact.board_delta).clamp(0.0, 100.0);
+        reputation.team_morale = (reputation.team_morale + impact.reputation_impact.team_delta).clamp(0.0, 100.0);
+        reputation.vendor_relationships = (reputation.vendor_relationships + impact.reputation_impact.vendor_delta).clamp(0.0, 100.0);
+        state.record_reputation_changes(&reputation_before, &self.id);
         
-        // Apply compliance impact
+        // Apply compliance impact - new obligations first, so progress toward them lands
+        for framework in &impact.brings_into_scope {
+            state.bring_framework_into_scope(*framework);
+        }
         for (framework, progress) in &impact.compliance_impact.framework_progress {
             if let Some(status) = state.compliance.frameworks.get_mut(framework) {
-                status.compliance_percent += progress;
+                status.add_progress(*progress, impact.audit_trail != AuditTrail::Toxic);
             }
         }
         
+        // Formal risk acceptances, and the findings actually fixed
+        for finding_id in &impact.compliance_impact.accepted_findings {
+            state.accept_finding(finding_id)?;
+        }
+        for finding_id in &impact.compliance_impact.resolved_findings {
+            state.resolve_finding(finding_id)?;
+        }
+        
         // Apply narrative impact
         if let Some(ref narrative_impact) = impact.narrative_impact {
-            // Directly modify narrative integrity score
-            state.narrative.score = (state.narrative.score - narrative_impact.integrity_penalty).max(0.0);
+            // Directly modify narrative integrity score - a recorded inconsistency carries the penalty itself
+            if narrative_impact.creates_inconsistency {
+                state.narrative.record_inconsistency(
+                    state.turn,
+                    narrative_impact.reason.clone(),
+                    narrative_impact.integrity_penalty,
+                );
+            } else {
+                state.narrative.score = (state.narrative.score - narrative_impact.integrity_penalty).clamp(0.0, 100.0);
+            }
             
             if let Some((inc_id, actual_sev, reported_sev)) = &narrative_impact.buries_incident {
-                state.narrative.bury_incident(
+                state.bury_incident(
                     inc_id.clone(),
                     *actual_sev,
                     *reported_sev,
-                    state.turn,
                     narrative_impact.reason.clone(),
                 );
             }
@@ -182,6 +692,33 @@ impl Decision {
             }
         }
         
+        // Immediate consequences
+        if let Some(ref incident) = impact.spawns_incident {
+            state.spawn_incident(incident);
+        }
+
+        // Debt paid down stays paid down
+        for (category, points) in &impact.debt_paydown {
+            state.technical_debt.pay_down(*points, *category);
+        }
+
+        for member in &impact.hires {
+            state.team.hire(member.clone());
+        }
+
+        // Response work lands on the incident it was aimed at
+        if let Some((incident_id, containment)) = &impact.works_incident {
+            state.work_incident_response(incident_id, *containment)?;
+        }
+        
+        // The board reads every call through its own priorities
+        for member in &mut state.board {
+            member.react_to_decision(&impact);
+        }
+
+        // Later consequences wait for their turn
+        state.pending_consequences.extend(choice.consequences.iter().cloned());
+
         // Record the decision
         state.decisions_made.push(self.id.clone());
         state.add_event(
@@ -201,28 +738,52 @@ pub struct DecisionFactory;
 impl DecisionFactory {
     /// Generate decisions based on game state
     /// First tries to load from DecisionLoader (TOML files), then falls back to hardcoded decisions
-    pub fn generate_decision(state: &GameState, loader: &crate::core::config::DecisionLoader) -> Option<Decision> {
+    pub fn generate_decision(state: &GameState, loader: &DecisionLoader) -> Option<Decision> {
         // First, try to get decision from TOML files
-        if let Some(decision) = loader.get_decision(state.turn) {
-            return Some(decision.clone());
+        if let Some(decision) = loader.get_decision(state.turn, state) {
+            return Some(decision);
         }
         
-        // Fall back to hardcoded decisions if TOML not found
-        match state.turn {
-            1 => Some(Self::turn_1_inheritance_decision()),
-            2 => Some(Self::turn_2_triage_decision(state)),
-            3 => Some(Self::turn_3_quick_win_or_foundation()),
-            5 => Self::generate_incident_decision(state),
-            6 => Some(Self::compliance_pressure_decision(state)),
-            8 => Some(Self::budget_battle_decision(state)),
-            10 => Some(Self::team_crisis_decision(state)),
-            12 => Some(Self::vendor_selection_decision()),
-            14 => Self::generate_discovery_decision(state),
+        // Fall back to hardcoded decisions if TOML not found - scheduled
+        // relative to the start of each act so campaigns can stretch or shrink
+        let campaign = &state.campaign;
+        let fallback = match campaign.phase_for(state.turn) {
+            Some(GamePhase::InheritanceDisaster) => match state.turn {
+                1 => Some(Self::turn_1_inheritance_decision(state)),
+                2 => Some(Self::turn_2_triage_decision(state)),
+                3 => Some(Self::turn_3_quick_win_or_foundation()),
+                _ => Self::generate_dynamic_decision(state),
+            },
+            Some(GamePhase::OperationalTempo) => match state.turn - campaign.inheritance_end {
+                2 => Self::generate_incident_decision(state),
+                3 => Some(Self::compliance_pressure_decision(state)),
+                5 => Some(Self::budget_battle_decision(state)),
+                7 => Some(Self::team_crisis_decision(state)),
+                9 => Some(Self::vendor_selection_decision(state)),
+                n if n % DEBT_PAYDOWN_INTERVAL == 0 => Some(Self::debt_paydown_decision(state)),
+                _ => Self::generate_dynamic_decision(state),
+            },
+            Some(GamePhase::Discovery) => match state.turn - campaign.operational_end {
+                2 => Self::generate_discovery_decision(state),
+                _ => Self::generate_dynamic_decision(state),
+            },
             _ => Self::generate_dynamic_decision(state),
+        };
+
+        // Content that schedules the same decision on another turn wins - no reruns
+        match fallback {
+            Some(decision) if loader.defines(&decision.id) => Self::generate_dynamic_decision(state)
+                .filter(|dynamic| !loader.defines(&dynamic.id)),
+            other => other,
         }
     }
 
-    fn turn_1_inheritance_decision() -> Decision {
+    fn turn_1_inheritance_decision(state: &GameState) -> Decision {
+        // The promise comes due when the SOC2 auditor actually shows up
+        let soc2_audit = state.compliance.frameworks.get(&ComplianceFramework::SOC2)
+            .map(|f| f.next_audit)
+            .unwrap_or(state.turn + state.campaign.stretch(7));
+
         Decision {
             id: "turn_1_inheritance".to_string(),
             turn: 1,
@@ -245,9 +806,10 @@ impl DecisionFactory {
                         political_note: Some("Board may question your competence immediately".to_string()),
                         risk_indicator: RiskIndicator::Reduces,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Team appreciates transparency".to_string(),
                     },
@@ -266,9 +828,10 @@ impl DecisionFactory {
                         political_note: Some("Board loves confidence, but...".to_string()),
                         risk_indicator: RiskIndicator::Significant,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Team is already stressed".to_string(),
                     },
@@ -276,7 +839,7 @@ impl DecisionFactory {
                     prerequisites: ChoicePrerequisites::default(),
                     consequences: vec![
                         DelayedConsequence {
-                            trigger_turn: 8,
+                            trigger_turn: soc2_audit,
                             event_type: EventType::ComplianceAudit,
                             description: "SOC2 audit reveals gaps you promised were fixed".to_string(),
                             additional_impact: Some(Self::audit_failure_impact()),
@@ -294,9 +857,10 @@ impl DecisionFactory {
                         political_note: Some("Balanced approach".to_string()),
                         risk_indicator: RiskIndicator::Neutral,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Team uncertain about direction".to_string(),
                     },
@@ -312,7 +876,7 @@ impl DecisionFactory {
         }
     }
 
-    fn turn_2_triage_decision(_state: &GameState) -> Decision {
+    fn turn_2_triage_decision(state: &GameState) -> Decision {
         Decision {
             id: "turn_2_triage".to_string(),
             turn: 2,
@@ -335,12 +899,13 @@ impl DecisionFactory {
                         risk_indicator: RiskIndicator::Reduces,
                         compliance_impact: ComplianceImpact {
                             framework_progress: {
-                                let mut progress = HashMap::new();
+                                let mut progress = BTreeMap::new();
                                 progress.insert(ComplianceFramework::SOC2, 15.0);
                                 progress
                             },
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Clear, achievable goal".to_string(),
                     },
@@ -364,12 +929,13 @@ impl DecisionFactory {
                         risk_indicator: RiskIndicator::Reduces,
                         compliance_impact: ComplianceImpact {
                             framework_progress: {
-                                let mut progress = HashMap::new();
+                                let mut progress = BTreeMap::new();
                                 progress.insert(ComplianceFramework::SOC2, 10.0);
                                 progress
                             },
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Stressful but necessary".to_string(),
                     },
@@ -393,12 +959,13 @@ impl DecisionFactory {
                         risk_indicator: RiskIndicator::Increases,
                         compliance_impact: ComplianceImpact {
                             framework_progress: {
-                                let mut progress = HashMap::new();
+                                let mut progress = BTreeMap::new();
                                 progress.insert(ComplianceFramework::SOC2, 30.0);
                                 progress
                             },
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Death by documentation".to_string(),
                     },
@@ -410,7 +977,7 @@ impl DecisionFactory {
                     },
                     consequences: vec![
                         DelayedConsequence {
-                            trigger_turn: 5,
+                            trigger_turn: state.turn + state.campaign.stretch(3),
                             event_type: EventType::RiskMaterialized,
                             description: "Unpatched vulnerability exploited during documentation sprint".to_string(),
                             additional_impact: Some(Self::deferred_risk_impact()),
@@ -444,9 +1011,10 @@ impl DecisionFactory {
                         political_note: Some("Board loves dashboards".to_string()),
                         risk_indicator: RiskIndicator::Neutral,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Team morale decreases".to_string(),
                     },
@@ -470,12 +1038,13 @@ impl DecisionFactory {
                         risk_indicator: RiskIndicator::Reduces,
                         compliance_impact: ComplianceImpact {
                             framework_progress: {
-                                let mut progress = HashMap::new();
+                                let mut progress = BTreeMap::new();
                                 progress.insert(ComplianceFramework::SOC2, 20.0);
                                 progress
                             },
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Team energized by real work".to_string(),
                     },
@@ -496,18 +1065,19 @@ impl DecisionFactory {
     }
 
     fn compliance_pressure_decision(state: &GameState) -> Decision {
-        let soc2_progress = state.compliance.frameworks.get(&ComplianceFramework::SOC2)
-            .map(|f| f.compliance_percent).unwrap_or(0.0);
+        let soc2 = state.compliance.frameworks.get(&ComplianceFramework::SOC2);
+        let soc2_progress = soc2.map(|f| f.compliance_percent).unwrap_or(0.0);
+        let turns_to_audit = soc2.map(|f| f.next_audit.saturating_sub(state.turn)).unwrap_or(2);
 
         Decision {
             id: "turn_6_compliance".to_string(),
             turn: 6,
             title: "The Auditor Cometh".to_string(),
             context: format!(
-                "SOC2 audit is in 2 turns. Current compliance: {:.0}%.\n\
+                "SOC2 audit is in {} turns. Current compliance: {:.0}%.\n\
                  Auditor's preliminary findings: 'Material weaknesses in access control and change management'.\n\
                  CFO: 'We NEED this certification to close the Series B.'",
-                soc2_progress
+                turns_to_audit, soc2_progress
             ),
             choices: vec![
                 Choice {
@@ -522,12 +1092,13 @@ impl DecisionFactory {
                         risk_indicator: RiskIndicator::Neutral,
                         compliance_impact: ComplianceImpact {
                             framework_progress: {
-                                let mut progress = HashMap::new();
+                                let mut progress = BTreeMap::new();
                                 progress.insert(ComplianceFramework::SOC2, 40.0);
                                 progress
                             },
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Burnout risk: High".to_string(),
                     },
@@ -539,7 +1110,7 @@ impl DecisionFactory {
                     },
                     consequences: vec![
                         DelayedConsequence {
-                            trigger_turn: 10,
+                            trigger_turn: state.turn + state.campaign.stretch(4),
                             event_type: EventType::TeamMemberDeparted,
                             description: "Senior engineer quits citing burnout".to_string(),
                             additional_impact: Some(Self::burnout_impact()),
@@ -558,12 +1129,13 @@ impl DecisionFactory {
                         risk_indicator: RiskIndicator::Reduces,
                         compliance_impact: ComplianceImpact {
                             framework_progress: {
-                                let mut progress = HashMap::new();
+                                let mut progress = BTreeMap::new();
                                 progress.insert(ComplianceFramework::SOC2, 10.0);
                                 progress
                             },
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Sustainable pace".to_string(),
                     },
@@ -586,12 +1158,13 @@ impl DecisionFactory {
                         risk_indicator: RiskIndicator::Significant,
                         compliance_impact: ComplianceImpact {
                             framework_progress: {
-                                let mut progress = HashMap::new();
+                                let mut progress = BTreeMap::new();
                                 progress.insert(ComplianceFramework::SOC2, 50.0);
                                 progress
                             },
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Moral hazard".to_string(),
                     },
@@ -599,7 +1172,7 @@ impl DecisionFactory {
                     prerequisites: ChoicePrerequisites::default(),
                     consequences: vec![
                         DelayedConsequence {
-                            trigger_turn: 14,
+                            trigger_turn: state.turn + state.campaign.stretch(8),
                             event_type: EventType::ComplianceAudit,
                             description: "Re-audit discovers falsified documentation. Criminal referral considered.".to_string(),
                             additional_impact: Some(Self::fraud_discovered_impact()),
@@ -637,9 +1210,10 @@ impl DecisionFactory {
                         political_note: Some("CFO will remember this".to_string()),
                         risk_indicator: RiskIndicator::Reduces,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Can finally staff properly".to_string(),
                     },
@@ -661,9 +1235,10 @@ impl DecisionFactory {
                         political_note: Some("Reasonable approach".to_string()),
                         risk_indicator: RiskIndicator::Neutral,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Partial win".to_string(),
                     },
@@ -682,9 +1257,10 @@ impl DecisionFactory {
                         political_note: Some("CFO is pleased".to_string()),
                         risk_indicator: RiskIndicator::Increases,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Morale tanks".to_string(),
                     },
@@ -726,9 +1302,10 @@ impl DecisionFactory {
                         political_note: Some("HR will push back".to_string()),
                         risk_indicator: RiskIndicator::Neutral,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Keeps the engineer, sets expectations".to_string(),
                     },
@@ -751,9 +1328,10 @@ impl DecisionFactory {
                         political_note: Some("Hiring takes 3+ months".to_string()),
                         risk_indicator: RiskIndicator::Increases,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Remaining team stressed".to_string(),
                     },
@@ -761,7 +1339,7 @@ impl DecisionFactory {
                     prerequisites: ChoicePrerequisites::default(),
                     consequences: vec![
                         DelayedConsequence {
-                            trigger_turn: 12,
+                            trigger_turn: state.turn + state.campaign.stretch(2),
                             event_type: EventType::RiskMaterialized,
                             description: "Short-staffed team misses critical alert".to_string(),
                             additional_impact: Some(Self::understaffed_impact()),
@@ -776,7 +1354,7 @@ impl DecisionFactory {
         }
     }
 
-    fn vendor_selection_decision() -> Decision {
+    fn vendor_selection_decision(state: &GameState) -> Decision {
         Decision {
             id: "turn_12_vendor".to_string(),
             turn: 12,
@@ -797,9 +1375,10 @@ impl DecisionFactory {
                         political_note: Some("CEO will be pleased".to_string()),
                         risk_indicator: RiskIndicator::Reduces,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Team accepts it".to_string(),
                     },
@@ -821,9 +1400,10 @@ impl DecisionFactory {
                         political_note: Some("Will need to justify this".to_string()),
                         risk_indicator: RiskIndicator::Reduces,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Team respects the decision".to_string(),
                     },
@@ -846,9 +1426,10 @@ impl DecisionFactory {
                         political_note: Some("CFO loves saving money".to_string()),
                         risk_indicator: RiskIndicator::Neutral,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Team is skeptical".to_string(),
                     },
@@ -859,7 +1440,7 @@ impl DecisionFactory {
                     },
                     consequences: vec![
                         DelayedConsequence {
-                            trigger_turn: 15,
+                            trigger_turn: state.turn + state.campaign.stretch(3),
                             event_type: EventType::IncidentDetected,
                             description: "EDR fails to detect ransomware. Vendor support is... lacking.".to_string(),
                             additional_impact: Some(Self::vendor_failure_impact()),
@@ -874,6 +1455,107 @@ impl DecisionFactory {
         }
     }
 
+    /// The pre-review board meeting - offered on the last turn of every quarter
+    pub fn board_meeting_decision(state: &GameState) -> Option<Decision> {
+        let review_turn = state.turn + 1;
+        if review_turn > state.campaign.final_turn || !state.campaign.is_quarter_end(review_turn) {
+            return None;
+        }
+
+        let quarter = state.quarter;
+        Some(Decision {
+            id: format!("board_meeting_q{}", quarter),
+            turn: state.turn,
+            title: format!("Q{} Board Meeting", quarter),
+            context: format!(
+                "The quarterly review is next week and you have twenty minutes on the agenda.
+                 - Total risk exposure: {:.0}
+                 - Open incidents: {}
+                 - Board confidence: {:.0}%
+
+                 The CEO: 'Keep it high level. They don't want to be scared.'",
+                state.risk.total_exposure,
+                state.active_incidents.len(),
+                state.business.board_confidence_percent
+            ),
+            choices: vec![
+                Choice {
+                    id: "transparent_framing".to_string(),
+                    label: "Transparent".to_string(),
+                    description: "Show them the real numbers, open incidents included. Uncomfortable, but on the record.".to_string(),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: 0.0,
+                        timeline_weeks: Some(1),
+                        political_note: Some("A quiet room. Some directors take notes.".to_string()),
+                        risk_indicator: RiskIndicator::Neutral,
+                        compliance_impact: ComplianceImpact {
+                            framework_progress: BTreeMap::new(),
+                            new_findings: Vec::new(),
+                            resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
+                        },
+                        team_impact: "Team sees you didn't hang them out to dry".to_string(),
+                    },
+                    impact_data: Some(Self::transparent_framing_impact()),
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+                Choice {
+                    id: "optimistic_framing".to_string(),
+                    label: "Optimistic".to_string(),
+                    description: "Lead with the wins, round the risk down, keep the incidents for another day.".to_string(),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: 0.0,
+                        timeline_weeks: Some(1),
+                        political_note: Some("The board leaves happy".to_string()),
+                        risk_indicator: RiskIndicator::Neutral,
+                        compliance_impact: ComplianceImpact {
+                            framework_progress: BTreeMap::new(),
+                            new_findings: Vec::new(),
+                            resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
+                        },
+                        team_impact: "Team recognizes none of the slides".to_string(),
+                    },
+                    impact_data: Some(Self::optimistic_framing_impact(state)),
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+                Choice {
+                    id: "data_driven_framing".to_string(),
+                    label: "Data-Driven".to_string(),
+                    description: "Trend lines, MTTD, control coverage. Your team spends the week building the deck.".to_string(),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: 0.0,
+                        timeline_weeks: Some(1),
+                        political_note: Some("The CFO finally understands a security slide".to_string()),
+                        risk_indicator: RiskIndicator::Neutral,
+                        compliance_impact: ComplianceImpact {
+                            framework_progress: BTreeMap::new(),
+                            new_findings: Vec::new(),
+                            resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
+                        },
+                        team_impact: "Team loses a week to slide formatting".to_string(),
+                    },
+                    impact_data: Some(Self::data_driven_framing_impact()),
+                    prerequisites: ChoicePrerequisites {
+                        min_team_capacity: 2.0,
+                        ..Default::default()
+                    },
+                    consequences: vec![],
+                },
+            ],
+            is_board_pressure: true,
+            is_time_sensitive: false,
+            decision_category: DecisionCategory::PoliticalNavigation,
+            prerequisites: Vec::new(),
+        })
+    }
+
     fn generate_incident_decision(state: &GameState) -> Option<Decision> {
         // Generate decision based on active incidents
         if let Some(incident) = state.active_incidents.first() {
@@ -906,9 +1588,10 @@ impl DecisionFactory {
                         political_note: Some("Transparent but panic-inducing".to_string()),
                         risk_indicator: RiskIndicator::Neutral,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Team under spotlight".to_string(),
                     },
@@ -927,9 +1610,10 @@ impl DecisionFactory {
                         political_note: Some("Better optics, but...".to_string()),
                         risk_indicator: RiskIndicator::Neutral,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Team can work without interference".to_string(),
                     },
@@ -960,7 +1644,7 @@ impl DecisionFactory {
     fn discovery_phase_decision(state: &GameState) -> Decision {
         Decision {
             id: "discovery_reckoning".to_string(),
-            turn: 14,
+            turn: state.turn,
             title: "Discovery: The Reckoning".to_string(),
             context: format!(
                 "External law firm conducting pre-IPO due diligence.\n\
@@ -981,9 +1665,10 @@ impl DecisionFactory {
                         political_note: Some("IPO may be delayed".to_string()),
                         risk_indicator: RiskIndicator::Neutral,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Uncertain".to_string(),
                     },
@@ -1002,9 +1687,10 @@ impl DecisionFactory {
                         political_note: Some("Damage control mode".to_string()),
                         risk_indicator: RiskIndicator::Neutral,
                         compliance_impact: ComplianceImpact {
-                            framework_progress: HashMap::new(),
+                            framework_progress: BTreeMap::new(),
                             new_findings: Vec::new(),
                             resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
                         },
                         team_impact: "Professional approach".to_string(),
                     },
@@ -1024,25 +1710,693 @@ impl DecisionFactory {
         }
     }
 
-    fn generate_dynamic_decision(_state: &GameState) -> Option<Decision> {
-        // Generate decisions based on current state
-        None  // Placeholder for dynamic generation
+    fn generate_dynamic_decision(state: &GameState) -> Option<Decision> {
+        let mut candidates = Vec::new();
+
+        // A peer's breach puts your exposure on the agenda - once per headline
+        if let Some(breach) = state.breaches_in_news().into_iter()
+            .find(|b| !state.decisions_made.contains(&Self::competitor_breach_id(b)))
+        {
+            candidates.push(Self::competitor_breach_decision(state, breach));
+        }
+
+        // Open findings need an owner - fix it or sign for it
+        if let Some(finding) = state.compliance.open_findings.iter()
+            .find(|f| f.status == FindingStatus::Open)
+        {
+            candidates.push(Self::finding_acceptance_decision(finding));
+        }
+
+        // A vector running hot with nothing in front of it - fund it or sign for it
+        if let Some((vector, metric)) = RiskVector::ALL.iter()
+            .filter_map(|v| state.risk.vectors.get(v).map(|m| (*v, m)))
+            .filter(|(v, m)| m.current_level > RISK_ACCEPTANCE_LEVEL
+                && m.mitigation_coverage < RISK_ACCEPTANCE_MAX_COVERAGE
+                && !state.decisions_made.contains(&Self::vector_acceptance_id(*v)))
+            .max_by(|(_, a), (_, b)| a.current_level.total_cmp(&b.current_level))
+        {
+            candidates.push(Self::vector_acceptance_decision(state, vector, metric));
+        }
+
+        // Short-handed with headcount money to spend - backfill before the spiral
+        let hire_pending = state.pending_consequences.iter()
+            .any(|c| c.additional_impact.as_ref().is_some_and(|i| !i.hires.is_empty()));
+        if state.team.total_capacity < HIRING_CAPACITY_FLOOR && !hire_pending
+            && state.budget.can_spend(ENGINEER_HIRE_COST, BudgetCategory::Headcount)
+        {
+            candidates.push(Self::hiring_decision(state));
+        }
+
+        // Growth drags new regulators in - sales doesn't ask while the house is on fire
+        let growing = state.turn > state.campaign.inheritance_end;
+        let in_scope = |framework| state.compliance.frameworks.contains_key(&framework);
+        if growing && state.business.arr_millions >= EU_EXPANSION_ARR
+            && !in_scope(ComplianceFramework::GDPR)
+            && !state.decisions_made.iter().any(|id| id == EU_EXPANSION_ID)
+        {
+            candidates.push(Self::eu_expansion_decision(state));
+        }
+        if growing && state.business.arr_millions >= PAYMENTS_ARR
+            && !in_scope(ComplianceFramework::PciDss)
+            && !state.decisions_made.iter().any(|id| id == PAYMENTS_ID)
+        {
+            candidates.push(Self::payments_decision(state));
+        }
+
+        Self::select_for_board(state, candidates)
     }
 
-    // Impact implementations
-    fn honest_assessment_impact() -> DecisionImpact {
-        let mut impact = DecisionImpact::new("honest_assessment".to_string());
-        
-        let mut risk_delta = RiskDelta::new();
-        risk_delta.add_change(RiskVector::DataExposure, 0.0, 10.0, -2.0);
-        risk_delta.add_change(RiskVector::AccessControl, 0.0, 10.0, -2.0);
-        impact.risk_delta = risk_delta;
-        
-        impact.business_delta = BusinessDelta {
-            arr_change: -0.5,
-            velocity_change: -10.0,
-            churn_change: 0.0,
-            confidence_change: -5.0,
+    /// Pick the candidate the dominant board priority cares most about - ties go to the earlier one
+    pub fn select_for_board(state: &GameState, candidates: Vec<Decision>) -> Option<Decision> {
+        let Some(priority) = state.dominant_board_priority() else {
+            return candidates.into_iter().next();
+        };
+
+        candidates.into_iter()
+            .fold(None, |best: Option<(f64, Decision)>, decision| {
+                let weight = decision.decision_category.board_weight(priority);
+                match best {
+                    Some((top, _)) if top >= weight => best,
+                    _ => Some((weight, decision)),
+                }
+            })
+            .map(|(_, decision)| decision)
+    }
+
+    fn competitor_breach_id(breach: &IndustryBreach) -> String {
+        format!("competitor_breach_{}", breach.company.to_lowercase().replace(' ', "_"))
+    }
+
+    fn competitor_breach_decision(state: &GameState, breach: &IndustryBreach) -> Decision {
+        Decision {
+            id: Self::competitor_breach_id(breach),
+            turn: state.turn,
+            title: format!("{} Just Got Breached", breach.company),
+            context: format!(
+                "{}: {}. Root cause: {}.\n\n\
+                 The CEO forwards the article with one line: 'Our competitor just got breached - what's our exposure?'",
+                breach.company, breach.impact, breach.root_cause
+            ),
+            choices: vec![
+                Choice {
+                    id: "exposure_review".to_string(),
+                    label: "Commission an Exposure Review".to_string(),
+                    description: format!("Have the team check whether '{}' applies to us. Report back with facts.", breach.root_cause.to_lowercase()),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: 0.0,
+                        timeline_weeks: Some(2),
+                        political_note: Some("The board likes a CISO with answers".to_string()),
+                        risk_indicator: RiskIndicator::Reduces,
+                        compliance_impact: ComplianceImpact::default(),
+                        team_impact: "Team drops everything for a week".to_string(),
+                    },
+                    impact_data: Some(Self::exposure_review_impact()),
+                    prerequisites: ChoicePrerequisites {
+                        min_team_capacity: 3.0,
+                        ..Default::default()
+                    },
+                    consequences: vec![],
+                },
+                Choice {
+                    id: "candid_briefing".to_string(),
+                    label: "Brief the Board Candidly".to_string(),
+                    description: "Walk them through where we'd be just as exposed. Nobody enjoys this meeting.".to_string(),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: 0.0,
+                        timeline_weeks: Some(1),
+                        political_note: Some("Confidence dips, credibility holds".to_string()),
+                        risk_indicator: RiskIndicator::Neutral,
+                        compliance_impact: ComplianceImpact::default(),
+                        team_impact: "Team feels heard".to_string(),
+                    },
+                    impact_data: Some(Self::candid_briefing_impact()),
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+                Choice {
+                    id: "couldnt_happen_here".to_string(),
+                    label: "'That Couldn't Happen Here'".to_string(),
+                    description: "Reassure the CEO. Different stack, different team, different story.".to_string(),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: 0.0,
+                        timeline_weeks: None,
+                        political_note: Some("The CEO stops forwarding articles".to_string()),
+                        risk_indicator: RiskIndicator::Neutral,
+                        compliance_impact: ComplianceImpact::default(),
+                        team_impact: "Team knows it could absolutely happen here".to_string(),
+                    },
+                    impact_data: Some(Self::couldnt_happen_here_impact(state, breach)),
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+            ],
+            is_board_pressure: true,
+            is_time_sensitive: false,
+            decision_category: DecisionCategory::PoliticalNavigation,
+            prerequisites: Vec::new(),
+        }
+    }
+
+    fn finding_acceptance_decision(finding: &ComplianceFinding) -> Decision {
+        let (remediation_budget, remediation_capacity) = finding.severity.remediation_cost();
+
+        Decision {
+            id: format!("risk_acceptance_{}", finding.id),
+            turn: finding.discovered_turn,
+            title: format!("Remediate or Accept: {:?} Finding", finding.framework),
+            context: format!(
+                "Open {:?} finding ({:?}): {}\n\
+                 Remediation deadline: turn {}. Past it, the gap is fair game.\n\
+                 The CTO: 'Can't we just accept the risk and move on?'",
+                finding.framework, finding.severity, finding.description, finding.remediation_deadline
+            ),
+            choices: vec![
+                Choice {
+                    id: "remediate_finding".to_string(),
+                    label: "Fix It".to_string(),
+                    description: "Close the gap properly, with evidence the auditor can sample.".to_string(),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: remediation_budget,
+                        timeline_weeks: Some(2),
+                        political_note: Some("Engineering loses a sprint".to_string()),
+                        risk_indicator: RiskIndicator::Reduces,
+                        compliance_impact: ComplianceImpact {
+                            framework_progress: BTreeMap::from([(finding.framework, REMEDIATION_COMPLIANCE_GAIN)]),
+                            new_findings: Vec::new(),
+                            resolved_findings: vec![finding.id.clone()],
+                            accepted_findings: Vec::new(),
+                        },
+                        team_impact: "Real work, real evidence".to_string(),
+                    },
+                    impact_data: Some(Self::remediate_finding_impact(finding)),
+                    prerequisites: ChoicePrerequisites {
+                        min_budget: remediation_budget,
+                        min_team_capacity: remediation_capacity,
+                        ..Default::default()
+                    },
+                    consequences: vec![],
+                },
+                Choice {
+                    id: "accept_risk_documented".to_string(),
+                    label: "Formally Accept the Risk".to_string(),
+                    description: "Write the risk acceptance memo, get management sign-off, and move on. Your name is on it.".to_string(),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: 0.0,
+                        timeline_weeks: Some(1),
+                        political_note: Some("Engineering appreciates not being blocked".to_string()),
+                        risk_indicator: RiskIndicator::Neutral,
+                        compliance_impact: ComplianceImpact {
+                            framework_progress: BTreeMap::new(),
+                            new_findings: Vec::new(),
+                            resolved_findings: Vec::new(),
+                            accepted_findings: vec![finding.id.clone()],
+                        },
+                        team_impact: "Team moves on to other fires".to_string(),
+                    },
+                    impact_data: Some(Self::accept_risk_documented_impact(finding)),
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+                Choice {
+                    id: "leave_on_backlog".to_string(),
+                    label: "Leave It On The Backlog".to_string(),
+                    description: "No memo, no sign-off. It'll get fixed eventually... probably.".to_string(),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: 0.0,
+                        timeline_weeks: None,
+                        political_note: None,
+                        risk_indicator: RiskIndicator::Increases,
+                        compliance_impact: ComplianceImpact {
+                            framework_progress: BTreeMap::new(),
+                            new_findings: Vec::new(),
+                            resolved_findings: Vec::new(),
+                            accepted_findings: Vec::new(),
+                        },
+                        team_impact: "Nobody owns it".to_string(),
+                    },
+                    impact_data: Some(Self::leave_on_backlog_impact()),
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+            ],
+            is_board_pressure: false,
+            is_time_sensitive: false,
+            decision_category: DecisionCategory::RiskAcceptance,
+            prerequisites: Vec::new(),
+        }
+    }
+
+    fn vector_acceptance_id(vector: RiskVector) -> String {
+        format!("vector_acceptance_{:?}", vector).to_lowercase()
+    }
+
+    fn vector_acceptance_decision(state: &GameState, vector: RiskVector, metric: &RiskMetric) -> Decision {
+        Decision {
+            id: Self::vector_acceptance_id(vector),
+            turn: state.turn,
+            title: format!("Risk Acceptance: {:?}", vector),
+            context: format!(
+                "{:?} risk is at {:.0}/100 with {:.0}% mitigation coverage.\n\
+                 Internal audit wants it either funded or formally accepted before the next board meeting.\n\
+                 The CFO: 'Accepting it is free, right?'",
+                vector, metric.current_level, metric.mitigation_coverage
+            ),
+            choices: vec![
+                Choice {
+                    id: "accept_vector_risk".to_string(),
+                    label: "Formally Accept the Risk".to_string(),
+                    description: format!("Sign the memo saying {:?} exposure is within appetite. It isn't, and the memo will outlive you.", vector),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: 0.0,
+                        timeline_weeks: Some(1),
+                        political_note: Some("Fast and cheap - auditors will read it later".to_string()),
+                        risk_indicator: RiskIndicator::Neutral,
+                        compliance_impact: ComplianceImpact::default(),
+                        team_impact: "Team knows what the memo papers over".to_string(),
+                    },
+                    impact_data: Some(Self::accept_vector_risk_impact(vector, metric)),
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+                Choice {
+                    id: "fund_vector_mitigation".to_string(),
+                    label: "Fund Mitigation".to_string(),
+                    description: format!("Stand up a project to bring {:?} down. Real money, real work.", vector),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: VECTOR_MITIGATION_COST,
+                        timeline_weeks: Some(6),
+                        political_note: Some("The CFO asks why this wasn't in the plan".to_string()),
+                        risk_indicator: RiskIndicator::Reduces,
+                        compliance_impact: ComplianceImpact::default(),
+                        team_impact: "Finally, a project with a budget".to_string(),
+                    },
+                    impact_data: Some(Self::fund_vector_mitigation_impact(vector)),
+                    prerequisites: ChoicePrerequisites {
+                        min_budget: VECTOR_MITIGATION_COST,
+                        min_team_capacity: 6.0,
+                        ..Default::default()
+                    },
+                    consequences: vec![],
+                },
+            ],
+            is_board_pressure: false,
+            is_time_sensitive: false,
+            decision_category: DecisionCategory::RiskAcceptance,
+            prerequisites: Vec::new(),
+        }
+    }
+
+    fn debt_paydown_decision(state: &GameState) -> Decision {
+        // Biggest piles first - ties keep declaration order
+        let debt = &state.technical_debt;
+        let mut piles: Vec<(DebtCategory, f64)> = DebtCategory::ALL.iter()
+            .map(|c| (*c, debt.categories.get(c).copied().unwrap_or(0.0)))
+            .filter(|(_, points)| *points > 0.0)
+            .collect();
+        piles.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let mut choices: Vec<Choice> = piles.into_iter()
+            .take(DEBT_PAYDOWN_OPTIONS)
+            .map(|(category, points)| Choice {
+                id: format!("pay_down_{:?}", category).to_lowercase(),
+                label: format!("Pay Down {:?}", category),
+                description: format!(
+                    "Spend a sprint on {:?} ({:.0} points outstanding). Takes pressure off {:?}.",
+                    category, points, category.risk_vector()
+                ),
+                impact_preview: ImpactPreview {
+                    estimated_arr_change: 0.0,
+                    budget_cost: DEBT_PAYDOWN_DECISION_COST,
+                    timeline_weeks: Some(3),
+                    political_note: Some("Product asks why the roadmap slipped".to_string()),
+                    risk_indicator: RiskIndicator::Reduces,
+                    compliance_impact: ComplianceImpact::default(),
+                    team_impact: "Engineers love deleting old code".to_string(),
+                },
+                impact_data: Some(Self::debt_paydown_impact(category)),
+                prerequisites: ChoicePrerequisites {
+                    min_budget: DEBT_PAYDOWN_DECISION_COST,
+                    min_team_capacity: 5.0,
+                    ..Default::default()
+                },
+                consequences: vec![],
+            })
+            .collect();
+
+        choices.push(Choice {
+            id: "defer_debt".to_string(),
+            label: "Keep Shipping".to_string(),
+            description: "Features now, cleanup later. Later never comes.".to_string(),
+            impact_preview: ImpactPreview {
+                estimated_arr_change: 0.0,
+                budget_cost: 0.0,
+                timeline_weeks: None,
+                political_note: Some("Product is thrilled".to_string()),
+                risk_indicator: RiskIndicator::Neutral,
+                compliance_impact: ComplianceImpact::default(),
+                team_impact: "Same workarounds, another quarter".to_string(),
+            },
+            impact_data: Some(Self::defer_debt_impact()),
+            prerequisites: ChoicePrerequisites::default(),
+            consequences: vec![],
+        });
+
+        Decision {
+            id: "debt_paydown".to_string(),
+            turn: state.turn,
+            title: "Pay Down Technical Debt".to_string(),
+            context: format!(
+                "Technical debt stands at {:.0} points and grows {:.0} a turn. It's costing engineering {:.0}% of its velocity.\n\
+                 The CTO: 'Pick one pile. We can't fix all of it.'",
+                debt.total_debt_points, debt.debt_velocity, debt.get_velocity_penalty()
+            ),
+            choices,
+            is_board_pressure: false,
+            is_time_sensitive: false,
+            decision_category: DecisionCategory::BudgetAllocation,
+            prerequisites: Vec::new(),
+        }
+    }
+
+    pub fn hiring_decision(state: &GameState) -> Decision {
+        let lag = state.campaign.turns_per_quarter;
+        let name = CANDIDATE_NAMES[state.turn as usize % CANDIDATE_NAMES.len()];
+        let hire = |id: &str, cost: f64, member: TeamMember| Choice {
+            id: id.to_string(),
+            label: format!("Hire {} ({:?})", member.name, member.role),
+            description: format!(
+                "Offer accepted, notice period served, laptop shipped. Starts in {} turns with {:.0} capacity.",
+                lag, member.capacity
+            ),
+            impact_preview: ImpactPreview {
+                estimated_arr_change: 0.0,
+                budget_cost: cost,
+                timeline_weeks: Some(12),
+                political_note: Some("The CFO asks if this is backfill or growth".to_string()),
+                risk_indicator: RiskIndicator::Reduces,
+                compliance_impact: ComplianceImpact::default(),
+                team_impact: "Help is coming. Eventually.".to_string(),
+            },
+            impact_data: Some(Self::hire_impact(id, cost)),
+            prerequisites: ChoicePrerequisites {
+                min_budget: cost,
+                ..Default::default()
+            },
+            consequences: vec![DelayedConsequence {
+                trigger_turn: state.turn + lag,
+                event_type: EventType::TeamMemberHired,
+                description: format!("{} joined the security team as {:?}", member.name, member.role),
+                additional_impact: Some(Self::onboarding_impact(id, member)),
+            }],
+        };
+
+        Decision {
+            id: format!("hiring_t{}", state.turn),
+            turn: state.turn,
+            title: "Open a Req".to_string(),
+            context: format!(
+                "Team capacity is down to {:.0} story points across {} people. Attrition took the rest.\n\
+                 Recruiting has a candidate. Hiring takes a quarter - nobody starts tomorrow.",
+                state.team.total_capacity,
+                state.team.members.len()
+            ),
+            choices: vec![
+                hire("hire_engineer", ENGINEER_HIRE_COST, TeamMember {
+                    name: name.to_string(),
+                    role: SecurityRole::SecurityEngineer,
+                    skill_level: 60.0,
+                    capacity: 8.0,
+                    burnout_level: 0.0,
+                    tenure_turns: 0,
+                }),
+                hire("hire_senior", SENIOR_HIRE_COST, TeamMember {
+                    name: name.to_string(),
+                    role: SecurityRole::SecurityArchitect,
+                    skill_level: 80.0,
+                    capacity: 10.0,
+                    burnout_level: 0.0,
+                    tenure_turns: 0,
+                }),
+                Choice {
+                    id: "hold_headcount".to_string(),
+                    label: "Freeze the Req".to_string(),
+                    description: "Make do with who's left. They've heard that before.".to_string(),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: 0.0,
+                        timeline_weeks: None,
+                        political_note: Some("The CFO approves".to_string()),
+                        risk_indicator: RiskIndicator::Increases,
+                        compliance_impact: ComplianceImpact::default(),
+                        team_impact: "Same workload, fewer people".to_string(),
+                    },
+                    impact_data: Some(Self::hold_headcount_impact()),
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+            ],
+            is_board_pressure: false,
+            is_time_sensitive: false,
+            decision_category: DecisionCategory::TeamManagement,
+            prerequisites: Vec::new(),
+        }
+    }
+
+    pub fn eu_expansion_decision(state: &GameState) -> Decision {
+        Decision {
+            id: EU_EXPANSION_ID.to_string(),
+            turn: state.turn,
+            title: "Expand to the EU".to_string(),
+            context: "Sales has three German prospects and a slide that says 'EMEA'. Legal has one question: \
+                      who is our Data Protection Officer?".to_string(),
+            choices: vec![
+                Choice {
+                    id: "expand_to_eu".to_string(),
+                    label: "Expand to the EU".to_string(),
+                    description: format!(
+                        "Take the deals. GDPR comes into scope with its first audit in {} turns.",
+                        FrameworkStatus::FIRST_AUDIT_LEAD
+                    ),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 1.5,
+                        budget_cost: 0.0,
+                        timeline_weeks: Some(8),
+                        political_note: Some("The CEO gets to say 'global'".to_string()),
+                        risk_indicator: RiskIndicator::Increases,
+                        compliance_impact: ComplianceImpact {
+                            framework_progress: BTreeMap::from([(ComplianceFramework::GDPR, 10.0)]),
+                            ..ComplianceImpact::default()
+                        },
+                        team_impact: "Someone becomes the DPO. Involuntarily.".to_string(),
+                    },
+                    impact_data: Some(Self::expand_to_eu_impact()),
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+                Choice {
+                    id: "stay_domestic".to_string(),
+                    label: "Not This Year".to_string(),
+                    description: "Finish the compliance you already owe before signing up for more.".to_string(),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: 0.0,
+                        timeline_weeks: None,
+                        political_note: Some("Sales escalates to the CEO".to_string()),
+                        risk_indicator: RiskIndicator::Neutral,
+                        compliance_impact: ComplianceImpact::default(),
+                        team_impact: "No new regulator to learn".to_string(),
+                    },
+                    impact_data: Some(Self::stay_domestic_impact()),
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+            ],
+            is_board_pressure: true,
+            is_time_sensitive: false,
+            decision_category: DecisionCategory::StrategicDirection,
+            prerequisites: Vec::new(),
+        }
+    }
+
+    fn payments_decision(state: &GameState) -> Decision {
+        Decision {
+            id: PAYMENTS_ID.to_string(),
+            turn: state.turn,
+            title: "Take Card Payments Directly".to_string(),
+            context: "Product wants to drop the payment processor and keep the margin. \
+                      Card numbers would touch our servers. That makes us a PCI problem.".to_string(),
+            choices: vec![
+                Choice {
+                    id: "process_payments".to_string(),
+                    label: "Process Cards In-House".to_string(),
+                    description: format!(
+                        "Keep the margin. PCI comes into scope with its first audit in {} turns.",
+                        FrameworkStatus::FIRST_AUDIT_LEAD
+                    ),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 1.0,
+                        budget_cost: 0.0,
+                        timeline_weeks: Some(6),
+                        political_note: Some("The CFO likes the margin".to_string()),
+                        risk_indicator: RiskIndicator::Increases,
+                        compliance_impact: ComplianceImpact::default(),
+                        team_impact: "A cardholder data environment to segment".to_string(),
+                    },
+                    impact_data: Some(Self::process_payments_impact()),
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+                Choice {
+                    id: "keep_processor".to_string(),
+                    label: "Keep the Processor".to_string(),
+                    description: "Pay the fees. Card numbers stay someone else's problem.".to_string(),
+                    impact_preview: ImpactPreview {
+                        estimated_arr_change: 0.0,
+                        budget_cost: 0.05,
+                        timeline_weeks: None,
+                        political_note: Some("The CFO sighs at the fee line".to_string()),
+                        risk_indicator: RiskIndicator::Neutral,
+                        compliance_impact: ComplianceImpact::default(),
+                        team_impact: "Nothing new to defend".to_string(),
+                    },
+                    impact_data: Some(Self::keep_processor_impact()),
+                    prerequisites: ChoicePrerequisites {
+                        min_budget: 0.05,
+                        ..Default::default()
+                    },
+                    consequences: vec![],
+                },
+            ],
+            is_board_pressure: false,
+            is_time_sensitive: false,
+            decision_category: DecisionCategory::StrategicDirection,
+            prerequisites: Vec::new(),
+        }
+    }
+
+    /// Hands on the open incident closest to blowing up - one push per turn
+    pub fn incident_containment_decision(state: &GameState) -> Option<Decision> {
+        let incident = state.active_incidents.iter()
+            .filter(|i| i.response_status != IncidentResponseStatus::Closed)
+            .min_by_key(|i| (i.turn_deadline.unwrap_or(u32::MAX), i.turn_detected))?;
+
+        let preview = |risk_indicator, team_impact: &str| ImpactPreview {
+            estimated_arr_change: 0.0,
+            budget_cost: 0.0,
+            timeline_weeks: None,
+            political_note: None,
+            risk_indicator,
+            compliance_impact: ComplianceImpact::default(),
+            team_impact: team_impact.to_string(),
+        };
+        let capacity = |min_team_capacity| ChoicePrerequisites {
+            min_team_capacity,
+            ..Default::default()
+        };
+
+        let choices = if incident.response_status == IncidentResponseStatus::PostMortem {
+            vec![
+                Choice {
+                    id: "run_postmortem".to_string(),
+                    label: "Run the Post-Mortem".to_string(),
+                    description: "Blameless review, written up, ticket closed. Someone will actually read it. Probably.".to_string(),
+                    impact_preview: preview(RiskIndicator::Reduces, "A day of writing instead of firefighting"),
+                    impact_data: Some(Self::postmortem_impact(incident)),
+                    prerequisites: capacity(POSTMORTEM_CAPACITY),
+                    consequences: vec![],
+                },
+                Choice {
+                    id: "defer_postmortem".to_string(),
+                    label: "Write It Up Later".to_string(),
+                    description: "It's contained. The paperwork can wait. The paperwork always waits.".to_string(),
+                    impact_preview: preview(RiskIndicator::Neutral, "Nobody misses the meeting"),
+                    impact_data: None,
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+            ]
+        } else {
+            vec![
+                Choice {
+                    id: "full_response".to_string(),
+                    label: "All Hands".to_string(),
+                    description: format!(
+                        "Pull the team off everything else. +{:.0}% containment, straight to the next stage.",
+                        FULL_RESPONSE_CONTAINMENT
+                    ),
+                    impact_preview: preview(RiskIndicator::Reduces, "Roadmap work stops"),
+                    impact_data: Some(Self::incident_response_impact(incident, "full_response", FULL_RESPONSE_CONTAINMENT, FULL_RESPONSE_CAPACITY)),
+                    prerequisites: capacity(FULL_RESPONSE_CAPACITY),
+                    consequences: vec![],
+                },
+                Choice {
+                    id: "lean_response".to_string(),
+                    label: "Skeleton Crew".to_string(),
+                    description: format!(
+                        "Two people and a shared channel. +{:.0}% containment, next stage.",
+                        LEAN_RESPONSE_CONTAINMENT
+                    ),
+                    impact_preview: preview(RiskIndicator::Reduces, "The on-call rotation absorbs it"),
+                    impact_data: Some(Self::incident_response_impact(incident, "lean_response", LEAN_RESPONSE_CONTAINMENT, LEAN_RESPONSE_CAPACITY)),
+                    prerequisites: capacity(LEAN_RESPONSE_CAPACITY),
+                    consequences: vec![],
+                },
+                Choice {
+                    id: "monitor_incident".to_string(),
+                    label: "Keep Watching".to_string(),
+                    description: "Dashboards open, hands off. The clock keeps running.".to_string(),
+                    impact_preview: preview(RiskIndicator::Increases, "No change"),
+                    impact_data: None,
+                    prerequisites: ChoicePrerequisites::default(),
+                    consequences: vec![],
+                },
+            ]
+        };
+
+        Some(Decision {
+            id: format!("respond_{}", incident.id),
+            turn: state.turn,
+            title: format!("Incident Response: {}", incident.title),
+            context: format!(
+                "{}\n\nSeverity: {:?}\nStatus: {:?} - {:.0}% contained{}",
+                incident.description,
+                incident.severity,
+                incident.response_status,
+                incident.containment_percent,
+                incident.turn_deadline
+                    .map(|deadline| format!("\nDeadline: turn {}", deadline))
+                    .unwrap_or_default()
+            ),
+            choices,
+            is_board_pressure: false,
+            is_time_sensitive: incident.turn_deadline.is_some(),
+            decision_category: DecisionCategory::IncidentResponse,
+            prerequisites: Vec::new(),
+        })
+    }
+
+    // Impact implementations
+    fn honest_assessment_impact() -> DecisionImpact {
+        let mut impact = DecisionImpact::new("honest_assessment".to_string());
+        
+        let mut risk_delta = RiskDelta::new();
+        risk_delta.add_change(RiskVector::DataExposure, 0.0, 10.0, -2.0);
+        risk_delta.add_change(RiskVector::AccessControl, 0.0, 10.0, -2.0);
+        impact.risk_delta = risk_delta;
+        
+        impact.business_delta = BusinessDelta {
+            arr_change: -0.5,
+            velocity_change: -10.0,
+            churn_change: 0.0,
+            confidence_change: -5.0,
             deal_cycle_change: 5.0,
             differentiator_change: 5.0,
             compliance_change: 10.0,
@@ -1124,9 +2478,16 @@ impl DecisionFactory {
         let mut impact = DecisionImpact::new("mfa_priority".to_string());
         let mut risk_delta = RiskDelta::new();
         risk_delta.add_change(RiskVector::AccessControl, -20.0, 30.0, -5.0);
+        risk_delta.add_change(RiskVector::InsiderThreat, -5.0, 10.0, 0.0);  // Shared admin passwords stop being a thing
         impact.risk_delta = risk_delta;
+        impact.business_delta.arr_change = -0.3;  // Sales loses a few minutes per login, and lets everyone know
+        impact.business_delta.velocity_change = -3.0;
+        impact.compliance_impact.framework_progress.insert(ComplianceFramework::SOC2, 15.0);
         impact.budget_cost = 0.15;
-        impact.budget_category = BudgetCategory::Project;
+        impact.budget_category = BudgetCategory::Tooling;
+        impact.team_capacity_required = 10.0;
+        impact.political_capital_cost = 5.0;
+        impact.reputation_impact.board_delta = 3.0;
         impact
     }
 
@@ -1134,8 +2495,15 @@ impl DecisionFactory {
         let mut impact = DecisionImpact::new("patch_priority".to_string());
         let mut risk_delta = RiskDelta::new();
         risk_delta.add_change(RiskVector::DataExposure, -15.0, 25.0, -3.0);
+        risk_delta.add_change(RiskVector::CloudMisconfiguration, -5.0, 10.0, 0.0);
         impact.risk_delta = risk_delta;
+        impact.business_delta.arr_change = -0.5;  // Outages during the sprint - customers notice
+        impact.business_delta.velocity_change = -5.0;
+        impact.compliance_impact.framework_progress.insert(ComplianceFramework::SOC2, 10.0);
         impact.budget_cost = 0.1;
+        impact.budget_category = BudgetCategory::Project;
+        impact.team_capacity_required = 12.0;
+        impact.reputation_impact.team_delta = -5.0;
         impact
     }
 
@@ -1174,9 +2542,17 @@ impl DecisionFactory {
 
     fn emergency_remediation_impact() -> DecisionImpact {
         let mut impact = DecisionImpact::new("emergency_remediation".to_string());
+        // Findings get fixed, and everything else gets ignored for three weeks
+        let mut risk_delta = RiskDelta::new();
+        risk_delta.add_change(RiskVector::AccessControl, -5.0, 10.0, 0.0);
+        risk_delta.add_change(RiskVector::Detection, 5.0, -5.0, 0.0);
+        impact.risk_delta = risk_delta;
+        impact.business_delta.arr_change = 0.3;
         impact.business_delta.compliance_change = 40.0;
+        impact.compliance_impact.framework_progress.insert(ComplianceFramework::SOC2, 40.0);
         impact.budget_cost = 0.15;
         impact.team_capacity_required = 18.0;
+        impact.reputation_impact.team_delta = -10.0;
         impact
     }
 
@@ -1191,6 +2567,7 @@ impl DecisionFactory {
     fn paper_over_gaps_impact() -> DecisionImpact {
         let mut impact = DecisionImpact::new("paper_over_gaps".to_string());
         impact.business_delta.arr_change = 0.5;
+        impact.compliance_impact.framework_progress.insert(ComplianceFramework::SOC2, 50.0);
         impact.audit_trail = AuditTrail::Toxic;
         impact.narrative_impact = Some(NarrativeImpact {
             integrity_penalty: 25.0,
@@ -1250,13 +2627,20 @@ impl DecisionFactory {
     fn counter_offer_impact() -> DecisionImpact {
         let mut impact = DecisionImpact::new("counter_offer".to_string());
         impact.budget_cost = 0.15;
+        impact.budget_category = BudgetCategory::Headcount;
         impact.political_capital_cost = 25.0;
         impact.reputation_impact.team_delta = 5.0;
+        impact.reputation_impact.board_delta = -3.0;  // HR escalated the salary band exception
         impact
     }
 
     fn let_them_go_impact() -> DecisionImpact {
         let mut impact = DecisionImpact::new("let_them_go".to_string());
+        // Their alerts and their tribal knowledge leave with them
+        let mut risk_delta = RiskDelta::new();
+        risk_delta.add_change(RiskVector::Detection, 5.0, -10.0, 2.0);
+        impact.risk_delta = risk_delta;
+        impact.budget_category = BudgetCategory::Headcount;
         impact.reputation_impact.team_delta = -10.0;
         impact
     }
@@ -1275,6 +2659,89 @@ impl DecisionFactory {
         impact
     }
 
+    fn transparent_framing_impact() -> DecisionImpact {
+        let mut impact = DecisionImpact::new("transparent_framing".to_string());
+        impact.business_delta.confidence_change = -5.0;
+        impact.reputation_impact.board_delta = 3.0;
+        impact.reputation_impact.team_delta = 3.0;
+        // Banked integrity - the record matches what you said
+        impact.narrative_impact = Some(NarrativeImpact {
+            integrity_penalty: -5.0,
+            creates_inconsistency: false,
+            buries_incident: None,
+            delays_escalation: None,
+            reason: "Presented the full risk picture to the board".to_string(),
+        });
+        impact
+    }
+
+    fn optimistic_framing_impact(state: &GameState) -> DecisionImpact {
+        let mut impact = DecisionImpact::new("optimistic_framing".to_string());
+        impact.business_delta.confidence_change = 8.0;
+        impact.reputation_impact.team_delta = -3.0;
+        impact.narrative_impact = Some(NarrativeImpact {
+            integrity_penalty: 10.0,
+            creates_inconsistency: true,
+            buries_incident: None,
+            delays_escalation: None,
+            reason: format!(
+                "Q{} board deck showed risk under control with exposure at {:.0} and {} open incidents",
+                state.quarter,
+                state.risk.total_exposure,
+                state.active_incidents.len()
+            ),
+        });
+        impact
+    }
+
+    fn data_driven_framing_impact() -> DecisionImpact {
+        let mut impact = DecisionImpact::new("data_driven_framing".to_string());
+        impact.business_delta.confidence_change = 3.0;
+        impact.team_capacity_required = 2.0;
+        impact.political_capital_gain = 3.0;
+        impact
+    }
+
+    fn exposure_review_impact() -> DecisionImpact {
+        let mut impact = DecisionImpact::new("exposure_review".to_string());
+        impact.team_capacity_required = 3.0;
+        impact.business_delta.confidence_change = 4.0;
+        impact.political_capital_gain = 5.0;
+        impact.risk_delta.add_change(RiskVector::Detection, -5.0, 5.0, 0.0);
+        impact
+    }
+
+    fn candid_briefing_impact() -> DecisionImpact {
+        let mut impact = DecisionImpact::new("candid_briefing".to_string());
+        impact.business_delta.confidence_change = -3.0;
+        impact.reputation_impact.board_delta = 4.0;
+        impact.narrative_impact = Some(NarrativeImpact {
+            integrity_penalty: -3.0,
+            creates_inconsistency: false,
+            buries_incident: None,
+            delays_escalation: None,
+            reason: "Briefed the board on shared exposure after a peer breach".to_string(),
+        });
+        impact
+    }
+
+    fn couldnt_happen_here_impact(state: &GameState, breach: &IndustryBreach) -> DecisionImpact {
+        let mut impact = DecisionImpact::new("couldnt_happen_here".to_string());
+        impact.business_delta.confidence_change = 5.0;
+        impact.reputation_impact.team_delta = -3.0;
+        impact.narrative_impact = Some(NarrativeImpact {
+            integrity_penalty: 5.0,
+            creates_inconsistency: true,
+            buries_incident: None,
+            delays_escalation: None,
+            reason: format!(
+                "Told the CEO a {} breach couldn't happen here with exposure at {:.0}",
+                breach.company, state.risk.total_exposure
+            ),
+        });
+        impact
+    }
+
     fn political_vendor_impact() -> DecisionImpact {
         let mut impact = DecisionImpact::new("political_vendor".to_string());
         impact.budget_cost = 0.35;
@@ -1294,6 +2761,25 @@ impl DecisionFactory {
         let mut impact = DecisionImpact::new("budget_vendor".to_string());
         impact.budget_cost = 0.10;
         impact.reputation_impact.team_delta = -5.0;
+        // Onboarding finds the vendor's console still on factory credentials
+        impact.spawns_incident = Some(ActiveIncident {
+            id: "vendor_c_default_creds".to_string(),
+            title: "Vendor C Default Credentials".to_string(),
+            description: "The new EDR console was reachable with the vendor's default admin password.".to_string(),
+            severity: IncidentSeverity::Medium,
+            turn_detected: 0,
+            turn_deadline: Some(2),
+            escalated_to_board: false,
+            escalation_turn: None,
+            response_status: IncidentResponseStatus::Detected,
+            assigned_team: Vec::new(),
+            capacity_consumed: 0.0,
+            containment_percent: 0.0,
+            root_cause_identified: true,
+            public_disclosure_required: false,
+            customer_impact_count: None,
+            timeline: Vec::new(),
+        });
         impact
     }
 
@@ -1342,4 +2828,158 @@ impl DecisionFactory {
         impact.political_capital_cost = 15.0;
         impact
     }
-}
\ No newline at end of file
+
+    fn remediate_finding_impact(finding: &ComplianceFinding) -> DecisionImpact {
+        let (budget, capacity) = finding.severity.remediation_cost();
+        let mut impact = DecisionImpact::new("remediate_finding".to_string());
+        impact.compliance_impact.resolved_findings.push(finding.id.clone());
+        impact.compliance_impact.framework_progress.insert(finding.framework, REMEDIATION_COMPLIANCE_GAIN);
+        let mut risk_delta = RiskDelta::new();
+        risk_delta.add_change(finding.framework.risk_vector(), -5.0, 5.0, 0.0);
+        impact.risk_delta = risk_delta;
+        impact.budget_cost = budget;
+        impact.budget_category = BudgetCategory::Project;
+        impact.team_capacity_required = capacity;
+        impact
+    }
+
+    fn accept_risk_documented_impact(finding: &ComplianceFinding) -> DecisionImpact {
+        let mut impact = DecisionImpact::new("accept_risk_documented".to_string());
+        impact.compliance_impact.accepted_findings.push(finding.id.clone());
+        impact.political_capital_gain = 5.0;
+        impact.audit_trail = AuditTrail::Flagged;
+        // Signing away a Critical finding is the memo plaintiffs' lawyers frame
+        if finding.severity == FindingSeverity::Critical {
+            impact.audit_trail = AuditTrail::Toxic;
+            impact.narrative_impact = Some(NarrativeImpact {
+                integrity_penalty: CRITICAL_ACCEPTANCE_PENALTY,
+                creates_inconsistency: false,
+                buries_incident: None,
+                delays_escalation: None,
+                reason: format!("Accepted Critical {:?} finding '{}' instead of fixing it", finding.framework, finding.id),
+            });
+        }
+        impact
+    }
+
+    fn leave_on_backlog_impact() -> DecisionImpact {
+        let mut impact = DecisionImpact::new("leave_on_backlog".to_string());
+        impact.audit_trail = AuditTrail::Flagged;
+        impact
+    }
+
+    fn accept_vector_risk_impact(vector: RiskVector, metric: &RiskMetric) -> DecisionImpact {
+        let mut impact = DecisionImpact::new("accept_vector_risk".to_string());
+        impact.political_capital_gain = 5.0;
+        impact.audit_trail = AuditTrail::Toxic;
+        impact.narrative_impact = Some(NarrativeImpact {
+            integrity_penalty: 10.0,
+            creates_inconsistency: false,
+            buries_incident: None,
+            delays_escalation: None,
+            reason: format!("Signed off {:?} at {:.0}/100 as within appetite", vector, metric.current_level),
+        });
+        impact
+    }
+
+    fn fund_vector_mitigation_impact(vector: RiskVector) -> DecisionImpact {
+        let mut impact = DecisionImpact::new("fund_vector_mitigation".to_string());
+        let mut risk_delta = RiskDelta::new();
+        risk_delta.add_change(vector, -20.0, 30.0, -3.0);
+        impact.risk_delta = risk_delta;
+        impact.budget_cost = VECTOR_MITIGATION_COST;
+        impact.budget_category = BudgetCategory::Project;
+        impact.team_capacity_required = 6.0;
+        impact
+    }
+
+    fn debt_paydown_impact(category: DebtCategory) -> DecisionImpact {
+        let mut impact = DecisionImpact::new(format!("pay_down_{:?}", category).to_lowercase());
+        impact.debt_paydown.insert(category, DEBT_PAYDOWN_DECISION_POINTS);
+        let mut risk_delta = RiskDelta::new();
+        risk_delta.add_change(category.risk_vector(), -8.0, 10.0, -2.0);
+        impact.risk_delta = risk_delta;
+        impact.budget_cost = DEBT_PAYDOWN_DECISION_COST;
+        impact.budget_category = BudgetCategory::Project;
+        impact.team_capacity_required = 5.0;
+        impact.reputation_impact.team_delta = 3.0;
+        impact
+    }
+
+    fn defer_debt_impact() -> DecisionImpact {
+        let mut impact = DecisionImpact::new("defer_debt".to_string());
+        impact.business_delta.velocity_change = 2.0;
+        impact.reputation_impact.team_delta = -2.0;
+        impact
+    }
+
+    fn incident_response_impact(incident: &ActiveIncident, id: &str, containment: f64, capacity: f64) -> DecisionImpact {
+        let mut impact = DecisionImpact::new(id.to_string());
+        impact.team_capacity_required = capacity;
+        impact.works_incident = Some((incident.id.clone(), containment));
+        impact
+    }
+
+    fn postmortem_impact(incident: &ActiveIncident) -> DecisionImpact {
+        let mut impact = DecisionImpact::new("run_postmortem".to_string());
+        impact.team_capacity_required = POSTMORTEM_CAPACITY;
+        impact.works_incident = Some((incident.id.clone(), 0.0));
+        impact.reputation_impact.team_delta = 2.0;
+        impact
+    }
+
+    fn expand_to_eu_impact() -> DecisionImpact {
+        let mut impact = DecisionImpact::new("expand_to_eu".to_string());
+        impact.business_delta.arr_change = 1.5;
+        impact.brings_into_scope.push(ComplianceFramework::GDPR);
+        impact.compliance_impact.framework_progress.insert(ComplianceFramework::GDPR, 10.0);
+        let mut risk_delta = RiskDelta::new();
+        risk_delta.add_change(RiskVector::DataExposure, 5.0, 0.0, 1.0);
+        impact.risk_delta = risk_delta;
+        impact.team_capacity_required = 4.0;
+        impact
+    }
+
+    fn stay_domestic_impact() -> DecisionImpact {
+        let mut impact = DecisionImpact::new("stay_domestic".to_string());
+        impact.business_delta.confidence_change = -3.0;
+        impact
+    }
+
+    fn process_payments_impact() -> DecisionImpact {
+        let mut impact = DecisionImpact::new("process_payments".to_string());
+        impact.business_delta.arr_change = 1.0;
+        impact.brings_into_scope.push(ComplianceFramework::PciDss);
+        let mut risk_delta = RiskDelta::new();
+        risk_delta.add_change(RiskVector::DataExposure, 8.0, 0.0, 1.0);
+        impact.risk_delta = risk_delta;
+        impact
+    }
+
+    fn keep_processor_impact() -> DecisionImpact {
+        let mut impact = DecisionImpact::new("keep_processor".to_string());
+        impact.budget_cost = 0.05;
+        impact.budget_category = BudgetCategory::Tooling;
+        impact
+    }
+
+    fn hire_impact(id: &str, cost: f64) -> DecisionImpact {
+        let mut impact = DecisionImpact::new(id.to_string());
+        impact.budget_cost = cost;
+        impact.budget_category = BudgetCategory::Headcount;
+        impact.reputation_impact.team_delta = 3.0;
+        impact
+    }
+
+    fn onboarding_impact(id: &str, member: TeamMember) -> DecisionImpact {
+        let mut impact = DecisionImpact::new(id.to_string());
+        impact.hires.push(member);
+        impact
+    }
+
+    fn hold_headcount_impact() -> DecisionImpact {
+        let mut impact = DecisionImpact::new("hold_headcount".to_string());
+        impact.reputation_impact.team_delta = -3.0;
+        impact
+    }
+}
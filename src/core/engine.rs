@@ -0,0 +1,109 @@
+use crate::core::types::*;
+use crate::core::state::*;
+use crate::core::decisions::*;
+use crate::core::config::DecisionLoader;
+
+/// Which of a turn's calls is up - the turn's own decision, then incident response, then the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecisionSlot {
+    Turn,
+    IncidentResponse,
+    BoardMeeting,
+}
+
+/// The game without a screen - what to decide, deciding it, and moving on
+///
+/// Bots, tests and the TUI all drive a run through this. It owns the turn
+/// order; frontends own everything the player sees in between.
+pub struct GameEngine {
+    state: GameState,
+    loader: DecisionLoader,
+    settled: Vec<DecisionSlot>,  // Handled this turn - cleared on advance
+}
+
+impl GameEngine {
+    pub fn new(state: GameState, loader: DecisionLoader) -> Self {
+        Self { state, loader, settled: Vec::new() }
+    }
+
+    pub fn state(&self) -> &GameState {
+        &self.state
+    }
+
+    /// For frontends with their own levers - hub actions, tutorials, debug tools
+    pub fn state_mut(&mut self) -> &mut GameState {
+        &mut self.state
+    }
+
+    pub fn into_state(self) -> GameState {
+        self.state
+    }
+
+    /// `Some` once the run is over
+    pub fn ending(&self) -> Option<&Ending> {
+        match &self.state.phase {
+            GamePhase::Ended(ending) => Some(ending),
+            _ => None,
+        }
+    }
+
+    /// The call waiting on the player, and which slot of the turn it fills
+    pub fn next_decision(&self) -> Option<(DecisionSlot, Decision)> {
+        if self.ending().is_some() || self.settled.contains(&DecisionSlot::BoardMeeting) {
+            return None;
+        }
+        if !self.settled.contains(&DecisionSlot::Turn)
+            && let Some(decision) = DecisionFactory::generate_decision(&self.state, &self.loader)
+        {
+            return Some((DecisionSlot::Turn, decision));
+        }
+        if !self.settled.contains(&DecisionSlot::IncidentResponse)
+            && let Some(decision) = DecisionFactory::incident_containment_decision(&self.state)
+        {
+            return Some((DecisionSlot::IncidentResponse, decision));
+        }
+        DecisionFactory::board_meeting_decision(&self.state)
+            .map(|decision| (DecisionSlot::BoardMeeting, decision))
+    }
+
+    pub fn current_decision(&self) -> Option<Decision> {
+        self.next_decision().map(|(_, decision)| decision)
+    }
+
+    /// Hand the waiting decision to `decide` and mark its slot done once it returns `Ok`
+    ///
+    /// `None` when nothing is waiting. Frontends that confirm, rewind or time
+    /// out go through here; `choose` is the no-frills version.
+    pub fn resolve<T>(&mut self, decide: impl FnOnce(&mut Decision, &mut GameState) -> Result<T>) -> Result<Option<T>> {
+        let Some((slot, mut decision)) = self.next_decision() else {
+            return Ok(None);
+        };
+        let outcome = decide(&mut decision, &mut self.state)?;
+        self.settled.push(slot);
+        Ok(Some(outcome))
+    }
+
+    /// Take `choice_id` on the waiting decision
+    pub fn choose(&mut self, choice_id: &str) -> Result<DecisionImpact> {
+        self.resolve(|decision, state| decision.apply_choice(choice_id, state))?
+            .ok_or(GameError::InvalidAction)
+    }
+
+    /// Whatever is due right now - alerts for incidents that just materialized
+    pub fn check_risks(&mut self) -> Vec<String> {
+        self.state.materialize_risks()
+    }
+
+    /// Close out the turn, undecided calls and all - alerts from the turn change
+    pub fn advance(&mut self) -> Vec<String> {
+        if self.ending().is_some() {
+            return Vec::new();
+        }
+        let mut alerts = self.state.advance_turn();
+        self.settled.clear();
+        if self.ending().is_none() {
+            alerts.extend(self.check_risks());
+        }
+        alerts
+    }
+}
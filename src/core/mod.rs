@@ -2,8 +2,22 @@ pub mod types;
 pub mod state;
 pub mod decisions;
 pub mod config;
+pub mod report;
+pub mod replay;
+pub mod operations;
+pub mod stats;
+pub mod engine;
+#[cfg(feature = "debug-console")]
+pub mod debug_console;
 
 pub use types::*;
 pub use state::*;
 pub use decisions::*;
-pub use config::*;
\ No newline at end of file
+pub use config::*;
+pub use report::*;
+pub use replay::*;
+pub use operations::*;
+pub use stats::*;
+pub use engine::*;
+#[cfg(feature = "debug-console")]
+pub use debug_console::*;
\ No newline at end of file
@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+use crate::core::types::*;
+use crate::core::state::*;
+
+/// Debt points cleared by one paydown sprint
+const DEBT_PAYDOWN_POINTS: f64 = 20.0;
+/// Risk level shaved off by a proactive patch cycle
+const PATCH_LEVEL_REDUCTION: f64 = 8.0;
+/// Mitigation coverage added by a proactive patch cycle
+const PATCH_COVERAGE_GAIN: f64 = 5.0;
+/// Political capital spent getting the CFO to sign off on moving money around
+pub const REBALANCE_CAPITAL_COST: f64 = 5.0;
+
+/// Optional work for quiet turns - small levers, small bills
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OperationalAction {
+    PayDownDebt,
+    PrepareAudit(ComplianceFramework),
+    BoostMorale(MoraleBoost),
+    PatchVector(RiskVector),
+    MaintainControls(Vec<RiskVector>),
+    RebalanceBudget { from: BudgetCategory, to: BudgetCategory, amount: f64 },
+}
+
+impl OperationalAction {
+    /// Team capacity the action ties up
+    pub fn capacity_cost(&self) -> f64 {
+        match self {
+            OperationalAction::PayDownDebt => 3.0,
+            OperationalAction::PrepareAudit(_) => 3.0,
+            OperationalAction::BoostMorale(MoraleBoost::TimeOff) => TIME_OFF_CAPACITY,
+            OperationalAction::BoostMorale(_) => 0.0,
+            OperationalAction::PatchVector(_) => 2.0,
+            OperationalAction::MaintainControls(vectors) => {
+                vectors.len() as f64 * MAINTENANCE_CAPACITY_PER_VECTOR
+            }
+            OperationalAction::RebalanceBudget { .. } => 0.0,
+        }
+    }
+
+    /// Budget the action spends ($M)
+    pub fn budget_cost(&self) -> f64 {
+        match self {
+            OperationalAction::PayDownDebt => 0.05,
+            OperationalAction::PrepareAudit(_) => 0.05,
+            OperationalAction::BoostMorale(kind) => kind.budget_cost(),
+            OperationalAction::PatchVector(_) => 0.05,
+            OperationalAction::MaintainControls(_) => 0.0,
+            OperationalAction::RebalanceBudget { .. } => 0.0,
+        }
+    }
+
+    fn budget_category(&self) -> BudgetCategory {
+        match self {
+            OperationalAction::BoostMorale(_) => BudgetCategory::Headcount,
+            _ => BudgetCategory::Project,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        let name = match self {
+            OperationalAction::PayDownDebt => "Pay down technical debt".to_string(),
+            OperationalAction::PrepareAudit(framework) => format!("Prepare for {:?} audit", framework),
+            OperationalAction::BoostMorale(kind) => kind.label().to_string(),
+            OperationalAction::PatchVector(vector) => format!("Proactively patch {:?}", vector),
+            OperationalAction::MaintainControls(vectors) => format!("Maintain existing controls ({} vectors)", vectors.len()),
+            OperationalAction::RebalanceBudget { from, to, amount } => {
+                return format!(
+                    "Move ${:.2}M from {:?} to {:?} budget ({:.0} political capital)",
+                    amount, from, to, REBALANCE_CAPITAL_COST
+                );
+            }
+        };
+
+        format!("{} ({:.0} capacity, ${:.2}M)", name, self.capacity_cost(), self.budget_cost())
+    }
+}
+
+impl GameState {
+    /// Run a filler-turn action - checks the bill first so a refusal costs nothing
+    pub fn perform_operational_action(&mut self, action: &OperationalAction) -> Result<String> {
+        let capacity = action.capacity_cost();
+        let budget = action.budget_cost();
+        let category = action.budget_category();
+
+        if self.team.available_capacity() < capacity {
+            return Err(GameError::TeamCapacityExceeded);
+        }
+        if budget > 0.0 && !self.budget.can_spend(budget, category) {
+            return Err(GameError::InsufficientBudget);
+        }
+        if let OperationalAction::RebalanceBudget { from, amount, .. } = action {
+            if !self.political_capital.can_spend(REBALANCE_CAPITAL_COST) {
+                return Err(GameError::InsufficientPoliticalCapital);
+            }
+            if self.budget.balance(*from) < *amount {
+                return Err(GameError::InsufficientBudget);
+            }
+        }
+
+        let summary = match action {
+            OperationalAction::PayDownDebt => {
+                // Hit the biggest pile first
+                let debt = self.technical_debt.categories.iter()
+                    .max_by(|a, b| a.1.total_cmp(b.1).then_with(|| b.0.cmp(a.0)))
+                    .map(|(debt, _)| *debt)
+                    .ok_or(GameError::InvalidAction)?;
+                self.team.allocate_capacity(capacity);
+                self.budget.spend(budget, category);
+                let cleared = self.technical_debt.pay_down(DEBT_PAYDOWN_POINTS, debt);
+                format!("Cleared {:.0} points of {:?} debt", cleared, debt)
+            }
+            OperationalAction::PrepareAudit(framework) => {
+                let gained = self.invest_compliance(*framework, capacity, budget)?;
+                format!("{:?} readiness up {:.1}%", framework, gained)
+            }
+            OperationalAction::BoostMorale(kind) => {
+                let gained = self.team.boost_morale(&mut self.budget, *kind)?;
+                match kind {
+                    _ if gained < 1.0 => "Polite thanks. Perks only stretch so far this quarter.".to_string(),
+                    MoraleBoost::TimeOff => format!("The team got a breather. Morale up {:.0}, but they're out this turn.", gained),
+                    MoraleBoost::Bonus => format!("Bonuses landed. Morale up {:.0}, and the LinkedIn activity slows down.", gained),
+                    MoraleBoost::Training => format!("Certs booked. Morale up {:.0}, skills sharper.", gained),
+                }
+            }
+            OperationalAction::PatchVector(vector) => {
+                let metric = self.risk.vectors.get_mut(vector).ok_or(GameError::InvalidAction)?;
+                metric.current_level = (metric.current_level - PATCH_LEVEL_REDUCTION).max(0.0);
+                metric.mitigation_coverage = (metric.mitigation_coverage + PATCH_COVERAGE_GAIN).min(100.0);
+                self.team.allocate_capacity(capacity);
+                self.budget.spend(budget, category);
+                self.refresh_mttd();
+                format!("{:?} patched ahead of schedule", vector)
+            }
+            OperationalAction::MaintainControls(vectors) => {
+                self.maintain_controls(vectors)?;
+                format!("Controls on {} vectors tuned and verified", vectors.len())
+            }
+            OperationalAction::RebalanceBudget { from, to, amount } => {
+                if !self.budget.reallocate(*from, *to, *amount) {
+                    return Err(GameError::InsufficientBudget);
+                }
+                self.political_capital.spend(REBALANCE_CAPITAL_COST, None);
+                format!("Finance moved ${:.2}M from {:?} to {:?}", amount, from, to)
+            }
+        };
+
+        self.add_event(
+            EventType::DecisionMade,
+            format!("Operational action: {}", summary),
+            None,
+            EventVisibility::Internal,
+        );
+
+        Ok(summary)
+    }
+}
@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use crate::core::types::*;
+use crate::core::state::*;
+use crate::core::decisions::*;
+use crate::core::config::DecisionLoader;
+use crate::core::operations::OperationalAction;
+use std::fs;
+use std::path::Path;
+
+/// One thing the player did, on the turn they did it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReplayEntry {
+    pub turn: u32,
+    pub action: ReplayAction,
+}
+
+/// What a replay has to redo - committed choices and every hub lever that touched the state
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ReplayAction {
+    /// A committed choice - rewinds never make it in, only what stood
+    Decision {
+        decision_id: String,
+        chosen_index: usize,
+        #[serde(default)]
+        timed_out: bool,  // The clock picked, and the board docked you for it
+    },
+    /// Money and people thrown at a vector from the risk dashboard
+    Mitigate { vector: RiskVector, budget: f64, capacity: f64 },
+    AssignResponder { incident_id: String, member: String },
+    Escalate { incident_id: String },
+    /// A quiet-turn action - debt, audit prep, patching, morale, rebalancing
+    Operational(OperationalAction),
+    EmergencyBudget { amount: f64 },
+    /// Team time spent sizing up one option of a waiting decision
+    Consult { decision_id: String, choice_index: usize },
+}
+
+impl ReplayAction {
+    /// Status-hub levers come before the turn's risk check; everything else after it
+    fn precedes_risk_check(&self) -> bool {
+        matches!(
+            self,
+            ReplayAction::Mitigate { .. } | ReplayAction::AssignResponder { .. } | ReplayAction::Escalate { .. }
+        )
+    }
+}
+
+/// Seed plus everything the player did - enough to rebuild a run for a bug report without the save blob
+///
+/// Hub actions are recorded alongside decisions, so a run that leaned on
+/// mitigation, responders or quiet-turn work replays to the same ending.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReplayLog {
+    pub seed: u64,
+    pub player: Player,
+    pub campaign: Campaign,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    pub entries: Vec<ReplayEntry>,
+}
+
+impl ReplayLog {
+    /// Start recording a fresh run - must be called before the first turn is played
+    pub fn new(state: &GameState) -> Self {
+        Self {
+            seed: state.rng_seed,
+            player: state.player.clone(),
+            campaign: state.campaign,
+            difficulty: state.difficulty,
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, turn: u32, decision_id: &str, chosen_index: usize, timed_out: bool) {
+        self.record_action(turn, ReplayAction::Decision {
+            decision_id: decision_id.to_string(),
+            chosen_index,
+            timed_out,
+        });
+    }
+
+    /// Log a hub action that went through - refused ones changed nothing and stay out
+    pub fn record_action(&mut self, turn: u32, action: ReplayAction) {
+        self.entries.push(ReplayEntry { turn, action });
+    }
+
+    /// Whether this log describes the run `state` came from
+    pub fn matches(&self, state: &GameState) -> bool {
+        self.seed == state.rng_seed && self.player.name == state.player.name
+    }
+
+    /// Rebuild the run from a fresh seeded state, turn by turn, to its ending
+    ///
+    /// Follows the turn order the game plays: hub levers, the risk check, then
+    /// quiet-turn work, consults and decisions, then the turn change.
+    pub fn replay(&self, loader: &DecisionLoader) -> Result<GameState> {
+        let mut state = GameState::new_seeded(self.player.clone(), self.campaign, self.seed)
+            .with_difficulty(self.difficulty);
+        let mut entries = self.entries.iter().peekable();
+
+        while !matches!(state.phase, GamePhase::Ended(_)) {
+            while let Some(entry) = entries.next_if(|e| e.turn == state.turn && e.action.precedes_risk_check()) {
+                Self::redo(&entry.action, &mut state, loader)?;
+            }
+            let _materialized = state.materialize_risks();
+
+            while let Some(entry) = entries.next_if(|e| e.turn == state.turn) {
+                Self::redo(&entry.action, &mut state, loader)?;
+            }
+
+            state.advance_turn();
+            if !matches!(state.phase, GamePhase::Ended(_)) {
+                let _materialized = state.materialize_risks();
+            }
+        }
+
+        Ok(state)
+    }
+
+    /// Make the same call the game made - anything that no longer goes through means the log doesn't fit this run
+    fn redo(action: &ReplayAction, state: &mut GameState, loader: &DecisionLoader) -> Result<()> {
+        match action {
+            ReplayAction::Decision { decision_id, chosen_index, timed_out } => {
+                let mut decision = Self::offered(state, loader, decision_id)
+                    .ok_or(GameError::InvalidAction)?;
+                if *timed_out {
+                    let label = decision.choices.get(*chosen_index)
+                        .map(|c| c.label.clone())
+                        .ok_or(GameError::InvalidAction)?;
+                    state.record_decision_timeout(&decision, &label);
+                }
+                decision.apply_choice_by_index(*chosen_index, state)?;
+            }
+            ReplayAction::Mitigate { vector, budget, capacity } => state.invest_mitigation(*vector, *budget, *capacity)?,
+            ReplayAction::AssignResponder { incident_id, member } => state.assign_to_incident(incident_id, member)?,
+            ReplayAction::Escalate { incident_id } => state.escalate_incident_to_board(incident_id)?,
+            ReplayAction::Operational(operation) => {
+                let _summary = state.perform_operational_action(operation)?;
+            }
+            ReplayAction::EmergencyBudget { amount } => {
+                let _approved = state.request_emergency_budget(*amount)?;
+            }
+            ReplayAction::Consult { decision_id, choice_index } => {
+                let decision = Self::offered(state, loader, decision_id)
+                    .ok_or(GameError::InvalidAction)?;
+                let choice = decision.choices.get(*choice_index).ok_or(GameError::InvalidAction)?;
+                let _report = state.consult_team(choice)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The decision with this id as the game would offer it right now
+    fn offered(state: &GameState, loader: &DecisionLoader, decision_id: &str) -> Option<Decision> {
+        DecisionFactory::generate_decision(state, loader)
+            .into_iter()
+            .chain(DecisionFactory::incident_containment_decision(state))
+            .chain(DecisionFactory::board_meeting_decision(state))
+            .find(|d| d.id == decision_id)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(|_| GameError::SystemFailure)?;
+        fs::write(path, json).map_err(|_| GameError::SystemFailure)
+    }
+
+    pub fn read(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path).map_err(|_| GameError::SystemFailure)?;
+        serde_json::from_str(&json).map_err(|_| GameError::SystemFailure)
+    }
+}
@@ -0,0 +1,364 @@
+use serde::{Deserialize, Serialize};
+use crate::core::types::*;
+use crate::core::state::*;
+use std::fs;
+use std::path::Path;
+
+/// Machine-readable post-game report - the numbers behind the narrative
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GameReport {
+    pub player_name: String,
+    pub company_name: String,
+    pub turns_played: u32,
+    pub ending: Option<Ending>,  // None if the run was exported mid-game
+    pub final_metrics: ReportMetrics,
+    pub decision_timeline: Vec<ReportDecision>,
+    pub incidents: Vec<ReportIncident>,
+    pub narrative_inconsistencies: Vec<NarrativeInconsistency>,
+    pub board_satisfaction: Vec<ReportBoardMember>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReportMetrics {
+    pub arr_millions: f64,
+    pub roadmap_velocity_percent: f64,
+    pub customer_churn_probability: f64,
+    pub board_confidence_percent: f64,
+    pub narrative_score: f64,
+    pub total_risk_exposure: f64,
+    pub budget_available: f64,
+    pub political_capital: f64,
+    pub team_morale: f64,
+    pub technical_debt_points: f64,
+    pub buried_incidents: usize,
+    pub delayed_escalations: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReportDecision {
+    pub turn: u32,
+    pub decision_id: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReportIncident {
+    pub id: String,
+    pub title: String,
+    pub severity: Option<IncidentSeverity>,  // Missing on incidents resolved in older saves
+    pub turn_detected: Option<u32>,
+    pub resolved_turn: Option<u32>,
+    pub escalated_to_board: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReportBoardMember {
+    pub name: String,
+    pub role: BoardMemberRole,
+    pub satisfaction: f64,
+}
+
+/// The event log as one audience would read it, with the run's outcome up top
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuditLogExport {
+    pub summary: AuditLogSummary,
+    pub events: Vec<Event>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuditLogSummary {
+    pub audience: Option<EventVisibility>,  // None for the unfiltered log
+    pub turn: u32,
+    pub final_arr_millions: f64,
+    pub narrative_score: f64,
+    pub ending: Option<Ending>,
+    pub buried_incidents: Option<usize>,  // Withheld from audiences that can't see buried events
+}
+
+/// Risk vectors at or above this level make the successor's "open risks" list
+const HANDOFF_RISK_THRESHOLD: f64 = 50.0;
+
+/// Below this narrative integrity the handoff stops admitting what was buried
+const HANDOFF_CANDOR_THRESHOLD: f64 = 50.0;
+
+/// Incident counts by severity
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct SeverityBreakdown {
+    pub critical: usize,
+    pub high: usize,
+    pub medium: usize,
+    pub low: usize,
+}
+
+impl SeverityBreakdown {
+    fn record(&mut self, severity: IncidentSeverity) {
+        match severity {
+            IncidentSeverity::Critical => self.critical += 1,
+            IncidentSeverity::High => self.high += 1,
+            IncidentSeverity::Medium => self.medium += 1,
+            IncidentSeverity::Low => self.low += 1,
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.critical + self.high + self.medium + self.low
+    }
+}
+
+/// Everything that went wrong, and how well you cleaned it up
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct IncidentScoreboard {
+    pub active: SeverityBreakdown,
+    pub resolved: SeverityBreakdown,
+    pub resolved_count: usize,  // Includes resolutions with no recorded severity
+    pub average_turns_to_resolve: Option<f64>,
+    pub total_cost_millions: f64,
+}
+
+impl GameState {
+    /// Tally active and resolved incidents for the post-game breakdown
+    pub fn incident_scoreboard(&self) -> IncidentScoreboard {
+        let mut board = IncidentScoreboard::default();
+
+        for incident in &self.active_incidents {
+            board.active.record(incident.severity);
+        }
+
+        for resolved in &self.resolved_incidents {
+            if let Some(severity) = resolved.severity {
+                board.resolved.record(severity);
+            }
+            board.total_cost_millions += resolved.final_cost;
+        }
+
+        board.resolved_count = self.resolved_incidents.len();
+        if board.resolved_count > 0 {
+            let total_turns: u32 = self.resolved_incidents.iter().map(|r| r.time_to_resolve).sum();
+            board.average_turns_to_resolve = Some(total_turns as f64 / board.resolved_count as f64);
+        }
+
+        board
+    }
+
+    /// Snapshot the run into a serializable report
+    pub fn export_report(&self) -> GameReport {
+        let ending = match &self.phase {
+            GamePhase::Ended(ending) => Some(ending.clone()),
+            _ => None,
+        };
+
+        let decision_timeline = self.events.iter()
+            .filter(|e| matches!(e.event_type, EventType::DecisionMade))
+            .filter_map(|e| e.decision_id.as_ref().map(|id| ReportDecision {
+                turn: e.turn,
+                decision_id: id.clone(),
+                description: e.description.clone(),
+            }))
+            .collect();
+
+        let mut incidents: Vec<ReportIncident> = self.active_incidents.iter()
+            .map(|i| ReportIncident {
+                id: i.id.clone(),
+                title: i.title.clone(),
+                severity: Some(i.severity),
+                turn_detected: Some(i.turn_detected),
+                resolved_turn: None,
+                escalated_to_board: i.escalated_to_board,
+            })
+            .collect();
+
+        incidents.extend(self.resolved_incidents.iter().map(|r| ReportIncident {
+            id: r.original_incident.clone(),
+            title: r.title.clone().unwrap_or_else(|| r.original_incident.clone()),
+            severity: r.severity,
+            turn_detected: Some(r.resolution_turn.saturating_sub(r.time_to_resolve)),
+            resolved_turn: Some(r.resolution_turn),
+            escalated_to_board: false,
+        }));
+
+        GameReport {
+            player_name: self.player.name.clone(),
+            company_name: self.player.company_name.clone(),
+            turns_played: self.turn,
+            ending,
+            final_metrics: ReportMetrics {
+                arr_millions: self.business.arr_millions,
+                roadmap_velocity_percent: self.business.roadmap_velocity_percent,
+                customer_churn_probability: self.business.customer_churn_probability,
+                board_confidence_percent: self.business.board_confidence_percent,
+                narrative_score: self.narrative.score,
+                total_risk_exposure: self.risk.total_exposure,
+                budget_available: self.budget.available(),
+                political_capital: self.political_capital.total,
+                team_morale: self.team.morale,
+                technical_debt_points: self.technical_debt.total_debt_points,
+                buried_incidents: self.narrative.buried_incidents.len(),
+                delayed_escalations: self.narrative.delayed_escalations.len(),
+            },
+            decision_timeline,
+            incidents,
+            narrative_inconsistencies: self.narrative.inconsistencies.clone(),
+            board_satisfaction: self.board.iter()
+                .map(|b| ReportBoardMember {
+                    name: b.name.clone(),
+                    role: b.role,
+                    satisfaction: b.satisfaction,
+                })
+                .collect(),
+        }
+    }
+
+    /// The documentation your successor inherits - hopefully more than "Good luck"
+    pub fn generate_handoff(&self) -> String {
+        let mut out = format!(
+            "HANDOFF REPORT - {}\nPrepared by the outgoing CISO after {} turns\n",
+            self.player.company_name, self.turn
+        );
+
+        out.push_str("\nOPEN RISKS\n");
+        let mut risks: Vec<(&RiskVector, &RiskMetric)> = self.risk.vectors.iter()
+            .filter(|(_, m)| m.current_level >= HANDOFF_RISK_THRESHOLD)
+            .collect();
+        risks.sort_by(|a, b| b.1.current_level.total_cmp(&a.1.current_level));
+        if risks.is_empty() {
+            out.push_str("- Nothing above the line. Enjoy it while it lasts.\n");
+        }
+        for (vector, metric) in risks {
+            out.push_str(&format!(
+                "- {:?}: level {:.0}, mitigation coverage {:.0}%\n",
+                vector, metric.current_level, metric.mitigation_coverage
+            ));
+        }
+
+        out.push_str("\nUNRESOLVED INCIDENTS\n");
+        let unresolved: Vec<&ActiveIncident> = self.active_incidents.iter()
+            .filter(|i| i.response_status != IncidentResponseStatus::Closed)
+            .collect();
+        if unresolved.is_empty() {
+            out.push_str("- None open.\n");
+        }
+        for incident in unresolved {
+            out.push_str(&format!(
+                "- {} [{}] {:?}, {:?} since turn {} ({:.0}% contained)\n",
+                incident.title, incident.id, incident.severity, incident.response_status,
+                incident.turn_detected, incident.containment_percent
+            ));
+        }
+
+        // Skeletons nobody has found yet - an honest predecessor names them
+        let skeletons: Vec<&BuriedIncident> = self.narrative.buried_incidents.iter()
+            .filter(|b| b.turn_disclosed.is_none())
+            .collect();
+        if !skeletons.is_empty() {
+            if self.narrative.score >= HANDOFF_CANDOR_THRESHOLD {
+                out.push_str("\nTHINGS I DIDN'T REPORT\n");
+                for buried in skeletons {
+                    out.push_str(&format!(
+                        "- {} (turn {}): actually {:?}, reported as {:?} - {}\n",
+                        buried.incident_id, buried.turn_occurred, buried.actual_severity,
+                        buried.reported_severity, buried.burial_method
+                    ));
+                }
+            } else {
+                out.push_str("\nRECORD GAPS\n");
+                for buried in skeletons {
+                    out.push_str(&format!(
+                        "- Turn {}: incident records are incomplete. Probably nothing.\n",
+                        buried.turn_occurred
+                    ));
+                }
+            }
+        }
+
+        out.push_str(&format!(
+            "\nTECHNICAL DEBT ({:.0} points)\n",
+            self.technical_debt.total_debt_points
+        ));
+        let mut debt: Vec<(&DebtCategory, &f64)> = self.technical_debt.categories.iter()
+            .filter(|(_, points)| **points > 0.0)
+            .collect();
+        debt.sort_by(|a, b| b.1.total_cmp(a.1));
+        for (category, points) in debt {
+            out.push_str(&format!("- {:?}: {:.0}\n", category, points));
+        }
+
+        out.push_str("\nOUTSTANDING FINDINGS\n");
+        let findings: Vec<&ComplianceFinding> = self.compliance.open_findings.iter()
+            .filter(|f| f.status != FindingStatus::Resolved)
+            .collect();
+        if findings.is_empty() {
+            out.push_str("- None. The auditors were almost pleasant.\n");
+        }
+        for finding in findings {
+            out.push_str(&format!(
+                "- {} ({:?}, {:?}): {} - due turn {}, {:?}\n",
+                finding.id, finding.framework, finding.severity, finding.description,
+                finding.remediation_deadline, finding.status
+            ));
+        }
+
+        out.push_str("\nGood luck.\n");
+        out
+    }
+
+    /// An event trail as pretty JSON - `audience` keeps only what they'd have seen
+    ///
+    /// `events` is usually `GamePersistence::event_trail`; `self.events` alone is just the last window.
+    pub fn export_audit_log_json(&self, events: &[Event], audience: Option<EventVisibility>) -> String {
+        let sees = |visibility: EventVisibility| audience.is_none_or(|a| visibility.visible_to(a));
+        let export = AuditLogExport {
+            summary: AuditLogSummary {
+                audience,
+                turn: self.turn,
+                final_arr_millions: self.business.arr_millions,
+                narrative_score: self.narrative.score,
+                ending: match &self.phase {
+                    GamePhase::Ended(ending) => Some(ending.clone()),
+                    _ => None,
+                },
+                buried_incidents: sees(EventVisibility::Buried)
+                    .then_some(self.narrative.buried_incidents.len()),
+            },
+            events: events.iter()
+                .filter(|e| sees(e.visibility))
+                .cloned()
+                .collect(),
+        };
+
+        // Plain data all the way down - serializing it can't fail
+        serde_json::to_string_pretty(&export).unwrap_or_default()
+    }
+
+    pub fn write_audit_log(&self, events: &[Event], path: &Path, audience: Option<EventVisibility>) -> Result<()> {
+        fs::write(path, self.export_audit_log_json(events, audience)).map_err(|_| GameError::SystemFailure)
+    }
+
+    /// One CSV row per turn advanced, header first - for plotting a run in a spreadsheet
+    pub fn export_metrics_csv(&self) -> String {
+        let mut out = String::from(
+            "turn,arr_millions,total_exposure,narrative_score,board_confidence_percent,budget_available,team_morale\n",
+        );
+        for row in &self.metrics_history {
+            out.push_str(&format!(
+                "{},{:.2},{:.1},{:.1},{:.1},{:.2},{:.1}\n",
+                row.turn, row.arr_millions, row.total_exposure, row.narrative_score,
+                row.board_confidence_percent, row.budget_available, row.team_morale
+            ));
+        }
+        out
+    }
+
+    pub fn write_metrics_csv(&self, path: &Path) -> Result<()> {
+        fs::write(path, self.export_metrics_csv()).map_err(|_| GameError::SystemFailure)
+    }
+
+    /// Write the post-game report as pretty JSON
+    pub fn write_report_json(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.export_report())
+            .map_err(|_| GameError::SystemFailure)?;
+
+        fs::write(path, json).map_err(|_| GameError::SystemFailure)?;
+
+        Ok(())
+    }
+}
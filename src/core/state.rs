@@ -1,10 +1,154 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use crate::core::types::*;
-use std::collections::HashMap;
+use crate::core::config::{IncidentCatalog, IncidentTemplate, IncidentTrigger};
+use crate::core::decisions::{Decision, DelayedConsequence};
+use std::collections::{BTreeMap, VecDeque};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+/// Churn bump for any public incident, before counting affected customers
+const PUBLIC_INCIDENT_BASE_CHURN: f64 = 2.0;
+/// Extra churn per 100K affected customers
+const CHURN_PER_100K_CUSTOMERS: f64 = 1.0;
+/// Ceiling on churn from a single incident event
+const MAX_INCIDENT_CHURN: f64 = 15.0;
+/// Churn added each turn a public incident stays open - the story keeps running
+pub const OPEN_PUBLIC_INCIDENT_CHURN: f64 = 1.0;
+/// Exposure past which customers' security reviews start coming back ugly - the same bar the ending uses
+const CHURN_EXPOSURE_THRESHOLD: f64 = 150.0;
+/// Churn added per turn for every 100 points of exposure past the threshold
+const CHURN_PER_100_EXPOSURE: f64 = 0.5;
+
+/// Team capacity to keep one vector's controls from decaying for a turn
+pub const MAINTENANCE_CAPACITY_PER_VECTOR: f64 = 1.0;
+
+/// Compliance points per unit of team capacity, before diminishing returns
+const COMPLIANCE_PER_CAPACITY: f64 = 0.02;
+/// Compliance points per $1M of budget, before diminishing returns
+const COMPLIANCE_PER_MILLION: f64 = 2.0;
+/// Distance to 100% compliance covered by each remaining control gap
+const COMPLIANCE_GAP_SPAN: f64 = 20.0;
+
+/// Coverage points per unit of team capacity, before diminishing returns
+const MITIGATION_PER_CAPACITY: f64 = 0.04;
+/// Coverage points per $1M of tooling budget, before diminishing returns
+const MITIGATION_PER_MILLION: f64 = 2.5;
+/// Risk level shed for each point of coverage gained
+const RISK_REDUCTION_PER_COVERAGE: f64 = 0.5;
+
+/// Coverage from a mitigation investment - the last few percent are always the expensive ones
+pub fn mitigation_investment_gain(current_coverage: f64, capacity: f64, budget: f64) -> f64 {
+    let effort = capacity * MITIGATION_PER_CAPACITY + budget * MITIGATION_PER_MILLION;
+    let remaining = (100.0 - current_coverage).max(0.0);
+    remaining * (1.0 - (-effort).exp())
+}
+
+/// Progress from an audit-prep investment - closes a share of the remaining gap, never all of it
+pub fn compliance_investment_gain(current_percent: f64, capacity: f64, budget: f64) -> f64 {
+    let effort = capacity * COMPLIANCE_PER_CAPACITY + budget * COMPLIANCE_PER_MILLION;
+    let remaining = (100.0 - current_percent).max(0.0);
+    remaining * (1.0 - (-effort).exp())
+}
+
+/// Per-turn chance a freshly buried incident leaks
+const LEAK_BASE_CHANCE: f64 = 0.02;
+/// Extra leak chance for every turn an incident stays buried
+const LEAK_CHANCE_PER_TURN: f64 = 0.03;
+/// Extra leak chance for every other secret you're keeping - more people know something
+const LEAK_CHANCE_PER_SECRET: f64 = 0.02;
+/// Leaks are never certain in any single turn
+const LEAK_MAX_CHANCE: f64 = 0.6;
+/// Integrity lost when a leak surfaces, before the cover-up duration is added
+const LEAK_INTEGRITY_PENALTY: f64 = 15.0;
+
+/// Team morale below which someone stops protecting your secrets
+const WHISTLEBLOWER_MORALE_THRESHOLD: f64 = 25.0;
+/// Per-turn chance of a regulator complaint right at the morale threshold
+const WHISTLEBLOWER_BASE_CHANCE: f64 = 0.15;
+/// Extra chance per morale point below the threshold
+const WHISTLEBLOWER_CHANCE_PER_POINT: f64 = 0.01;
+/// Integrity lost on top of the leak itself - regulators, not reporters, have the emails
+const WHISTLEBLOWER_INTEGRITY_PENALTY: f64 = 25.0;
+
+/// Containment gained per turn for each skill point of an assigned responder
+const CONTAINMENT_PER_SKILL_POINT: f64 = 0.4;
+/// Burnout each assigned responder picks up per turn on an incident
+const INCIDENT_BURNOUT_PER_TURN: f64 = 5.0;
+
+/// Chance per turn that a peer company's breach makes the news
+const INDUSTRY_BREACH_CHANCE: f64 = 0.15;
+/// Turns a breach stays in the headlines - and on the board's mind
+pub const BREACH_NEWS_TURNS: u32 = 3;
+/// Board risk sensitivity while a peer's breach is in the news
+const BREACH_RISK_SENSITIVITY: f64 = 1.5;
+/// Peer breaches the news cycle can draw on - each one only happens once
+const INDUSTRY_BREACH_HEADLINES: [(&str, &str, &str); 4] = [
+    ("Northwind Analytics", "2.1M customer records exposed", "Misconfigured cloud storage bucket"),
+    ("Helix Payments", "Card data skimmed for six weeks", "Compromised third-party JavaScript"),
+    ("Brightline Health", "Ransomware halted operations for nine days", "Unpatched VPN appliance"),
+    ("Cobalt Logistics", "Source code and signing keys leaked", "Phished admin without MFA"),
+];
+
+/// Political capital burned just to get emergency funding on the board agenda
+pub const EMERGENCY_BUDGET_CAPITAL_COST: f64 = 15.0;
+/// Largest emergency ask the board will entertain, as a share of the annual budget
+pub const EMERGENCY_BUDGET_MAX_SHARE: f64 = 0.25;
+/// Blended board confidence and CFO trust needed for a yes
+const EMERGENCY_BUDGET_APPROVAL_BAR: f64 = 55.0;
+/// Each earlier ask raises the bar by this much - and costs as much CFO trust up front
+const EMERGENCY_BUDGET_REPEAT_PENALTY: f64 = 10.0;
+/// CFO trust and board credibility lost when the answer is no
+const EMERGENCY_BUDGET_DENIAL_PENALTY: f64 = 8.0;
+
+/// Each turn an incident stays open adds this share of its base carrying cost
+const INCIDENT_COST_GROWTH_PER_TURN: f64 = 0.25;
+/// Board confidence lost the turn incident costs empty the emergency reserve
+const RESERVE_EXHAUSTED_CONFIDENCE_HIT: f64 = 5.0;
+/// CFO trust lost each turn incidents bleed the general budget instead
+const GENERAL_BUDGET_DRAW_TRUST_HIT: f64 = 2.0;
+
+/// Spreads consecutive turns across the seed space so their rolls don't correlate
+const TURN_SEED_STRIDE: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Turns an auditor gives you to fix what they wrote up
+pub const FINDING_REMEDIATION_TURNS: u32 = 3;
+/// Risk added to the framework's vector when a finding blows its deadline
+const MISSED_REMEDIATION_RISK: f64 = 10.0;
+/// Papered-over compliance points an audit sample will catch
+const PAPER_FINDING_THRESHOLD: f64 = 10.0;
+/// Past this much paper, the finding is High - the auditor is now writing a memo
+const PAPER_FINDING_HIGH_THRESHOLD: f64 = 30.0;
+
+/// Reputation moves smaller than this stay out of the audit log - rounding noise isn't news
+const REPUTATION_EVENT_THRESHOLD: f64 = 0.5;
+
+/// Political capital lost per point of (influence-scaled) dissatisfaction below 50
+const BOARD_PRESSURE_FACTOR: f64 = 0.2;
+
+/// Turns of exposure history kept for the trend line - bounded so saves stay small
+pub const EXPOSURE_HISTORY_LEN: usize = 12;
+/// Events kept in memory and in the save - older ones spill to the overflow log
+pub const EVENT_WINDOW: usize = 256;
+
+/// The Q3 board objective - MTTD under four hours
+pub const MTTD_OBJECTIVE_HOURS: f64 = 4.0;
+const MTTD_OBJECTIVE_ID: &str = "q3_objective";
+/// The opening SOC2 objective - tracks the framework's compliance percent
+const SOC2_OBJECTIVE_ID: &str = "soc2_cert";
+/// The opening incident objective - tracks how much of the incident load got closed out
+const INCIDENT_OBJECTIVE_ID: &str = "reduce_incidents";
+/// The Q2 board objective - MFA on every admin account
+const MFA_OBJECTIVE_ID: &str = "q2_objective";
+/// Suffix on the objective that tracks a framework brought into scope mid-run
+const FRAMEWORK_OBJECTIVE_SUFFIX: &str = "_readiness";
+/// Access-control coverage that counts as "all administrative accounts" - nobody gets the last 20%
+pub const MFA_OBJECTIVE_COVERAGE: f64 = 80.0;
+/// MTTD added by each incident still sitting untriaged after its first turn
+const MTTD_UNTRIAGED_PENALTY_HOURS: f64 = 6.0;
 
 /// Immutable event in the audit log - everything is recorded
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Event {
     pub timestamp: DateTime<Utc>,
     pub turn: u32,
@@ -12,7 +156,7 @@ pub struct Event {
     pub description: String,
     pub decision_id: Option<String>,
     pub visibility: EventVisibility,  // Who knows about this?
-    pub metadata: HashMap<String, String>,
+    pub metadata: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -24,13 +168,32 @@ pub enum EventVisibility {
     Buried,        // Someone tried to hide this
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl EventVisibility {
+    /// How far inside the company you have to be to see it - the public is outermost
+    fn depth(&self) -> u8 {
+        match self {
+            EventVisibility::Public => 0,
+            EventVisibility::Board => 1,
+            EventVisibility::Management => 2,
+            EventVisibility::Internal | EventVisibility::Buried => 3,  // The team knows what it hid
+        }
+    }
+
+    /// Whether someone with `audience` clearance would have seen this
+    pub fn visible_to(&self, audience: EventVisibility) -> bool {
+        self.depth() <= audience.depth()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum EventType {
     GameStart,
     DecisionMade,
     RiskMaterialized,
     BoardPressure,
     ComplianceAudit,
+    CertificationGranted,
+    CertificationLapsed,
     IncidentDetected,
     IncidentEscalated,
     IncidentResolved,
@@ -40,13 +203,18 @@ pub enum EventType {
     TeamMemberHired,
     ComplianceFindingOpened,
     ComplianceFindingClosed,
+    RiskAccepted,
+    DecisionRewound,
+    DecisionTimedOut,
+    DebugOverride,
+    WhistleblowerComplaint,
     PoliticalCapitalSpent,
     ReputationChange,
     GameEnd,
 }
 
 /// Core game state - now significantly more complex
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GameState {
     pub player: Player,
     pub turn: u32,
@@ -67,17 +235,187 @@ pub struct GameState {
     pub phase: GamePhase,
     pub quarterly_objectives: Vec<Objective>,
     pub technical_debt: TechnicalDebt,
+    #[serde(default = "GameState::standard_rewind_tokens")]
+    pub rewind_tokens: u32,  // Limited mulligans - the audit log still remembers
+    #[serde(default)]
+    pub tutorial: bool,  // First-run overlays still pending
+    #[serde(default)]
+    pub campaign: Campaign,
+    #[serde(default)]
+    pub exposure_history: VecDeque<f64>,  // Oldest first, one sample per turn
+    #[serde(default)]
+    pub emergency_budget_requests: u32,  // The CFO keeps count even if you don't
+    #[serde(default)]
+    pub rng_seed: u64,  // Every turn's rolls derive from this - same seed, same luck
+    #[serde(skip)]
+    pub incident_catalog: IncidentCatalog,  // Content, not progress - reloaded at launch, never saved
+    #[serde(default)]
+    pub spilled_events: usize,  // Aged out of `events` over the whole run - the overflow log holds them
+    #[serde(skip)]
+    pub unflushed_events: Vec<Event>,  // Aged out, not on disk yet - the next save writes them
+    #[serde(default)]
+    pub pending_consequences: Vec<DelayedConsequence>,  // The bill for earlier choices, due on its trigger turn
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub metrics_history: Vec<TurnSnapshot>,  // One row per turn advanced, for the CSV export
+}
+
+/// The headline numbers as they stood at the end of a turn
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TurnSnapshot {
+    pub turn: u32,
+    pub arr_millions: f64,
+    pub total_exposure: f64,
+    pub narrative_score: f64,
+    pub board_confidence_percent: f64,
+    pub budget_available: f64,
+    pub team_morale: f64,
+}
+
+/// Copy of the game state captured before a decision, for rewinds
+#[derive(Debug, Clone)]
+pub struct StateSnapshot {
+    state: GameState,
+}
+
+impl StateSnapshot {
+    pub fn turn(&self) -> u32 {
+        self.state.turn
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum GamePhase {
-    InheritanceDisaster,  // Turns 1-3: "What did I walk into?"
-    OperationalTempo,     // Turns 4-12: "Just keep the lights on"
-    Discovery,            // Turns 13-16: "The auditors found what?"
+    InheritanceDisaster,  // Standard turns 1-3: "What did I walk into?"
+    OperationalTempo,     // Standard turns 4-12: "Just keep the lights on"
+    Discovery,            // Standard turns 13-16: "The auditors found what?"
     Ended(Ending),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Environment variable that sets the campaign length in turns - acts scale to fit
+pub const CAMPAIGN_TURNS_ENV: &str = "CISO_CAMPAIGN_TURNS";
+
+/// Environment variable that pins the first turn of Discovery - unset keeps it proportional
+pub const DISCOVERY_START_ENV: &str = "CISO_DISCOVERY_TURN";
+
+/// Campaign shape - where each act ends and how long a quarter runs
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Campaign {
+    pub inheritance_end: u32,  // Last turn of InheritanceDisaster
+    pub operational_end: u32,  // Last turn of OperationalTempo
+    pub final_turn: u32,       // Last turn of Discovery - the run ends after it
+    pub turns_per_quarter: u32,
+}
+
+impl Default for Campaign {
+    /// The standard sixteen-turn run
+    fn default() -> Self {
+        Self {
+            inheritance_end: 3,
+            operational_end: 12,
+            final_turn: 16,
+            turns_per_quarter: 4,
+        }
+    }
+}
+
+impl Campaign {
+    /// Custom campaign - every act needs at least one turn
+    pub fn new(inheritance_end: u32, operational_end: u32, final_turn: u32, turns_per_quarter: u32) -> Result<Self> {
+        if inheritance_end == 0
+            || operational_end <= inheritance_end
+            || final_turn <= operational_end
+            || turns_per_quarter == 0
+        {
+            return Err(GameError::InvalidAction);
+        }
+
+        Ok(Self { inheritance_end, operational_end, final_turn, turns_per_quarter })
+    }
+
+    /// A run of `total_turns`, with each act stretched in proportion to the standard sixteen
+    ///
+    /// Quarters keep their length, so a longer run just has more of them -
+    /// and most of the extra time lands in OperationalTempo.
+    pub fn with_length(total_turns: u32) -> Result<Self> {
+        let standard = Self::default();
+        let scale = |act_end: u32| (act_end as f64 * total_turns as f64 / standard.final_turn as f64).round() as u32;
+
+        Self::new(
+            scale(standard.inheritance_end).max(1),
+            scale(standard.operational_end),
+            total_turns,
+            standard.turns_per_quarter,
+        )
+    }
+
+    /// A run of `total_turns` whose Discovery opens on `discovery_start` instead of where
+    /// the proportions put it - OperationalTempo takes up the difference
+    pub fn with_discovery_start(total_turns: u32, discovery_start: u32) -> Result<Self> {
+        let scaled = Self::with_length(total_turns)?;
+        Self::new(
+            scaled.inheritance_end,
+            discovery_start.saturating_sub(1),
+            total_turns,
+            scaled.turns_per_quarter,
+        )
+    }
+
+    /// Shape from `CISO_CAMPAIGN_TURNS` and `CISO_DISCOVERY_TURN` - unset or unplayable keeps the standard run
+    pub fn from_env() -> Self {
+        let turns_from = |name: &str| std::env::var(name).ok().and_then(|v| v.trim().parse::<u32>().ok());
+        let total_turns = turns_from(CAMPAIGN_TURNS_ENV).unwrap_or(Self::default().final_turn);
+        match turns_from(DISCOVERY_START_ENV) {
+            Some(discovery_start) => Self::with_discovery_start(total_turns, discovery_start),
+            None => Self::with_length(total_turns),
+        }
+        .unwrap_or_default()
+    }
+
+    /// `turns` of the standard sixteen-turn run, stretched to this campaign's length
+    ///
+    /// Built-in and TOML content is written against the standard run - delays and
+    /// audit dates go through this so they keep their place in a longer or shorter one.
+    pub fn stretch(&self, turns: u32) -> u32 {
+        let standard = Self::default();
+        (turns as f64 * self.final_turn as f64 / standard.final_turn as f64).round() as u32
+    }
+
+    /// First turn of Discovery
+    pub fn discovery_start(&self) -> u32 {
+        self.operational_end + 1
+    }
+
+    /// Phase for a turn - `None` once the campaign is over
+    pub fn phase_for(&self, turn: u32) -> Option<GamePhase> {
+        if turn <= self.inheritance_end {
+            Some(GamePhase::InheritanceDisaster)
+        } else if turn <= self.operational_end {
+            Some(GamePhase::OperationalTempo)
+        } else if turn <= self.final_turn {
+            Some(GamePhase::Discovery)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_quarter_end(&self, turn: u32) -> bool {
+        turn.is_multiple_of(self.turns_per_quarter)
+    }
+
+    /// First turn of a quarter (1-based)
+    pub fn quarter_start(&self, quarter: u32) -> u32 {
+        quarter.saturating_sub(1) * self.turns_per_quarter + 1
+    }
+
+    /// The quarter on the books once `turn` has played - a quarter's last turn already rolls over
+    pub fn quarter_for(&self, turn: u32) -> u32 {
+        turn / self.turns_per_quarter + 1
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Ending {
     GoldenCISO,           // Top 5%: Nailed it
     LawsuitSurvivor,     // Middle 70%: You made it out alive
@@ -85,8 +423,80 @@ pub enum Ending {
     CriminalInvestigation, // Bottom 1%: Lawyer up
 }
 
+/// One check behind the ending - which tier it guards and whether you made it
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndingGate {
+    pub tier: Ending,
+    pub label: &'static str,
+    pub actual: String,
+    pub requirement: String,
+    pub passed: bool,
+}
+
+/// Exposure at which the risk-management score bottoms out - 150 exposure scores 50
+pub const RISK_SCORE_EXPOSURE_CEILING: f64 = 300.0;
+/// ARR that maxes out the growth half of the business score - the board's IPO number
+pub const BUSINESS_SCORE_ARR_TARGET: f64 = 20.0;
+
+/// Letter on the report card - it's the first thing anyone reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Grade {
+    A,
+    B,
+    C,
+    D,
+    F,
+}
+
+impl Grade {
+    pub fn for_score(total: f64) -> Self {
+        match total {
+            t if t >= 85.0 => Grade::A,
+            t if t >= 70.0 => Grade::B,
+            t if t >= 55.0 => Grade::C,
+            t if t >= 40.0 => Grade::D,
+            _ => Grade::F,
+        }
+    }
+}
+
+/// The tenure in five numbers, each 0-100 - the ending is the headline, this is the detail
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreReport {
+    pub risk_management: f64,
+    pub narrative_integrity: f64,
+    pub business_growth: f64,
+    pub compliance: f64,
+    pub board_relations: f64,
+    pub total: f64,
+    pub grade: Grade,
+}
+
+impl ScoreReport {
+    /// Risk and honesty weigh most - they're what the investigation reads first
+    pub const WEIGHTS: [(&'static str, f64); 5] = [
+        ("Risk management", 0.25),
+        ("Narrative integrity", 0.25),
+        ("Business growth", 0.2),
+        ("Compliance", 0.15),
+        ("Board relations", 0.15),
+    ];
+
+    /// Sub-scores paired with their labels, in `WEIGHTS` order
+    pub fn components(&self) -> [(&'static str, f64); 5] {
+        let scores = [
+            self.risk_management,
+            self.narrative_integrity,
+            self.business_growth,
+            self.compliance,
+            self.board_relations,
+        ];
+        std::array::from_fn(|i| (Self::WEIGHTS[i].0, scores[i]))
+    }
+}
+
 /// Active incidents - require response and management
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ActiveIncident {
     pub id: String,
     pub title: String,
@@ -106,7 +516,118 @@ pub struct ActiveIncident {
     pub timeline: Vec<IncidentTimelineEntry>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Ways to play an open incident, as the forecast sees them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseOption {
+    Escalate,
+    ContainQuietly,
+    Ignore,
+}
+
+impl ResponseOption {
+    pub const ALL: [ResponseOption; 3] = [
+        ResponseOption::Escalate,
+        ResponseOption::ContainQuietly,
+        ResponseOption::Ignore,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ResponseOption::Escalate => "Escalate to the board now",
+            ResponseOption::ContainQuietly => "Contain it quietly",
+            ResponseOption::Ignore => "Ignore it and hope",
+        }
+    }
+}
+
+/// Rough cost of one response path - an estimate, not a promise
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectedImpact {
+    pub reputation_delta: f64,       // Board credibility and industry standing, combined
+    pub arr_delta_millions: f64,
+    pub integrity_delta: f64,
+    pub disclosure_likelihood: f64,  // 0-1: Chance this ends up in front of customers
+}
+
+impl ActiveIncident {
+    /// Whether telling the board on `turn` still counts as telling them promptly
+    pub fn escalation_is_timely(&self, turn: u32) -> bool {
+        turn.saturating_sub(self.turn_detected) <= 1
+    }
+
+    /// Churn points this incident costs each time it lands in front of customers
+    fn public_churn(&self) -> f64 {
+        let impacted = self.customer_impact_count.unwrap_or(0) as f64;
+        (PUBLIC_INCIDENT_BASE_CHURN + impacted / 100_000.0 * CHURN_PER_100K_CUSTOMERS)
+            .min(MAX_INCIDENT_CHURN)
+    }
+
+    /// What each response path is likely to cost from here
+    ///
+    /// Severity scales everything; customer reach sets the ARR at stake; a disclosure
+    /// duty means silence only decides who tells the story.
+    pub fn forecast_outcomes(&self, state: &GameState) -> Vec<(ResponseOption, ProjectedImpact)> {
+        let weight = match self.severity {
+            IncidentSeverity::Critical => 1.0,
+            IncidentSeverity::High => 0.6,
+            IncidentSeverity::Medium => 0.3,
+            IncidentSeverity::Low => 0.1,
+        };
+        let arr_per_exposure = state.business.arr_millions * self.public_churn() / 100.0;
+        let delay = state.turn.saturating_sub(self.turn_detected) as f64;
+        let late = delay > 1.0;  // Same grace the board gives a real escalation
+        // What a leak costs: the 15 + 20 hit to standing and credibility, scaled
+        let leak_reputation = 35.0 * weight;
+
+        ResponseOption::ALL
+            .iter()
+            .map(|&option| {
+                let impact = match option {
+                    ResponseOption::Escalate => {
+                        let disclosure_likelihood = if self.public_disclosure_required { 1.0 } else { 0.25 * weight };
+                        ProjectedImpact {
+                            reputation_delta: if late { -10.0 * weight - delay } else { -5.0 * weight },
+                            arr_delta_millions: -arr_per_exposure * disclosure_likelihood,
+                            integrity_delta: if late { -delay * 5.0 } else { 0.0 },
+                            disclosure_likelihood,
+                        }
+                    }
+                    ResponseOption::ContainQuietly => {
+                        let disclosure_likelihood = if self.public_disclosure_required {
+                            0.8
+                        } else {
+                            (LEAK_BASE_CHANCE + LEAK_CHANCE_PER_TURN * (delay + 2.0)).min(LEAK_MAX_CHANCE)
+                        };
+                        ProjectedImpact {
+                            reputation_delta: -leak_reputation * disclosure_likelihood,
+                            arr_delta_millions: -arr_per_exposure * disclosure_likelihood,
+                            integrity_delta: -LEAK_INTEGRITY_PENALTY * weight * disclosure_likelihood,
+                            disclosure_likelihood,
+                        }
+                    }
+                    ResponseOption::Ignore => {
+                        let disclosure_likelihood = if self.public_disclosure_required {
+                            1.0
+                        } else {
+                            (LEAK_MAX_CHANCE * weight).max(LEAK_BASE_CHANCE)
+                        };
+                        // Nobody working it means it also blows through the deadline
+                        let exposures = if self.public_disclosure_required { 2.0 } else { 1.0 };
+                        ProjectedImpact {
+                            reputation_delta: -(leak_reputation + 10.0 * weight) * disclosure_likelihood,
+                            arr_delta_millions: -arr_per_exposure * exposures * disclosure_likelihood,
+                            integrity_delta: -(LEAK_INTEGRITY_PENALTY + 10.0) * weight * disclosure_likelihood,
+                            disclosure_likelihood,
+                        }
+                    }
+                };
+                (option, impact)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IncidentTimelineEntry {
     pub turn: u32,
     pub action: String,
@@ -125,7 +646,22 @@ pub enum IncidentResponseStatus {
     Closed,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl IncidentResponseStatus {
+    /// The stage after this one - Closed is as far as it goes
+    pub fn next(self) -> Self {
+        use IncidentResponseStatus::*;
+        match self {
+            Detected => Investigating,
+            Investigating => Containing,
+            Containing => Eradicating,
+            Eradicating => Recovering,
+            Recovering => PostMortem,
+            PostMortem | Closed => Closed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResolvedIncident {
     pub id: String,
     pub original_incident: String,
@@ -135,10 +671,14 @@ pub struct ResolvedIncident {
     pub follow_up_actions: Vec<String>,
     pub final_cost: f64,
     pub reputation_impact: f64,
+    #[serde(default)]
+    pub severity: Option<IncidentSeverity>,  // None for saves from before this was tracked
+    #[serde(default)]
+    pub title: Option<String>,  // Likewise
 }
 
 /// Objectives - what the board expects you to accomplish
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Objective {
     pub id: String,
     pub description: String,
@@ -158,15 +698,15 @@ pub enum ObjectivePriority {
 }
 
 /// Technical debt - the gift that keeps on giving
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TechnicalDebt {
     pub total_debt_points: f64,
     pub debt_velocity: f64,  // How fast debt is growing
-    pub categories: HashMap<DebtCategory, f64>,
+    pub categories: BTreeMap<DebtCategory, f64>,
     pub oldest_debt_age_turns: u32,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum DebtCategory {
     UnpatchedSystems,
     LegacyAccess,
@@ -176,9 +716,32 @@ pub enum DebtCategory {
     ComplianceGaps,
 }
 
+impl DebtCategory {
+    pub const ALL: [DebtCategory; 6] = [
+        DebtCategory::UnpatchedSystems,
+        DebtCategory::LegacyAccess,
+        DebtCategory::UndocumentedProcesses,
+        DebtCategory::ToolingGaps,
+        DebtCategory::ArchitecturalFlaws,
+        DebtCategory::ComplianceGaps,
+    ];
+
+    /// The risk this pile of debt feeds - paying it down starves that vector
+    pub fn risk_vector(&self) -> RiskVector {
+        match self {
+            DebtCategory::UnpatchedSystems => RiskVector::DataExposure,
+            DebtCategory::LegacyAccess => RiskVector::AccessControl,
+            DebtCategory::UndocumentedProcesses => RiskVector::InsiderThreat,
+            DebtCategory::ToolingGaps => RiskVector::Detection,
+            DebtCategory::ArchitecturalFlaws => RiskVector::CloudMisconfiguration,
+            DebtCategory::ComplianceGaps => RiskVector::VendorRisk,
+        }
+    }
+}
+
 impl TechnicalDebt {
     pub fn new() -> Self {
-        let mut categories = HashMap::new();
+        let mut categories = BTreeMap::new();
         categories.insert(DebtCategory::UnpatchedSystems, 40.0);
         categories.insert(DebtCategory::LegacyAccess, 30.0);
         categories.insert(DebtCategory::UndocumentedProcesses, 25.0);
@@ -220,7 +783,13 @@ impl TechnicalDebt {
 }
 
 impl GameState {
-    pub fn new(player: Player) -> Self {
+    pub fn new(player: Player, campaign: Campaign) -> Self {
+        Self::new_seeded(player, campaign, rand::random())
+    }
+
+    /// Same seed and same choices, same run - what replays are built on
+    pub fn new_seeded(player: Player, campaign: Campaign, seed: u64) -> Self {
+        let mut setup_rng = StdRng::seed_from_u64(seed);
         let mut events = Vec::new();
         events.push(Event {
             timestamp: Utc::now(),
@@ -231,30 +800,44 @@ impl GameState {
                 Exit interview mentions: 'Board expectations unrealistic', 'Budget insufficient', \
                 'Nobody listened until after the breach'.",
                 player.name, player.company_name,
-                if rand::random::<bool>() { "Richard" } else { "Susan" }
+                if setup_rng.gen_bool(0.5) { "Richard" } else { "Susan" }
             ),
             decision_id: None,
             visibility: EventVisibility::Management,
-            metadata: HashMap::new(),
+            metadata: BTreeMap::new(),
         });
 
-        // Initialize board with personalities
-        let board = Self::initialize_board();
+        // Initialize board with personalities - the industry sets some agendas
+        let profile = player.profile;
+        let mut board = Self::initialize_board();
+        for &(role, priority) in profile.board_priorities() {
+            for member in board.iter_mut().filter(|m| m.role == role) {
+                member.current_priority = priority;
+            }
+        }
+        let mut risk = RiskLevel::new();
+        risk.apply_delta(&profile.starting_risk());
 
         // Set initial quarterly objectives
         let quarterly_objectives = Self::initial_objectives(&board);
 
+        // Audit dates are set for the standard run - a longer one gets longer lead times
+        let mut compliance = ComplianceStatus::for_profile(profile);
+        for status in compliance.frameworks.values_mut() {
+            status.next_audit = campaign.stretch(status.next_audit);
+        }
+
         Self {
             player,
             turn: 1,
             quarter: 1,
-            risk: RiskLevel::new(),
+            risk,
             business: BusinessMetrics::new(),
             narrative: NarrativeIntegrity::new(),
             budget: Budget::new(),
             political_capital: PoliticalCapital::new(),
             team: SecurityTeam::new(),
-            compliance: ComplianceStatus::new(),
+            compliance,
             threat_landscape: ThreatLandscape::new(),
             board,
             events,
@@ -264,7 +847,64 @@ impl GameState {
             phase: GamePhase::InheritanceDisaster,
             quarterly_objectives,
             technical_debt: TechnicalDebt::new(),
+            rewind_tokens: Self::standard_rewind_tokens(),
+            tutorial: false,
+            campaign,
+            exposure_history: VecDeque::new(),
+            emergency_budget_requests: 0,
+            rng_seed: seed,
+            incident_catalog: IncidentCatalog::builtin(),
+            spilled_events: 0,
+            unflushed_events: Vec::new(),
+            pending_consequences: Vec::new(),
+            difficulty: Difficulty::Standard,
+            metrics_history: Vec::new(),
+        }
+    }
+
+    /// A fresh run at the chosen difficulty
+    pub fn new_with_difficulty(player: Player, campaign: Campaign, difficulty: Difficulty) -> Self {
+        Self::new(player, campaign).with_difficulty(difficulty)
+    }
+
+    /// Rescale a fresh run's inheritance - only meaningful before the first turn is played
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        let budget_scale = difficulty.budget_scale();
+        let budget = &mut self.budget;
+        for line in [
+            &mut budget.total_annual,
+            &mut budget.committed,
+            &mut budget.headcount_budget,
+            &mut budget.tooling_budget,
+            &mut budget.project_budget,
+            &mut budget.emergency_reserve,
+        ] {
+            *line *= budget_scale;
+        }
+
+        let debt_scale = difficulty.debt_scale();
+        self.technical_debt.total_debt_points *= debt_scale;
+        for points in self.technical_debt.categories.values_mut() {
+            *points *= debt_scale;
         }
+
+        let (morale, capacity) = difficulty.team_adjustment();
+        self.team.morale = (self.team.morale + morale).clamp(0.0, 100.0);
+        self.team.total_capacity = (self.team.total_capacity + capacity).max(self.team.committed_capacity);
+
+        self.risk.decay_rate = difficulty.risk_decay_rate();
+        self.rewind_tokens = difficulty.rewind_tokens();
+        self.difficulty = difficulty;
+        self
+    }
+
+    fn standard_rewind_tokens() -> u32 {
+        Difficulty::Standard.rewind_tokens()
+    }
+
+    /// This turn's dice - derived from the seed so a replay rolls the same numbers
+    fn turn_rng(&self) -> StdRng {
+        StdRng::seed_from_u64(self.rng_seed ^ u64::from(self.turn).wrapping_mul(TURN_SEED_STRIDE))
     }
 
     fn initialize_board() -> Vec<BoardMember> {
@@ -276,6 +916,7 @@ impl GameState {
                 current_priority: BoardPriority::GrowthAtAllCosts,
                 satisfaction: 70.0,
                 influence: 95.0,
+                risk_sensitivity: BoardMember::CALM_SENSITIVITY,
             },
             BoardMember {
                 role: BoardMemberRole::CFO,
@@ -284,6 +925,7 @@ impl GameState {
                 current_priority: BoardPriority::CostReduction,
                 satisfaction: 60.0,
                 influence: 80.0,
+                risk_sensitivity: BoardMember::CALM_SENSITIVITY,
             },
             BoardMember {
                 role: BoardMemberRole::CTO,
@@ -292,6 +934,7 @@ impl GameState {
                 current_priority: BoardPriority::RiskMitigation,
                 satisfaction: 50.0,  // Skeptical of new CISO
                 influence: 75.0,
+                risk_sensitivity: BoardMember::CALM_SENSITIVITY,
             },
             BoardMember {
                 role: BoardMemberRole::GeneralCounsel,
@@ -300,6 +943,25 @@ impl GameState {
                 current_priority: BoardPriority::ComplianceFirst,
                 satisfaction: 55.0,
                 influence: 70.0,
+                risk_sensitivity: BoardMember::CALM_SENSITIVITY,
+            },
+            BoardMember {
+                role: BoardMemberRole::COO,
+                name: "Samuel Okafor".to_string(),
+                personality: BoardPersonality::DataDriven,
+                current_priority: BoardPriority::CustomerTrust,
+                satisfaction: 60.0,
+                influence: 65.0,  // Owns the customer escalations
+                risk_sensitivity: BoardMember::CALM_SENSITIVITY,
+            },
+            BoardMember {
+                role: BoardMemberRole::BoardChair,
+                name: "Eleanor Voss".to_string(),
+                personality: BoardPersonality::Fiduciary,
+                current_priority: BoardPriority::IpoPreparation,
+                satisfaction: 65.0,
+                influence: 90.0,  // Speaks for the investors
+                risk_sensitivity: BoardMember::CALM_SENSITIVITY,
             },
         ]
     }
@@ -329,7 +991,7 @@ impl GameState {
 
     pub fn add_event(&mut self, event_type: EventType, description: String, 
                      decision_id: Option<String>, visibility: EventVisibility) {
-        let mut metadata = HashMap::new();
+        let mut metadata = BTreeMap::new();
         metadata.insert("phase".to_string(), format!("{:?}", self.phase));
         metadata.insert("quarter".to_string(), self.quarter.to_string());
 
@@ -342,19 +1004,51 @@ impl GameState {
             visibility,
             metadata,
         });
+
+        // Keep the save small - the oldest events wait in `unflushed_events` for the overflow log
+        if self.events.len() > EVENT_WINDOW {
+            let excess = self.events.len() - EVENT_WINDOW;
+            self.unflushed_events.extend(self.events.drain(..excess));
+            self.spilled_events += excess;
+        }
     }
 
-    pub fn advance_turn(&mut self) {
+    /// Spilled events as they stand now - disclosures made after the spill still apply
+    pub fn reconcile_disclosures(&self, events: &mut [Event]) {
+        let disclosed: Vec<&str> = self.narrative.buried_incidents.iter()
+            .filter(|b| b.turn_disclosed.is_some())
+            .map(|b| b.incident_id.as_str())
+            .collect();
+
+        for event in events {
+            if event.visibility == EventVisibility::Buried
+                && event.metadata.get("incident_id").is_some_and(|id| disclosed.contains(&id.as_str())) {
+                event.visibility = EventVisibility::Public;
+            }
+        }
+    }
+
+    /// Move to the next turn - returns the alerts for anything that materialized on the way
+    pub fn advance_turn(&mut self) -> Vec<String> {
         self.turn += 1;
         
         // Natural processes
         self.risk.apply_decay(self.turn);
         self.risk.calculate_cascade_effects();
-        self.threat_landscape.evolve(self.turn);
+        self.refresh_mttd();
+        self.record_exposure_sample();
+        let mut rng = self.turn_rng();
+        self.threat_landscape.evolve(self.turn, &mut rng);
+        self.apply_campaign_pressure();
         self.technical_debt.total_debt_points += self.technical_debt.debt_velocity;
+        for status in self.compliance.frameworks.values_mut() {
+            status.decay_unsubstantiated();
+        }
         
         // Check for team attrition
-        let departed = self.team.check_attrition(self.turn);
+        self.team.return_from_leave();
+        self.team.accumulate_strain();
+        let departed = self.team.check_attrition(self.turn, &mut rng);
         for name in departed {
             self.add_event(
                 EventType::TeamMemberDeparted,
@@ -362,125 +1056,601 @@ impl GameState {
                 None,
                 EventVisibility::Internal,
             );
-            self.team.total_capacity -= 8.0;  // Losing someone hurts
-            self.team.morale -= 10.0;
+            self.team.total_capacity = (self.team.total_capacity - 8.0).max(0.0);  // Losing someone hurts
+            self.team.committed_capacity = self.team.committed_capacity.min(self.team.total_capacity);
+            self.team.morale = (self.team.morale - 10.0).max(0.0);
         }
 
+        // Assigned responders chip away at open incidents
+        let _contained = self.work_incidents();
+        let _incident_costs = self.charge_incident_costs();
+
         // Check for risk materialization
-        let _materialized = self.check_risk_materialization();
+        let materialized = self.check_risk_materialization();
+
+        // Earlier choices come due
+        let _fired = self.fire_delayed_consequences();
+
+        // Secrets don't keep - least of all from a burnt-out team
+        let _leaked = self.check_buried_leaks(&mut rng);
+        let _reported = self.check_whistleblower(&mut rng);
+
+        // Somebody else's bad week
+        if rng.gen_bool(INDUSTRY_BREACH_CHANCE) {
+            let _headline = self.break_industry_news();
+        }
+        self.refresh_board_sensitivity();
+
+        // Auditors show up whether you're ready or not
+        self.conduct_scheduled_audits();
+        self.enforce_remediation_deadlines();
+
+        // Missed deadlines leak to customers; lost customers take revenue with them
+        self.apply_deadline_breach_churn();
+        self.apply_churn_pressure();
+        let _organic = self.business.apply_organic_growth();
+        let arr_lost = self.business.apply_churn_erosion();
+        if arr_lost > 0.0 {
+            self.add_event(
+                EventType::ReputationChange,
+                format!(
+                    "Customer churn at {:.1}% cost ${:.2}M ARR this turn",
+                    self.business.customer_churn_probability, arr_lost
+                ),
+                None,
+                EventVisibility::Management,
+            );
+        }
         
         // Update phase
-        self.phase = match self.turn {
-            1..=3 => GamePhase::InheritanceDisaster,
-            4..=12 => GamePhase::OperationalTempo,
-            13..=16 => GamePhase::Discovery,
-            _ => {
-                let ending = self.calculate_ending();
-                GamePhase::Ended(ending)
-            }
-        };
+        self.update_phase();
 
         // Quarter boundaries - THE MOST STRESSFUL MOMENTS
-        if self.turn % 4 == 0 {
+        if self.campaign.is_quarter_end(self.turn) {
             self.conduct_quarterly_review();
         }
-    }
+        self.record_turn_snapshot();
 
-    /// Quarterly review - where careers are made or ended
-    fn conduct_quarterly_review(&mut self) {
-        self.quarter += 1;
-        
-        self.add_event(
-            EventType::QuarterEnd,
-            format!("Q{} ends. Board review in progress...", self.quarter - 1),
-            None,
-            EventVisibility::Board,
+        debug_assert!(
+            self.invariant_violation().is_none(),
+            "turn {} left the state invalid: {:?}", self.turn, self.invariant_violation()
         );
 
-        // Reset political capital tracking
-        self.political_capital.quarterly_reset();
+        materialized
+    }
 
-        // Evaluate objectives
-        let mut objectives_met = 0;
-        let mut critical_objectives_missed = Vec::new();
+    /// Land every consequence whose turn has come - returns how many fired
+    pub fn fire_delayed_consequences(&mut self) -> usize {
+        let (due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_consequences)
+            .into_iter()
+            .partition(|c| c.trigger_turn <= self.turn);
+        self.pending_consequences = pending;
 
-        for objective in &mut self.quarterly_objectives {
-            if objective.progress >= 100.0 && objective.completion_turn.is_none() {
-                objective.completion_turn = Some(self.turn);
-                objectives_met += 1;
-            } else if objective.priority == ObjectivePriority::Critical && objective.progress < 50.0 {
-                critical_objectives_missed.push(objective.description.clone());
+        for consequence in &due {
+            if let Some(impact) = &consequence.additional_impact {
+                self.apply_decision_impact(impact);
             }
+            self.add_event(
+                consequence.event_type.clone(),
+                consequence.description.clone(),
+                consequence.additional_impact.as_ref().map(|i| i.decision_id.clone()),
+                EventVisibility::Management,
+            );
         }
+        due.len()
+    }
 
-        // Board member reactions
-        let mut board_feedback = Vec::new();
+    /// Range checks on everything that should stay clamped - `StateCorruption` if anything drifted
+    pub fn validate_invariants(&self) -> Result<()> {
+        match self.invariant_violation() {
+            Some(_) => Err(GameError::StateCorruption),
+            None => Ok(()),
+        }
+    }
+
+    /// First out-of-range field, described for humans
+    fn invariant_violation(&self) -> Option<String> {
+        fn percent(name: &str, value: f64) -> Option<String> {
+            (!(0.0..=100.0).contains(&value)).then(|| format!("{} = {} is outside 0-100", name, value))
+        }
+        fn non_negative(name: &str, value: f64) -> Option<String> {
+            (value.is_nan() || value < 0.0).then(|| format!("{} = {} is negative", name, value))
+        }
+
+        let reputation = &self.player.reputation;
+        let mut checks = vec![
+            percent("reputation.industry_standing", reputation.industry_standing),
+            percent("reputation.board_credibility", reputation.board_credibility),
+            percent("reputation.team_morale", reputation.team_morale),
+            percent("reputation.vendor_relationships", reputation.vendor_relationships),
+            percent("business.board_confidence_percent", self.business.board_confidence_percent),
+            percent("business.regulatory_compliance_score", self.business.regulatory_compliance_score),
+            percent("team.morale", self.team.morale),
+            non_negative("budget.total_annual", self.budget.total_annual),
+            non_negative("budget.spent", self.budget.spent),
+            non_negative("budget.committed", self.budget.committed),
+            non_negative("budget.headcount_budget", self.budget.headcount_budget),
+            non_negative("budget.tooling_budget", self.budget.tooling_budget),
+            non_negative("budget.project_budget", self.budget.project_budget),
+            non_negative("budget.emergency_reserve", self.budget.emergency_reserve),
+            non_negative("team.total_capacity", self.team.total_capacity),
+        ];
+        if self.team.committed_capacity > self.team.total_capacity {
+            checks.push(Some(format!(
+                "team.committed_capacity = {} exceeds total_capacity = {}",
+                self.team.committed_capacity, self.team.total_capacity
+            )));
+        }
         for member in &self.board {
-            let reaction = self.evaluate_board_member_satisfaction(member);
-            board_feedback.push(format!("{} ({}): {}", 
-                member.name, 
-                format!("{:?}", member.role).replace('_', " "),
-                reaction
-            ));
+            checks.push(percent(&format!("board[{:?}].satisfaction", member.role), member.satisfaction));
+        }
+        for (framework, status) in &self.compliance.frameworks {
+            checks.push(percent(&format!("compliance[{:?}]", framework), status.compliance_percent));
+            if status.unsubstantiated_percent > status.compliance_percent {
+                checks.push(Some(format!(
+                    "compliance[{:?}].unsubstantiated_percent = {} exceeds compliance_percent = {}",
+                    framework, status.unsubstantiated_percent, status.compliance_percent
+                )));
+            }
+        }
+        for (vector, metric) in &self.risk.vectors {
+            checks.push(percent(&format!("risk[{:?}].current_level", vector), metric.current_level));
+            checks.push(percent(&format!("risk[{:?}].mitigation_coverage", vector), metric.mitigation_coverage));
         }
 
-        // Calculate political capital earned/lost
-        let capital_change = if objectives_met > 0 {
-            let gain = objectives_met as f64 * 10.0;
-            self.political_capital.earn(gain, "Quarterly objectives met".to_string());
-            gain
-        } else {
-            let loss = critical_objectives_missed.len() as f64 * 15.0;
-            self.political_capital.total = (self.political_capital.total - loss).max(0.0);
-            -loss
+        checks.into_iter().flatten().next()
+    }
+
+    /// File this turn's headline numbers for the metrics export
+    fn record_turn_snapshot(&mut self) {
+        self.metrics_history.push(TurnSnapshot {
+            turn: self.turn,
+            arr_millions: self.business.arr_millions,
+            total_exposure: self.risk.total_exposure,
+            narrative_score: self.narrative.score,
+            board_confidence_percent: self.business.board_confidence_percent,
+            budget_available: self.budget.available(),
+            team_morale: self.team.morale,
+        });
+    }
+
+    /// Push this turn's exposure onto the trend buffer, dropping the oldest past the cap
+    fn record_exposure_sample(&mut self) {
+        if self.exposure_history.len() == EXPOSURE_HISTORY_LEN {
+            self.exposure_history.pop_front();
+        }
+        self.exposure_history.push_back(self.risk.total_exposure);
+    }
+
+    /// Recompute MTTD - coverage sets the pace, incidents nobody has triaged drag it out
+    ///
+    /// Also moves the Q3 MTTD objective, which is measured on the way down from fully blind.
+    pub fn refresh_mttd(&mut self) {
+        let untriaged = self.active_incidents.iter()
+            .filter(|i| i.response_status == IncidentResponseStatus::Detected && i.turn_detected < self.turn)
+            .count();
+        self.risk.mttd_hours = self.risk.coverage_mttd() + untriaged as f64 * MTTD_UNTRIAGED_PENALTY_HOURS;
+
+        let progress = (MTTD_BLIND_HOURS - self.risk.mttd_hours) / (MTTD_BLIND_HOURS - MTTD_OBJECTIVE_HOURS) * 100.0;
+        for objective in &mut self.quarterly_objectives {
+            if objective.id == MTTD_OBJECTIVE_ID && objective.completion_turn.is_none() {
+                objective.progress = progress.clamp(0.0, 100.0);
+            }
+        }
+    }
+
+    /// Read open objectives off the live metrics they're about - finished ones stay put
+    ///
+    /// MTTD is left to `refresh_mttd`, which moves it every time detection does.
+    pub fn update_objective_progress(&mut self) {
+        let soc2 = self.compliance.frameworks.get(&ComplianceFramework::SOC2)
+            .map_or(0.0, |status| status.compliance_percent);
+        let incidents = self.resolved_incidents.len() + self.active_incidents.len();
+        let resolved_share = match incidents {
+            0 => 0.0,  // Nothing closed out is nothing to show the board
+            n => self.resolved_incidents.len() as f64 / n as f64 * 100.0,
         };
+        let mfa = self.risk.vectors.get(&RiskVector::AccessControl)
+            .map_or(0.0, |v| v.mitigation_coverage / MFA_OBJECTIVE_COVERAGE * 100.0);
 
-        // Generate new objectives for next quarter
-        self.generate_next_quarter_objectives();
+        for objective in &mut self.quarterly_objectives {
+            if objective.completion_turn.is_some() {
+                continue;
+            }
+            let progress = match objective.id.as_str() {
+                SOC2_OBJECTIVE_ID => soc2,
+                INCIDENT_OBJECTIVE_ID => resolved_share,
+                MFA_OBJECTIVE_ID => mfa,
+                id => match ComplianceFramework::ALL.iter()
+                    .find(|f| Self::framework_objective_id(**f) == id)
+                    .and_then(|f| self.compliance.frameworks.get(f))
+                {
+                    Some(status) => status.compliance_percent,
+                    None => continue,
+                },
+            };
+            objective.progress = progress.clamp(0.0, 100.0);
+        }
+    }
 
-        // Record review event
+    /// Phase follows the turn counter - past the last act, the audit begins
+    pub(crate) fn update_phase(&mut self) {
+        self.phase = match self.campaign.phase_for(self.turn) {
+            Some(phase) => phase,
+            None => GamePhase::Ended(self.calculate_ending()),
+        };
+    }
+
+    fn framework_objective_id(framework: ComplianceFramework) -> String {
+        format!("{:?}{}", framework, FRAMEWORK_OBJECTIVE_SUFFIX).to_lowercase()
+    }
+
+    /// The business signed up for a new regulator - schedule its audit and put readiness on the board's list
+    pub fn bring_framework_into_scope(&mut self, framework: ComplianceFramework) {
+        if !self.compliance.bring_into_scope(framework, self.turn) {
+            return;
+        }
+        let first_audit = self.turn + FrameworkStatus::FIRST_AUDIT_LEAD;
+
+        self.quarterly_objectives.push(Objective {
+            id: Self::framework_objective_id(framework),
+            description: format!("Get {:?} audit-ready before turn {}", framework, first_audit),
+            assigned_quarter: self.quarter,
+            priority: ObjectivePriority::High,
+            progress: FrameworkStatus::SCOPE_BASELINE,
+            completion_turn: None,
+            assigned_by: BoardMemberRole::GeneralCounsel,
+        });
         self.add_event(
-            EventType::BoardReview,
-            format!(
-                "Q{} Board Review:\n- Objectives met: {}\n- Critical misses: {}\n- Political capital: {:+.0}\n\nBoard feedback:\n{}",
-                self.quarter - 1,
-                objectives_met,
-                critical_objectives_missed.len(),
-                capital_change,
-                board_feedback.join("\n")
-            ),
+            EventType::ComplianceAudit,
+            format!("{:?} is now in scope. First audit scheduled for turn {}.", framework, first_audit),
             None,
-            EventVisibility::Board,
+            EventVisibility::Management,
         );
     }
 
-    fn evaluate_board_member_satisfaction(&self, member: &BoardMember) -> String {
-        match member.satisfaction {
-            s if s > 80.0 => {
-                match member.personality {
-                    BoardPersonality::DataDriven => "Excellent metrics. Keep it up.".to_string(),
-                    BoardPersonality::PoliticallyShrewd => "The board is impressed with your progress.".to_string(),
-                    BoardPersonality::TechnicallyMinded => "Finally, someone who gets it.".to_string(),
-                    BoardPersonality::BottomLineFocused => "ROI is acceptable.".to_string(),
-                    BoardPersonality::RiskAverse => "I'm sleeping better at night.".to_string(),
-                }
-            }
-            s if s > 50.0 => {
-                match member.personality {
-                    BoardPersonality::DataDriven => "Show me more data on your progress.".to_string(),
-                    BoardPersonality::PoliticallyShrewd => "We need to discuss your approach.".to_string(),
-                    BoardPersonality::TechnicallyMinded => "The technical debt concerns me.".to_string(),
-                    BoardPersonality::BottomLineFocused => "Your budget utilization needs work.".to_string(),
-                    BoardPersonality::RiskAverse => "I'm not comfortable with current risk levels.".to_string(),
+    /// Run every framework audit due this turn - certify, renew, or revoke
+    fn conduct_scheduled_audits(&mut self) {
+        let mut due: Vec<ComplianceFramework> = self.compliance.frameworks.iter()
+            .filter(|(_, status)| status.next_audit == self.turn)
+            .map(|(framework, _)| *framework)
+            .collect();
+        due.sort();
+
+        for framework in due {
+            let turn = self.turn;
+            let Some(status) = self.compliance.frameworks.get_mut(&framework) else { continue };
+            let was_certified = status.is_certified();
+            let percent = status.compliance_percent;
+            let paper = status.unsubstantiated_percent;
+            let passed = percent >= FrameworkStatus::CERTIFICATION_THRESHOLD;
+
+            if passed {
+                if !was_certified {
+                    status.certification_date = Some(turn);
                 }
+                status.next_audit = turn + FrameworkStatus::RECERTIFICATION_INTERVAL;
+            } else {
+                status.certification_date = None;
+                status.next_audit = turn + FrameworkStatus::FOLLOW_UP_INTERVAL;
             }
-            _ => {
+            let next_audit = status.next_audit;
+
+            self.add_event(
+                EventType::ComplianceAudit,
+                format!(
+                    "{:?} audit: {:.0}% compliant ({}). Next audit turn {}.",
+                    framework,
+                    percent,
+                    if passed { "passed" } else { "failed" },
+                    next_audit
+                ),
+                None,
+                EventVisibility::Board,
+            );
+
+            match (was_certified, passed) {
+                (false, true) => self.add_event(
+                    EventType::CertificationGranted,
+                    format!("{:?} certification granted. Sales can finally stop apologizing.", framework),
+                    None,
+                    EventVisibility::Board,
+                ),
+                (true, false) => self.add_event(
+                    EventType::CertificationLapsed,
+                    format!("{:?} certification lapsed after a failed surveillance audit.", framework),
+                    None,
+                    EventVisibility::Board,
+                ),
+                _ => {}
+            }
+
+            if !passed {
+                self.open_audit_findings(framework);
+            }
+
+            // Auditors sample controls - the more of the number is paper, the more they find
+            if paper >= PAPER_FINDING_THRESHOLD {
+                self.raise_paper_finding(framework, paper);
+            }
+        }
+    }
+
+    /// The auditor writes up every control gap not already on the books - the further short, the worse the findings
+    fn open_audit_findings(&mut self, framework: ComplianceFramework) {
+        let turn = self.turn;
+        let Some(status) = self.compliance.frameworks.get(&framework) else { return };
+        let gap = FrameworkStatus::CERTIFICATION_THRESHOLD - status.compliance_percent;
+        if gap <= 0.0 {
+            return;
+        }
+
+        let severity = FindingSeverity::for_gap(gap);
+        let gaps = match status.control_gaps.as_slice() {
+            [] => vec![format!("{:?} controls {:.0} points short of certification", framework, gap)],
+            listed => listed.to_vec(),
+        };
+        let already_open = |description: &String| self.compliance.open_findings.iter().any(|f| {
+            f.framework == framework
+                && f.description == *description
+                && matches!(f.status, FindingStatus::Open | FindingStatus::InProgress | FindingStatus::Ignored)
+        });
+        let gaps: Vec<String> = gaps.into_iter().filter(|g| !already_open(g)).collect();
+
+        for (n, description) in gaps.into_iter().enumerate() {
+            let id = format!("{:?}_audit_t{}_{}", framework, turn, n + 1).to_lowercase();
+            self.compliance.open_findings.push(ComplianceFinding {
+                id: id.clone(),
+                framework,
+                severity,
+                description,
+                discovered_turn: turn,
+                remediation_deadline: turn + FINDING_REMEDIATION_TURNS,
+                status: FindingStatus::Open,
+                accepted_by: None,
+                accepted_turn: None,
+            });
+            self.add_event(
+                EventType::ComplianceFindingOpened,
+                format!("{} opened a {:?} {:?} finding, due turn {}", framework.auditor(), severity, framework, turn + FINDING_REMEDIATION_TURNS),
+                None,
+                EventVisibility::Board,
+            );
+            if let Some(event) = self.events.last_mut() {
+                event.metadata.insert("finding_id".to_string(), id);
+            }
+        }
+    }
+
+    /// Findings still open at their deadline - the exposure grows, the board notices, and
+    /// the gap gets exploited. A signed-off acceptance skips the reprimand, not the breach.
+    fn enforce_remediation_deadlines(&mut self) {
+        let overdue: Vec<ComplianceFinding> = self.compliance.open_findings.iter()
+            .filter(|f| f.remediation_deadline == self.turn && f.status != FindingStatus::Resolved)
+            .cloned()
+            .collect();
+
+        for finding in overdue {
+            if finding.status != FindingStatus::Accepted {
+                let mut delta = RiskDelta::new();
+                delta.add_change(finding.framework.risk_vector(), MISSED_REMEDIATION_RISK, 0.0, 1.0);
+                self.risk.apply_delta(&delta);
+                self.business.board_confidence_percent =
+                    (self.business.board_confidence_percent - finding.severity.missed_deadline_confidence()).max(0.0);
+                self.add_event(
+                    EventType::BoardPressure,
+                    format!("{:?} finding '{}' missed its remediation deadline", finding.framework, finding.description),
+                    None,
+                    EventVisibility::Board,
+                );
+                if let Some(event) = self.events.last_mut() {
+                    event.metadata.insert("finding_id".to_string(), finding.id.clone());
+                }
+            }
+            let _ = self.materialize_finding(&finding.id);
+        }
+    }
+
+    /// Close a finding for real - the control gap it described goes with it
+    pub fn resolve_finding(&mut self, finding_id: &str) -> Result<()> {
+        let finding = self.compliance.open_findings.iter_mut()
+            .find(|f| f.id == finding_id)
+            .ok_or(GameError::InvalidAction)?;
+        if finding.status == FindingStatus::Resolved {
+            return Err(GameError::InvalidAction);
+        }
+        finding.status = FindingStatus::Resolved;
+        let (framework, description) = (finding.framework, finding.description.clone());
+
+        if let Some(status) = self.compliance.frameworks.get_mut(&framework) {
+            status.control_gaps.retain(|gap| *gap != description);
+        }
+        self.add_event(
+            EventType::ComplianceFindingClosed,
+            format!("{:?} finding remediated: {}", framework, description),
+            None,
+            EventVisibility::Board,
+        );
+        if let Some(event) = self.events.last_mut() {
+            event.metadata.insert("finding_id".to_string(), finding_id.to_string());
+        }
+        Ok(())
+    }
+
+    fn raise_paper_finding(&mut self, framework: ComplianceFramework, paper: f64) {
+        let id = format!("{:?}_paper_controls_t{}", framework, self.turn).to_lowercase();
+        let severity = if paper >= PAPER_FINDING_HIGH_THRESHOLD {
+            FindingSeverity::High
+        } else {
+            FindingSeverity::Medium
+        };
+
+        self.compliance.open_findings.push(ComplianceFinding {
+            id: id.clone(),
+            framework,
+            severity,
+            description: format!(
+                "Documented controls don't match operation - {:.0} points of {:?} compliance unsupported by evidence",
+                paper, framework
+            ),
+            discovered_turn: self.turn,
+            remediation_deadline: self.turn + FrameworkStatus::FOLLOW_UP_INTERVAL,
+            status: FindingStatus::Open,
+            accepted_by: None,
+            accepted_turn: None,
+        });
+        self.add_event(
+            EventType::ComplianceAudit,
+            format!("Auditor sampled {:?} controls and found paper where evidence should be ({:?} finding)", framework, severity),
+            None,
+            EventVisibility::Board,
+        );
+        if let Some(event) = self.events.last_mut() {
+            event.metadata.insert("finding_id".to_string(), id);
+        }
+    }
+
+    /// Quarterly review - where careers are made or ended
+    fn conduct_quarterly_review(&mut self) {
+        self.quarter += 1;
+        
+        self.add_event(
+            EventType::QuarterEnd,
+            format!("Q{} ends. Board review in progress...", self.quarter - 1),
+            None,
+            EventVisibility::Board,
+        );
+
+        // Reset political capital tracking
+        self.political_capital.quarterly_reset();
+        self.team.boosts_this_quarter = 0;
+
+        // Evaluate objectives
+        self.refresh_mttd();
+        self.update_objective_progress();
+        let mut objectives_met = 0;
+        let mut critical_objectives_missed = Vec::new();
+
+        for objective in &mut self.quarterly_objectives {
+            if objective.progress >= 100.0 && objective.completion_turn.is_none() {
+                objective.completion_turn = Some(self.turn);
+                objectives_met += 1;
+            } else if objective.priority == ObjectivePriority::Critical && objective.progress < 50.0 {
+                critical_objectives_missed.push(objective.description.clone());
+            }
+        }
+
+        // Board member reactions
+        let mut board_feedback = Vec::new();
+        for member in &self.board {
+            let reaction = self.evaluate_board_member_satisfaction(member);
+            board_feedback.push(format!("{} ({}): {}", 
+                member.name, 
+                format!("{:?}", member.role).replace('_', " "),
+                reaction
+            ));
+        }
+
+        // Calculate political capital earned/lost
+        let capital_change = if objectives_met > 0 {
+            let gain = objectives_met as f64 * 10.0;
+            self.political_capital.earn(gain, "Quarterly objectives met".to_string());
+            gain
+        } else {
+            let loss = critical_objectives_missed.len() as f64 * 15.0;
+            self.political_capital.total = (self.political_capital.total - loss).max(0.0);
+            -loss
+        };
+
+        // Unhappy board members cost capital in proportion to their influence
+        let board_pressure: f64 = self.board.iter()
+            .filter(|b| b.satisfaction < 50.0)
+            .map(|b| (50.0 - b.satisfaction) * b.influence / 100.0 * BOARD_PRESSURE_FACTOR)
+            .sum();
+        self.political_capital.total = (self.political_capital.total - board_pressure).max(0.0);
+        let capital_change = capital_change - board_pressure;
+        let weighted_confidence = self.weighted_board_confidence();
+
+        // Generate new objectives for next quarter
+        self.generate_next_quarter_objectives();
+
+        // Record review event
+        self.add_event(
+            EventType::BoardReview,
+            format!(
+                "Q{} Board Review:\n- Objectives met: {}\n- Critical misses: {}\n- Political capital: {:+.0}\n- Weighted board confidence: {:.0}%\n\nBoard feedback:\n{}",
+                self.quarter - 1,
+                objectives_met,
+                critical_objectives_missed.len(),
+                capital_change,
+                weighted_confidence,
+                board_feedback.join("\n")
+            ),
+            None,
+            EventVisibility::Board,
+        );
+    }
+
+    /// Board confidence weighted by influence - the CEO's opinion outweighs counsel's
+    pub fn weighted_board_confidence(&self) -> f64 {
+        let total_influence: f64 = self.board.iter().map(|b| b.influence).sum();
+        if total_influence <= 0.0 {
+            return 0.0;
+        }
+
+        self.board.iter()
+            .map(|b| b.satisfaction * b.influence)
+            .sum::<f64>() / total_influence
+    }
+
+    /// The agenda that carries the room - priorities summed by influence, ties to whoever sits first
+    pub fn dominant_board_priority(&self) -> Option<BoardPriority> {
+        let mut totals: Vec<(BoardPriority, f64)> = Vec::new();
+        for member in &self.board {
+            match totals.iter_mut().find(|(p, _)| *p == member.current_priority) {
+                Some((_, weight)) => *weight += member.influence,
+                None => totals.push((member.current_priority, member.influence)),
+            }
+        }
+
+        totals.into_iter()
+            .fold(None, |best: Option<(BoardPriority, f64)>, (priority, weight)| match best {
+                Some((_, top)) if top >= weight => best,
+                _ => Some((priority, weight)),
+            })
+            .map(|(priority, _)| priority)
+    }
+
+    fn evaluate_board_member_satisfaction(&self, member: &BoardMember) -> String {
+        match member.satisfaction {
+            s if s > 80.0 => {
+                match member.personality {
+                    BoardPersonality::DataDriven => "Excellent metrics. Keep it up.".to_string(),
+                    BoardPersonality::PoliticallyShrewd => "The board is impressed with your progress.".to_string(),
+                    BoardPersonality::TechnicallyMinded => "Finally, someone who gets it.".to_string(),
+                    BoardPersonality::BottomLineFocused => "ROI is acceptable.".to_string(),
+                    BoardPersonality::RiskAverse => "I'm sleeping better at night.".to_string(),
+                    BoardPersonality::Fiduciary => "This is the story we want to tell investors.".to_string(),
+                }
+            }
+            s if s > 50.0 => {
+                match member.personality {
+                    BoardPersonality::DataDriven => "Show me more data on your progress.".to_string(),
+                    BoardPersonality::PoliticallyShrewd => "We need to discuss your approach.".to_string(),
+                    BoardPersonality::TechnicallyMinded => "The technical debt concerns me.".to_string(),
+                    BoardPersonality::BottomLineFocused => "Your budget utilization needs work.".to_string(),
+                    BoardPersonality::RiskAverse => "I'm not comfortable with current risk levels.".to_string(),
+                    BoardPersonality::Fiduciary => "Diligence will ask about this. Have an answer.".to_string(),
+                }
+            }
+            _ => {
                 match member.personality {
                     BoardPersonality::DataDriven => "The numbers don't support your decisions.".to_string(),
                     BoardPersonality::PoliticallyShrewd => "We're hearing concerns from other stakeholders.".to_string(),
                     BoardPersonality::TechnicallyMinded => "This is amateur hour.".to_string(),
                     BoardPersonality::BottomLineFocused => "You're burning cash without results.".to_string(),
                     BoardPersonality::RiskAverse => "I'm updating my resume. You should too.".to_string(),
+                    BoardPersonality::Fiduciary => "I'll be raising your tenure with the compensation committee.".to_string(),
                 }
             }
         }
@@ -489,6 +1659,11 @@ impl GameState {
     fn generate_next_quarter_objectives(&mut self) {
         // Objectives get harder each quarter
         let _difficulty_multiplier = 1.0 + (self.quarter as f64 * 0.2);
+
+        // No point assigning work for a quarter the campaign never reaches
+        if self.campaign.quarter_start(self.quarter) > self.campaign.final_turn {
+            return;
+        }
         
         let new_objective = match self.quarter {
             2 => Objective {
@@ -523,41 +1698,29 @@ impl GameState {
         self.quarterly_objectives.push(new_objective);
     }
 
+    /// Tier the run lands in - checked worst-first, so the first tier that claims it wins
+    ///
+    /// Criminal Investigation beats everything, then Golden CISO, then
+    /// Post-Breach Cleanup; whatever's left survives the lawsuit. Every number is
+    /// settled to the precision the ending screen shows before it's compared, so
+    /// a 49.99999 narrative is the 50 the player sees. Each gate says in its
+    /// requirement whether the bar is inclusive ("or more") or strict ("above").
     pub fn calculate_ending(&self) -> Ending {
-        let critical_incidents = self.active_incidents.iter()
-            .filter(|i| matches!(i.severity, IncidentSeverity::Critical))
-            .count();
-        
-        let unresolved_critical = self.active_incidents.iter()
-            .filter(|i| matches!(i.severity, IncidentSeverity::Critical) 
-                     && !matches!(i.response_status, IncidentResponseStatus::Closed))
-            .count();
-        
-        let narrative_score = self.narrative.score;
-        let business_health = self.business.arr_millions > 10.0 
-            && self.business.board_confidence_percent > 50.0;
-        let compliance_score = self.compliance.frameworks.get(&ComplianceFramework::SOC2)
-            .map(|f| f.compliance_percent).unwrap_or(0.0);
+        let gates = self.ending_gates();
+        let clears = |tier: Ending| gates.iter().filter(|g| g.tier == tier).all(|g| g.passed);
 
         // Criminal investigation - you buried too much
-        if self.narrative.criminal_exposure() {
+        if !clears(Ending::CriminalInvestigation) {
             return Ending::CriminalInvestigation;
         }
 
         // Golden CISO - top 5%
-        if critical_incidents == 0 
-           && narrative_score > 85.0 
-           && business_health 
-           && self.risk.total_exposure < 150.0 
-           && compliance_score > 90.0 
-           && self.board.iter().all(|b| b.satisfaction > 70.0) {
+        if clears(Ending::GoldenCISO) {
             return Ending::GoldenCISO;
         }
 
         // Post-breach cleanup - bottom 25%
-        if unresolved_critical > 0 
-           || narrative_score < 50.0 
-           || self.business.board_confidence_percent < 30.0 {
+        if !clears(Ending::PostBreachCleanup) {
             return Ending::PostBreachCleanup;
         }
 
@@ -565,20 +1728,123 @@ impl GameState {
         Ending::LawsuitSurvivor
     }
 
+    /// Five sub-scores, a weighted total and a grade - the ending gates read the same numbers
+    pub fn score_report(&self) -> ScoreReport {
+        let exposure = settled(self.risk.total_exposure, 1.0).max(0.0);
+        let risk_management = (100.0 - exposure / RISK_SCORE_EXPOSURE_CEILING * 100.0).clamp(0.0, 100.0);
+
+        let narrative_integrity = settled_percent(self.narrative.score);
+
+        let arr = settled(self.business.arr_millions, 0.1);
+        let growth = (arr / BUSINESS_SCORE_ARR_TARGET * 100.0).clamp(0.0, 100.0);
+        let business_growth = (growth + settled_percent(self.business.board_confidence_percent)) / 2.0;
+
+        let frameworks = self.compliance.frameworks.len().max(1) as f64;
+        let compliance = settled_percent(
+            self.compliance.frameworks.values().map(|f| f.compliance_percent).sum::<f64>() / frameworks,
+        );
+
+        let board_relations = settled_percent(self.weighted_board_confidence());
+
+        let mut report = ScoreReport {
+            risk_management,
+            narrative_integrity,
+            business_growth,
+            compliance,
+            board_relations,
+            total: 0.0,
+            grade: Grade::F,
+        };
+        report.total = report.components().iter()
+            .zip(ScoreReport::WEIGHTS)
+            .map(|((_, score), (_, weight))| score * weight)
+            .sum();
+        report.grade = Grade::for_score(report.total);
+        report
+    }
+
+    /// Every check `calculate_ending` makes, with the numbers behind it
+    ///
+    /// Golden CISO needs all of its gates passed; Criminal Investigation and
+    /// Post-Breach Cleanup are avoided only by passing all of theirs.
+    pub fn ending_gates(&self) -> Vec<EndingGate> {
+        // Closing a critical breach doesn't erase it - Golden CISO means none, all run
+        let critical_incidents = self.active_incidents.iter()
+            .filter(|i| matches!(i.severity, IncidentSeverity::Critical))
+            .count()
+            + self.resolved_incidents.iter()
+                .filter(|r| matches!(r.severity, Some(IncidentSeverity::Critical)))
+                .count();
+        
+        let unresolved_critical = self.active_incidents.iter()
+            .filter(|i| matches!(i.severity, IncidentSeverity::Critical) 
+                     && !matches!(i.response_status, IncidentResponseStatus::Closed))
+            .count();
+        
+        let scores = self.score_report();
+        let narrative_score = scores.narrative_integrity;
+        let board_confidence = scores.board_relations;
+        let arr = settled(self.business.arr_millions, 0.1);
+        let confidence = settled_percent(self.business.board_confidence_percent);
+        let exposure = settled(self.risk.total_exposure, 1.0).max(0.0);
+        let buried = self.narrative.buried_incidents.len();
+
+        let gate = |tier, label, actual: String, requirement: &str, passed| EndingGate {
+            tier,
+            label,
+            actual,
+            requirement: requirement.to_string(),
+            passed,
+        };
+
+        vec![
+            gate(Ending::CriminalInvestigation, "No criminal exposure",
+                 format!("integrity {:.0}, {} buried incidents", narrative_score, buried),
+                 "integrity 30+ or at most 2 buried incidents",
+                 !self.narrative.criminal_exposure()),
+
+            gate(Ending::GoldenCISO, "No critical incidents",
+                 critical_incidents.to_string(), "0, resolved ones included", critical_incidents == 0),
+            gate(Ending::GoldenCISO, "Narrative integrity",
+                 format!("{:.0}", narrative_score), "above 85", narrative_score > 85.0),
+            gate(Ending::GoldenCISO, "Business health",
+                 format!("ARR ${:.1}M, board confidence {:.0}%", arr, confidence),
+                 "ARR above $10M and confidence above 50%",
+                 arr > 10.0 && confidence > 50.0),
+            gate(Ending::GoldenCISO, "Risk exposure",
+                 format!("{:.0}", exposure), "below 150", scores.risk_management > 50.0),
+            gate(Ending::GoldenCISO, "Compliance",
+                 format!("{:.0}%", scores.compliance), "above 90% across frameworks in scope", scores.compliance > 90.0),
+            gate(Ending::GoldenCISO, "Board satisfaction",
+                 format!("{:.0}%", board_confidence), "above 70% (influence-weighted)", board_confidence > 70.0),
+
+            gate(Ending::PostBreachCleanup, "Unresolved critical incidents",
+                 unresolved_critical.to_string(), "0", unresolved_critical == 0),
+            gate(Ending::PostBreachCleanup, "Narrative integrity",
+                 format!("{:.0}", narrative_score), "50 or more", narrative_score >= 50.0),
+            gate(Ending::PostBreachCleanup, "Board confidence",
+                 format!("{:.0}%", confidence), "30% or more", confidence >= 30.0),
+            gate(Ending::PostBreachCleanup, "Board satisfaction",
+                 format!("{:.0}%", board_confidence), "30% or more (influence-weighted)", board_confidence >= 30.0),
+        ]
+    }
+
     pub fn apply_decision_impact(&mut self, impact: &DecisionImpact) {
         // Risk changes
         self.risk.apply_delta(&impact.risk_delta);
+        self.refresh_mttd();
         
         // Business changes
         self.business.apply_delta(&impact.business_delta);
         
         // Reputation changes
+        let before = self.player.reputation.clone();
         let rep = &mut self.player.reputation;
         rep.industry_standing = (rep.industry_standing + impact.reputation_impact.industry_delta).max(0.0).min(100.0);
         rep.board_credibility = (rep.board_credibility + impact.reputation_impact.board_delta).max(0.0).min(100.0);
         rep.team_morale = (rep.team_morale + impact.reputation_impact.team_delta).max(0.0).min(100.0);
         rep.vendor_relationships = (rep.vendor_relationships + impact.reputation_impact.vendor_delta).max(0.0).min(100.0);
-
+        self.record_reputation_changes(&before, &impact.decision_id);
 
         // Team capacity
         if impact.team_capacity_required > 0.0 {
@@ -598,10 +1864,13 @@ impl GameState {
             self.budget.spend(impact.budget_cost, impact.budget_category);
         }
 
-        // Compliance
+        // Compliance - new obligations first, so progress toward them lands
+        for framework in &impact.brings_into_scope {
+            self.bring_framework_into_scope(*framework);
+        }
         for (framework, progress) in &impact.compliance_impact.framework_progress {
             if let Some(status) = self.compliance.frameworks.get_mut(framework) {
-                status.compliance_percent = (status.compliance_percent + progress).max(0.0).min(100.0);
+                status.add_progress(*progress, impact.audit_trail != AuditTrail::Toxic);
             }
         }
 
@@ -618,11 +1887,10 @@ impl GameState {
             }
 
             if let Some((incident_id, actual_sev, reported_sev)) = &narrative.buries_incident {
-                self.narrative.bury_incident(
+                self.bury_incident(
                     incident_id.clone(),
                     *actual_sev,
                     *reported_sev,
-                    self.turn,
                     narrative.reason.clone(),
                 );
             }
@@ -637,6 +1905,30 @@ impl GameState {
             }
         }
 
+        // Some choices blow up immediately
+        if let Some(incident) = &impact.spawns_incident {
+            self.spawn_incident(incident);
+        }
+
+        for (category, points) in &impact.debt_paydown {
+            self.technical_debt.pay_down(*points, *category);
+        }
+
+        if let Some((incident_id, containment)) = &impact.works_incident {
+            let _ = self.work_incident_response(incident_id, *containment);
+        }
+
+        for finding_id in &impact.compliance_impact.accepted_findings {
+            let _ = self.accept_finding(finding_id);
+        }
+        for finding_id in &impact.compliance_impact.resolved_findings {
+            let _ = self.resolve_finding(finding_id);
+        }
+
+        for member in &impact.hires {
+            self.team.hire(member.clone());
+        }
+
         // Board member reactions
         for member in &mut self.board {
             member.react_to_decision(impact);
@@ -646,7 +1938,47 @@ impl GameState {
         self.decisions_made.push(impact.decision_id.clone());
     }
 
-    pub fn trigger_incident(&mut self, incident: ActiveIncident) {
+    /// What the world would look like after this choice - played out on a clone, nothing here moves
+    pub fn simulate_choice(&self, decision: &Decision, choice_id: &str) -> Result<GameState> {
+        let mut hypothetical = self.clone();
+        decision.clone().apply_choice(choice_id, &mut hypothetical)?;
+        Ok(hypothetical)
+    }
+
+    /// One audit entry per reputation component that actually moved
+    pub fn record_reputation_changes(&mut self, before: &Reputation, decision_id: &str) {
+        let after = self.player.reputation.clone();
+        let components = [
+            ("industry_standing", before.industry_standing, after.industry_standing, EventVisibility::Public),
+            ("board_credibility", before.board_credibility, after.board_credibility, EventVisibility::Board),
+            ("team_morale", before.team_morale, after.team_morale, EventVisibility::Internal),
+            ("vendor_relationships", before.vendor_relationships, after.vendor_relationships, EventVisibility::Management),
+        ];
+
+        for (component, old, new, visibility) in components {
+            let delta = new - old;
+            if delta.abs() <= REPUTATION_EVENT_THRESHOLD {
+                continue;
+            }
+
+            self.add_event(
+                EventType::ReputationChange,
+                format!("{} {:+.1} ({:.1} -> {:.1})", component.replace('_', " "), delta, old, new),
+                Some(decision_id.to_string()),
+                visibility,
+            );
+            if let Some(event) = self.events.last_mut() {
+                event.metadata.insert("component".to_string(), component.to_string());
+                event.metadata.insert("delta".to_string(), format!("{:.2}", delta));
+            }
+        }
+    }
+
+    pub fn trigger_incident(&mut self, mut incident: ActiveIncident) {
+        let reach = self.player.profile.customer_reach();
+        incident.customer_impact_count = incident.customer_impact_count
+            .map(|count| (f64::from(count) * reach).round() as u32);
+
         let visibility = if incident.severity == IncidentSeverity::Critical {
             EventVisibility::Board
         } else {
@@ -678,147 +2010,398 @@ impl GameState {
             );
         }
 
+        if incident.public_disclosure_required {
+            self.apply_incident_churn(&incident, "went public");
+        }
+
         self.active_incidents.push(incident);
     }
 
-    /// Check if delayed risk should materialize - now more sophisticated
-    pub fn check_risk_materialization(&mut self) -> Vec<String> {
-        let mut materialized = Vec::new();
-        
-        // Data exposure risk with time-to-critical
-        if let Some(data_metric) = self.risk.vectors.get(&RiskVector::DataExposure) {
-            if data_metric.current_level > 60.0 && self.turn > 5 {
-                if !self.active_incidents.iter().any(|i| i.id == "s3_breach") {
-                    let incident = ActiveIncident {
-                        id: "s3_breach".to_string(),
-                        title: "S3 Bucket Public Exposure".to_string(),
-                        description: "S3 bucket containing customer PII found publicly accessible. Misconfigured 8 months ago during migration.".to_string(),
-                        severity: IncidentSeverity::Critical,
-                        turn_detected: self.turn,
-                        turn_deadline: Some(self.turn + 2),  // 2 turns before this goes public
-                        escalated_to_board: false,
-                        escalation_turn: None,
-                        response_status: IncidentResponseStatus::Detected,
-                        assigned_team: Vec::new(),
-                        capacity_consumed: 0.0,
-                        containment_percent: 0.0,
-                        root_cause_identified: false,
-                        public_disclosure_required: true,
-                        customer_impact_count: Some(840000),
-                        timeline: vec![
-                            IncidentTimelineEntry {
-                                turn: self.turn,
-                                action: "Bucket discovered publicly accessible via automated scan".to_string(),
-                                actor: "Security tooling".to_string(),
-                                visibility: EventVisibility::Internal,
-                            }
-                        ],
-                    };
-                    self.trigger_incident(incident);
-                    materialized.push("CRITICAL: S3 bucket with 840K customer records publicly exposed".to_string());
-                }
+    /// Customers hear about it - churn scales with how many of them were hit
+    fn apply_incident_churn(&mut self, incident: &ActiveIncident, reason: &str) {
+        let churn = incident.public_churn();
+
+        self.business.customer_churn_probability =
+            (self.business.customer_churn_probability + churn).min(100.0);
+
+        self.add_event(
+            EventType::ReputationChange,
+            format!("{} {} - customer churn risk up {:.1} points", incident.title, reason, churn),
+            None,
+            EventVisibility::Public,
+        );
+    }
+
+    /// Public incidents still open when their deadline hits make it worse
+    fn apply_deadline_breach_churn(&mut self) {
+        let breached: Vec<ActiveIncident> = self.active_incidents.iter()
+            .filter(|i| i.public_disclosure_required
+                && i.turn_deadline == Some(self.turn)
+                && !matches!(i.response_status, IncidentResponseStatus::Closed))
+            .cloned()
+            .collect();
+
+        for incident in &breached {
+            self.apply_incident_churn(incident, "blew through its deadline");
+        }
+    }
+
+    /// Open public incidents and a sprawling attack surface wear customers down every turn
+    fn apply_churn_pressure(&mut self) {
+        let open_public = self.active_incidents.iter()
+            .filter(|i| i.public_disclosure_required
+                && !matches!(i.response_status, IncidentResponseStatus::Closed))
+            .count();
+        let excess_exposure = (self.risk.total_exposure - CHURN_EXPOSURE_THRESHOLD).max(0.0);
+
+        let pressure = open_public as f64 * OPEN_PUBLIC_INCIDENT_CHURN
+            + excess_exposure / 100.0 * CHURN_PER_100_EXPOSURE;
+        if pressure > 0.0 {
+            self.business.customer_churn_probability =
+                (self.business.customer_churn_probability + pressure).min(100.0);
+        }
+    }
+
+    /// Downplay an incident - the narrative takes the hit and the log keeps a buried record
+    pub fn bury_incident(&mut self, incident_id: String, actual: IncidentSeverity,
+                         reported: IncidentSeverity, method: String) {
+        self.narrative.bury_incident(incident_id.clone(), actual, reported, self.turn, method.clone());
+
+        self.add_event(
+            EventType::IncidentDetected,
+            format!("Incident {} reported as {:?}: {}", incident_id, reported, method),
+            None,
+            EventVisibility::Buried,
+        );
+        if let Some(event) = self.events.last_mut() {
+            event.metadata.insert("incident_id".to_string(), incident_id);
+        }
+    }
+
+    /// Chance this buried incident surfaces this turn
+    fn leak_chance(&self, buried: &BuriedIncident) -> f64 {
+        let age = self.turn.saturating_sub(buried.turn_occurred) as f64;
+        let other_secrets = self.narrative.buried_incidents.iter()
+            .filter(|b| b.turn_disclosed.is_none())
+            .count()
+            .saturating_sub(1) as f64;
+
+        (LEAK_BASE_CHANCE + age * LEAK_CHANCE_PER_TURN + other_secrets * LEAK_CHANCE_PER_SECRET)
+            .min(LEAK_MAX_CHANCE)
+    }
+
+    /// Roll for each undisclosed buried incident - returns the ids that leaked
+    pub fn check_buried_leaks(&mut self, rng: &mut impl Rng) -> Vec<String> {
+        let chances: Vec<(usize, f64)> = self.narrative.buried_incidents.iter()
+            .enumerate()
+            .filter(|(_, b)| b.turn_disclosed.is_none())
+            .map(|(i, b)| (i, self.leak_chance(b)))
+            .collect();
+
+        let mut leaked = Vec::new();
+        for (index, chance) in chances {
+            if rng.gen_bool(chance) {
+                leaked.push(self.leak_buried_incident(index));
             }
         }
+        leaked
+    }
 
-        // Access control with credential stuffing
-        if let Some(access_metric) = self.risk.vectors.get(&RiskVector::AccessControl) {
-            if access_metric.current_level > 50.0 && access_metric.mitigation_coverage < 30.0 && self.turn > 6 {
-                if !self.active_incidents.iter().any(|i| i.id == "credential_stuffing") {
-                    let incident = ActiveIncident {
-                        id: "credential_stuffing".to_string(),
-                        title: "Admin Account Compromise".to_string(),
-                        description: "Credential stuffing attack successful on admin accounts. No MFA. Attacker accessed production systems.".to_string(),
-                        severity: IncidentSeverity::High,
-                        turn_detected: self.turn,
-                        turn_deadline: Some(self.turn + 3),
-                        escalated_to_board: false,
-                        escalation_turn: None,
-                        response_status: IncidentResponseStatus::Detected,
-                        assigned_team: Vec::new(),
-                        capacity_consumed: 0.0,
-                        containment_percent: 0.0,
-                        root_cause_identified: false,
-                        public_disclosure_required: false,
-                        customer_impact_count: None,
-                        timeline: vec![
-                            IncidentTimelineEntry {
-                                turn: self.turn,
-                                action: "Suspicious admin logins detected from unusual IP ranges".to_string(),
-                                actor: "SIEM alert".to_string(),
-                                visibility: EventVisibility::Internal,
-                            }
-                        ],
-                    };
-                    self.trigger_incident(incident);
-                    materialized.push("HIGH: Admin account compromised via credential stuffing".to_string());
-                }
+    /// Bill this turn's carrying cost for every open incident - returns the total charged
+    ///
+    /// The longer an incident stays open the more it costs. The emergency
+    /// reserve pays first; once it runs dry the board hears about it and the
+    /// general budget picks up the rest.
+    pub fn charge_incident_costs(&mut self) -> f64 {
+        let cost: f64 = self.active_incidents.iter()
+            .map(|i| {
+                let open_turns = self.turn.saturating_sub(i.turn_detected) as f64;
+                i.severity.carrying_cost() * (1.0 + open_turns * INCIDENT_COST_GROWTH_PER_TURN)
+            })
+            .sum();
+        if cost <= 0.0 {
+            return 0.0;
+        }
+
+        let reserve_before = self.budget.emergency_reserve;
+        let unfunded = self.budget.draw_down(cost);
+        let from_general = (cost - unfunded) - (reserve_before - self.budget.emergency_reserve);
+
+        if reserve_before > 0.0 && self.budget.emergency_reserve <= 0.0 {
+            self.business.board_confidence_percent =
+                (self.business.board_confidence_percent - RESERVE_EXHAUSTED_CONFIDENCE_HIT).max(0.0);
+            self.add_event(
+                EventType::BoardPressure,
+                format!(
+                    "Emergency reserve exhausted - {} open incident(s) now cost ${:.2}M a turn",
+                    self.active_incidents.len(), cost
+                ),
+                None,
+                EventVisibility::Board,
+            );
+            if let Some(event) = self.events.last_mut() {
+                event.metadata.insert("budget_exhausted".to_string(), "emergency_reserve".to_string());
+            }
+        }
+        if from_general > 0.0 {
+            let political = &mut self.political_capital;
+            political.cfo_trust = (political.cfo_trust - GENERAL_BUDGET_DRAW_TRUST_HIT).max(0.0);
+        }
+        if unfunded > 0.0 {
+            self.add_event(
+                EventType::BoardPressure,
+                format!("${:.2}M in incident costs this turn had no budget left to cover them", unfunded),
+                None,
+                EventVisibility::Board,
+            );
+            if let Some(event) = self.events.last_mut() {
+                event.metadata.insert("budget_exhausted".to_string(), "general".to_string());
             }
         }
 
-        // Vendor risk cascading
-        if let Some(vendor_metric) = self.risk.vectors.get(&RiskVector::VendorRisk) {
-            if vendor_metric.current_level > 40.0 && self.turn > 7 {
-                if !self.active_incidents.iter().any(|i| i.id == "vendor_breach") {
-                    let incident = ActiveIncident {
-                        id: "vendor_breach".to_string(),
-                        title: "Third-Party SSO Provider Breach".to_string(),
-                        description: "SSO provider disclosed breach. Unknown if customer credentials compromised. Vendor is being 'less than forthcoming'.".to_string(),
-                        severity: IncidentSeverity::High,
-                        turn_detected: self.turn,
-                        turn_deadline: Some(self.turn + 4),
-                        escalated_to_board: false,
-                        escalation_turn: None,
-                        response_status: IncidentResponseStatus::Investigating,
-                        assigned_team: Vec::new(),
-                        capacity_consumed: 0.0,
-                        containment_percent: 0.0,
-                        root_cause_identified: false,
-                        public_disclosure_required: true,
-                        customer_impact_count: None,
-                        timeline: vec![
-                            IncidentTimelineEntry {
-                                turn: self.turn,
-                                action: "Vendor notification received via email (not phone call - red flag)".to_string(),
-                                actor: "Vendor".to_string(),
-                                visibility: EventVisibility::Internal,
-                            }
-                        ],
-                    };
-                    self.trigger_incident(incident);
-                    materialized.push("HIGH: SSO vendor breach - impact assessment needed".to_string());
-                }
+        cost - unfunded
+    }
+
+    /// Go to the board for more money - `Ok(false)` is a formal no, and the CFO remembers
+    pub fn request_emergency_budget(&mut self, amount: f64) -> Result<bool> {
+        if !(amount > 0.0 && amount <= self.budget.total_annual * EMERGENCY_BUDGET_MAX_SHARE) {
+            return Err(GameError::InvalidAction);
+        }
+        if !self.political_capital.spend(EMERGENCY_BUDGET_CAPITAL_COST, None) {
+            return Err(GameError::InsufficientPoliticalCapital);
+        }
+
+        // Coming back for more is its own answer
+        let prior_asks = self.emergency_budget_requests as f64;
+        self.emergency_budget_requests += 1;
+        let political = &mut self.political_capital;
+        political.cfo_trust = (political.cfo_trust - prior_asks * EMERGENCY_BUDGET_REPEAT_PENALTY).max(0.0);
+
+        let support = (self.business.board_confidence_percent + political.cfo_trust) / 2.0;
+        let bar = EMERGENCY_BUDGET_APPROVAL_BAR + prior_asks * EMERGENCY_BUDGET_REPEAT_PENALTY;
+        let granted = support >= bar;
+
+        if granted {
+            self.budget.total_annual += amount;
+            self.budget.emergency_reserve += amount;
+        } else {
+            political.cfo_trust = (political.cfo_trust - EMERGENCY_BUDGET_DENIAL_PENALTY).max(0.0);
+            let reputation = &mut self.player.reputation;
+            reputation.board_credibility = (reputation.board_credibility - EMERGENCY_BUDGET_DENIAL_PENALTY).max(0.0);
+        }
+
+        self.add_event(
+            EventType::PoliticalCapitalSpent,
+            if granted {
+                format!("Board approved ${:.2}M in emergency security funding", amount)
+            } else {
+                format!("Board denied a ${:.2}M emergency budget request", amount)
+            },
+            None,
+            EventVisibility::Board,
+        );
+        if let Some(event) = self.events.last_mut() {
+            event.metadata.insert("emergency_budget_request".to_string(), self.emergency_budget_requests.to_string());
+        }
+
+        Ok(granted)
+    }
+
+    /// A peer company's breach hits the news - returns the company, or `None` once the list runs dry
+    pub fn break_industry_news(&mut self) -> Option<String> {
+        let (company, impact, root_cause) = INDUSTRY_BREACH_HEADLINES.iter()
+            .find(|(company, _, _)| !self.threat_landscape.industry_breaches.iter().any(|b| b.company == *company))?;
+
+        self.record_industry_breach(IndustryBreach {
+            company: company.to_string(),
+            turn: self.turn,
+            impact: impact.to_string(),
+            root_cause: root_cause.to_string(),
+        });
+        Some(company.to_string())
+    }
+
+    /// Log a peer's breach - the board gets jumpy about risk for a few turns
+    pub fn record_industry_breach(&mut self, breach: IndustryBreach) {
+        self.add_event(
+            EventType::BoardPressure,
+            format!("Industry news: {} - {} ({})", breach.company, breach.impact, breach.root_cause),
+            None,
+            EventVisibility::Public,
+        );
+        self.threat_landscape.industry_breaches.push(breach);
+        self.refresh_board_sensitivity();
+    }
+
+    /// Breaches still in the headlines, newest first
+    pub fn breaches_in_news(&self) -> Vec<&IndustryBreach> {
+        let mut recent: Vec<&IndustryBreach> = self.threat_landscape.industry_breaches.iter()
+            .filter(|b| b.turn + BREACH_NEWS_TURNS > self.turn)
+            .collect();
+        recent.sort_by_key(|b| std::cmp::Reverse(b.turn));
+        recent
+    }
+
+    fn refresh_board_sensitivity(&mut self) {
+        let sensitivity = if self.breaches_in_news().is_empty() {
+            BoardMember::CALM_SENSITIVITY
+        } else {
+            BREACH_RISK_SENSITIVITY
+        };
+        for member in &mut self.board {
+            member.risk_sensitivity = sensitivity;
+        }
+    }
+
+    /// A demoralized team member goes to the regulators with the oldest buried incident
+    pub fn check_whistleblower(&mut self, rng: &mut impl Rng) -> Option<String> {
+        if self.team.morale >= WHISTLEBLOWER_MORALE_THRESHOLD {
+            return None;
+        }
+
+        let index = self.narrative.buried_incidents.iter()
+            .enumerate()
+            .filter(|(_, b)| b.turn_disclosed.is_none())
+            .min_by_key(|(_, b)| b.turn_occurred)
+            .map(|(i, _)| i)?;
+
+        let chance = WHISTLEBLOWER_BASE_CHANCE
+            + (WHISTLEBLOWER_MORALE_THRESHOLD - self.team.morale) * WHISTLEBLOWER_CHANCE_PER_POINT;
+        if !rng.gen_bool(chance.clamp(0.0, 1.0)) {
+            return None;
+        }
+
+        let incident_id = self.leak_buried_incident(index);
+        self.narrative.record_inconsistency(
+            self.turn,
+            format!("Whistleblower complaint to regulators over buried incident {}", incident_id),
+            WHISTLEBLOWER_INTEGRITY_PENALTY,
+        );
+        self.add_event(
+            EventType::WhistleblowerComplaint,
+            format!(
+                "A member of your security team filed a whistleblower complaint about {}. \
+                Regulators have opened an inquiry.",
+                incident_id
+            ),
+            None,
+            EventVisibility::Public,
+        );
+        if let Some(event) = self.events.last_mut() {
+            event.metadata.insert("incident_id".to_string(), incident_id.clone());
+        }
+
+        Some(incident_id)
+    }
+
+    fn leak_buried_incident(&mut self, index: usize) -> String {
+        let turn = self.turn;
+        let buried = &mut self.narrative.buried_incidents[index];
+        buried.turn_disclosed = Some(turn);
+        let incident_id = buried.incident_id.clone();
+        let buried_for = turn.saturating_sub(buried.turn_occurred);
+        let actual = buried.actual_severity;
+
+        // What was hidden is now on the record for everyone
+        for event in &mut self.events {
+            if event.visibility == EventVisibility::Buried
+                && event.metadata.get("incident_id") == Some(&incident_id) {
+                event.visibility = EventVisibility::Public;
             }
         }
 
-        // Technical debt causing incidents
-        if self.technical_debt.total_debt_points > 200.0 && self.turn % 3 == 0 {
-            if !self.active_incidents.iter().any(|i| i.id.starts_with("debt_incident")) {
-                let incident = ActiveIncident {
-                    id: format!("debt_incident_{}", self.turn),
-                    title: "Legacy System Vulnerability Exploited".to_string(),
-                    description: "Unpatched system from 2019 compromised. 'We were going to fix that next quarter' - famous last words.".to_string(),
-                    severity: IncidentSeverity::Medium,
-                    turn_detected: self.turn,
-                    turn_deadline: Some(self.turn + 2),
-                    escalated_to_board: false,
-                    escalation_turn: None,
-                    response_status: IncidentResponseStatus::Detected,
-                    assigned_team: Vec::new(),
-                    capacity_consumed: 0.0,
-                    containment_percent: 0.0,
-                    root_cause_identified: true,  // Oh, we know exactly what happened
-                    public_disclosure_required: false,
-                    customer_impact_count: None,
-                    timeline: Vec::new(),
-                };
-                self.trigger_incident(incident);
-                materialized.push("MEDIUM: Technical debt materialized - legacy system compromised".to_string());
+        let penalty = LEAK_INTEGRITY_PENALTY + buried_for as f64 * 2.0;
+        self.narrative.record_inconsistency(
+            turn,
+            format!("Buried incident {} leaked after {} turns", incident_id, buried_for),
+            penalty,
+        );
+        self.player.reputation.industry_standing = (self.player.reputation.industry_standing - 15.0).max(0.0);
+        self.player.reputation.board_credibility = (self.player.reputation.board_credibility - 20.0).max(0.0);
+        self.business.board_confidence_percent = (self.business.board_confidence_percent - 15.0).max(0.0);
+
+        self.add_event(
+            EventType::ReputationChange,
+            format!(
+                "LEAKED: {} was really {:?}, and you knew for {} turns. A journalist has the emails.",
+                incident_id, actual, buried_for
+            ),
+            None,
+            EventVisibility::Public,
+        );
+
+        incident_id
+    }
+
+    /// Start an incident authored on a decision - stamps it with the current turn
+    pub fn spawn_incident(&mut self, template: &ActiveIncident) {
+        let mut incident = template.clone();
+        incident.turn_detected = self.turn;
+        incident.turn_deadline = template.turn_deadline.map(|offset| self.turn + offset);
+        self.trigger_incident(incident);
+    }
+
+    /// Check if delayed risk should materialize - one pass over the incident catalog
+    pub fn check_risk_materialization(&mut self) -> Vec<String> {
+        let mut materialized = Vec::new();
+
+        for template in self.incident_catalog.incidents.clone() {
+            if !self.incident_due(&template) {
+                continue;
+            }
+
+            let mut incident = template.incident.clone();
+            if template.repeatable {
+                incident.id = format!("{}_{}", incident.id, self.turn);
             }
+            for entry in &mut incident.timeline {
+                entry.turn = self.turn;
+            }
+            let cut = self.threat_landscape.current_threat_level.deadline_cut();
+            incident.turn_deadline = incident.turn_deadline.map(|turns| turns.saturating_sub(cut).max(1));
+            self.spawn_incident(&incident);
+            materialized.push(template.alert.clone());
         }
 
         materialized
     }
 
+    /// Campaigns aimed at this company's industry push their target vectors up a notch
+    fn apply_campaign_pressure(&mut self) {
+        let industry = self.player.profile.industry();
+        for vector in self.threat_landscape.targeted_vectors(industry) {
+            if let Some(metric) = self.risk.vectors.get_mut(&vector) {
+                metric.current_level = (metric.current_level + CAMPAIGN_PRESSURE).min(100.0);
+                metric.trend = metric.trend.max(CAMPAIGN_PRESSURE);
+            }
+        }
+    }
+
+    /// Conditions met and not already burning - a hot threat landscape lowers the bar
+    fn incident_due(&self, template: &IncidentTemplate) -> bool {
+        if self.turn <= template.after_turn {
+            return false;
+        }
+        if template.every_turns.is_some_and(|n| n == 0 || !self.turn.is_multiple_of(n)) {
+            return false;
+        }
+
+        let armed = match template.trigger {
+            IncidentTrigger::Risk(vector) => self.risk.vectors.get(&vector).is_some_and(|metric| {
+                metric.current_level > template.threshold - self.threat_landscape.current_threat_level.threshold_discount()
+                    && template.max_coverage.is_none_or(|max| metric.mitigation_coverage < max)
+            }),
+            IncidentTrigger::TechnicalDebt => self.technical_debt.total_debt_points > template.threshold,
+        };
+
+        let id = &template.incident.id;
+        let open = self.active_incidents.iter().any(|i| {
+            if template.repeatable { i.id.starts_with(id.as_str()) } else { i.id == *id }
+        });
+
+        armed && !open
+    }
+
     /// Alias for check_risk_materialization - more intuitive naming
     pub fn materialize_risks(&mut self) -> Vec<String> {
         self.check_risk_materialization()
@@ -827,7 +2410,7 @@ impl GameState {
     /// Escalate incident to board - this is a BIG decision
     pub fn escalate_incident_to_board(&mut self, incident_id: &str) -> Result<()> {
         // Extract data we need BEFORE any mutable operations
-        let (turn_detected, incident_title, _already_escalated) = {
+        let (turn_detected, incident_title, severity, is_timely) = {
             let incident = self.active_incidents.iter()
                 .find(|i| i.id == incident_id)
                 .ok_or(GameError::InvalidAction)?;
@@ -836,10 +2419,14 @@ impl GameState {
                 return Err(GameError::InvalidAction);
             }
             
-            (incident.turn_detected, incident.title.clone(), incident.escalated_to_board)
+            (incident.turn_detected, incident.title.clone(), incident.severity, incident.escalation_is_timely(self.turn))
         };
-        
-        let is_timely = self.turn - turn_detected <= 1;
+
+        // Same news, different rooms - each member hears it through their own agenda
+        for member in &mut self.board {
+            let delta = member.escalation_reaction(is_timely, severity);
+            member.satisfaction = (member.satisfaction + delta).clamp(0.0, 100.0);
+        }
         
         // Now do all mutable operations without any borrows
         if is_timely {
@@ -887,6 +2474,103 @@ impl GameState {
         Ok(())
     }
 
+    /// Put a named team member on an incident - they start on it next turn
+    pub fn assign_to_incident(&mut self, incident_id: &str, member_name: &str) -> Result<()> {
+        if !self.team.members.iter().any(|m| m.name == member_name) {
+            return Err(GameError::InvalidAction);
+        }
+        let incident = self.active_incidents.iter_mut()
+            .find(|i| i.id == incident_id)
+            .ok_or(GameError::InvalidAction)?;
+        if incident.assigned_team.iter().any(|n| n == member_name) {
+            return Err(GameError::InvalidAction);
+        }
+
+        incident.assigned_team.push(member_name.to_string());
+        incident.timeline.push(IncidentTimelineEntry {
+            turn: self.turn,
+            action: format!("{} assigned to the response", member_name),
+            actor: self.player.name.clone(),
+            visibility: EventVisibility::Internal,
+        });
+        Ok(())
+    }
+
+    /// One turn of incident response - skill buys containment, responders pay in burnout
+    ///
+    /// Incidents that reach full containment are resolved; their ids are returned.
+    pub fn work_incidents(&mut self) -> Vec<String> {
+        let mut contained = Vec::new();
+
+        for incident in &mut self.active_incidents {
+            let mut progress = 0.0;
+            for member in self.team.members.iter_mut()
+                .filter(|m| incident.assigned_team.contains(&m.name))
+            {
+                progress += member.skill_level * CONTAINMENT_PER_SKILL_POINT;
+                member.burnout_level = (member.burnout_level + INCIDENT_BURNOUT_PER_TURN).min(100.0);
+            }
+            if progress <= 0.0 {
+                continue;
+            }
+
+            incident.containment_percent = (incident.containment_percent + progress).min(100.0);
+            incident.response_status = match incident.containment_percent {
+                p if p >= 100.0 => IncidentResponseStatus::PostMortem,
+                p if p >= 75.0 => IncidentResponseStatus::Recovering,
+                p if p >= 50.0 => IncidentResponseStatus::Eradicating,
+                p if p >= 25.0 => IncidentResponseStatus::Containing,
+                _ => IncidentResponseStatus::Investigating,
+            };
+            if incident.containment_percent >= 100.0 {
+                contained.push((incident.id.clone(), incident.assigned_team.join(", ")));
+            }
+        }
+
+        contained.into_iter()
+            .filter_map(|(id, responders)| {
+                self.resolve_incident(&id, vec![format!("Contained by {}", responders)]).ok()?;
+                Some(id)
+            })
+            .collect()
+    }
+
+    /// One push on an incident's response - `containment` points of work, one stage along
+    ///
+    /// Work stalls at Recovering until the incident is fully contained, then
+    /// moves on to the post-mortem. Pushing a post-mortem closes the incident.
+    pub fn work_incident_response(&mut self, incident_id: &str, containment: f64) -> Result<IncidentResponseStatus> {
+        let turn = self.turn;
+        let actor = self.player.name.clone();
+        let incident = self.active_incidents.iter_mut()
+            .find(|i| i.id == incident_id)
+            .ok_or(GameError::InvalidAction)?;
+
+        if incident.response_status == IncidentResponseStatus::PostMortem {
+            self.resolve_incident(incident_id, vec![format!("Post-mortem completed on turn {}", turn)])?;
+            return Ok(IncidentResponseStatus::Closed);
+        }
+
+        incident.containment_percent = (incident.containment_percent + containment).min(100.0);
+        let next = incident.response_status.next();
+        incident.response_status = match next {
+            IncidentResponseStatus::PostMortem if incident.containment_percent < 100.0 => IncidentResponseStatus::Recovering,
+            _ => next,
+        };
+        // Fully contained and recovered - nothing left but the write-up
+        if incident.response_status == IncidentResponseStatus::Recovering && incident.containment_percent >= 100.0 {
+            incident.response_status = IncidentResponseStatus::PostMortem;
+        }
+
+        incident.timeline.push(IncidentTimelineEntry {
+            turn,
+            action: format!("Response moved to {:?} ({:.0}% contained)", incident.response_status, incident.containment_percent),
+            actor,
+            visibility: EventVisibility::Internal,
+        });
+        Ok(incident.response_status)
+    }
+
     /// Resolve incident - requires work and leaves a trail
     pub fn resolve_incident(&mut self, incident_id: &str, lessons_learned: Vec<String>) -> Result<()> {
         let incident_index = self.active_incidents.iter()
@@ -925,6 +2609,8 @@ impl GameState {
             ],
             final_cost,
             reputation_impact: rep_impact,
+            severity: Some(incident.severity),
+            title: Some(incident.title.clone()),
         };
 
         // Update team morale based on how it went
@@ -934,8 +2620,8 @@ impl GameState {
             self.team.morale = (self.team.morale - 5.0).max(0.0);
         }
 
-        // Budget impact
-        self.budget.spend(final_cost, BudgetCategory::Emergency);
+        // Budget impact - same pecking order as the carrying costs
+        let _unfunded = self.budget.draw_down(final_cost);
 
         self.resolved_incidents.push(resolved);
 
@@ -949,4 +2635,247 @@ impl GameState {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Keep controls on these vectors tuned so they survive the next turn's decay
+    pub fn maintain_controls(&mut self, vectors: &[RiskVector]) -> Result<()> {
+        let capacity = vectors.len() as f64 * MAINTENANCE_CAPACITY_PER_VECTOR;
+        if !self.team.allocate_capacity(capacity) {
+            return Err(GameError::TeamCapacityExceeded);
+        }
+
+        let through = self.turn + 1;
+        for vector in vectors {
+            if let Some(metric) = self.risk.vectors.get_mut(vector) {
+                metric.maintained_through = Some(through);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Spend capacity and money on audit prep - each point is harder to earn than the last
+    ///
+    /// Returns the compliance progress gained.
+    pub fn invest_compliance(&mut self, framework: ComplianceFramework, capacity: f64, budget: f64) -> Result<f64> {
+        if capacity < 0.0 || budget < 0.0 || !self.compliance.frameworks.contains_key(&framework) {
+            return Err(GameError::InvalidAction);
+        }
+        if self.team.available_capacity() < capacity {
+            return Err(GameError::TeamCapacityExceeded);
+        }
+        if budget > 0.0 && !self.budget.can_spend(budget, BudgetCategory::Project) {
+            return Err(GameError::InsufficientBudget);
+        }
+
+        self.team.allocate_capacity(capacity);
+        if budget > 0.0 {
+            self.budget.spend(budget, BudgetCategory::Project);
+        }
+
+        let status = self.compliance.frameworks.get_mut(&framework)
+            .ok_or(GameError::StateCorruption)?;
+        let gained = compliance_investment_gain(status.compliance_percent, capacity, budget);
+        status.compliance_percent = (status.compliance_percent + gained).min(100.0);
+
+        // Every remaining GAP_SPAN points of distance to 100% is one open control gap
+        let gaps_allowed = ((100.0 - status.compliance_percent) / COMPLIANCE_GAP_SPAN).ceil() as usize;
+        let excess = status.control_gaps.len().saturating_sub(gaps_allowed);
+        let cleared: Vec<String> = status.control_gaps.drain(..excess).collect();
+
+        for gap in cleared {
+            self.add_event(
+                EventType::ComplianceFindingClosed,
+                format!("{:?} control gap closed: {}", framework, gap),
+                None,
+                EventVisibility::Management,
+            );
+        }
+
+        Ok(gained)
+    }
+
+    /// Buy down one vector directly - tooling money and team time, outside any scripted decision
+    pub fn invest_mitigation(&mut self, vector: RiskVector, budget: f64, capacity: f64) -> Result<()> {
+        if capacity < 0.0 || budget < 0.0 || capacity + budget <= 0.0 || !self.risk.vectors.contains_key(&vector) {
+            return Err(GameError::InvalidAction);
+        }
+        if self.team.available_capacity() < capacity {
+            return Err(GameError::TeamCapacityExceeded);
+        }
+        if budget > 0.0 && !self.budget.can_spend(budget, BudgetCategory::Tooling) {
+            return Err(GameError::InsufficientBudget);
+        }
+
+        self.team.allocate_capacity(capacity);
+        if budget > 0.0 {
+            self.budget.spend(budget, BudgetCategory::Tooling);
+        }
+
+        let metric = self.risk.vectors.get_mut(&vector).ok_or(GameError::StateCorruption)?;
+        let gained = mitigation_investment_gain(metric.mitigation_coverage, capacity, budget);
+        metric.mitigation_coverage = (metric.mitigation_coverage + gained).min(100.0);
+        metric.current_level = (metric.current_level - gained * RISK_REDUCTION_PER_COVERAGE).max(0.0);
+        self.refresh_mttd();
+
+        self.add_event(
+            EventType::DecisionMade,
+            format!(
+                "Invested ${:.2}M and {:.0} capacity in {:?} mitigation - coverage up {:.1}%",
+                budget, capacity, vector, gained
+            ),
+            None,
+            EventVisibility::Internal,
+        );
+
+        Ok(())
+    }
+
+    /// Formally accept the risk of an open finding - your name goes on the paperwork
+    pub fn accept_finding(&mut self, finding_id: &str) -> Result<()> {
+        let accepted_by = format!("{} (CISO)", self.player.name);
+        let turn = self.turn;
+
+        let finding = self.compliance.open_findings.iter_mut()
+            .find(|f| f.id == finding_id)
+            .ok_or(GameError::InvalidAction)?;
+
+        if !matches!(finding.status, FindingStatus::Open | FindingStatus::InProgress) {
+            return Err(GameError::InvalidAction);
+        }
+
+        finding.status = FindingStatus::Accepted;
+        finding.accepted_by = Some(accepted_by.clone());
+        finding.accepted_turn = Some(turn);
+
+        let description = format!(
+            "Risk accepted for {:?} finding '{}' ({:?}): {}. Signed off by {} on turn {}.",
+            finding.framework, finding.id, finding.severity, finding.description, accepted_by, turn
+        );
+
+        self.add_event(
+            EventType::RiskAccepted,
+            description,
+            None,
+            EventVisibility::Management,
+        );
+
+        if let Some(event) = self.events.last_mut() {
+            event.metadata.insert("finding_id".to_string(), finding_id.to_string());
+            event.metadata.insert("accepted_by".to_string(), accepted_by);
+            event.metadata.insert("accepted_turn".to_string(), turn.to_string());
+        }
+
+        Ok(())
+    }
+
+    /// A compliance finding turns into a real incident - how it was handled decides the fallout
+    pub fn materialize_finding(&mut self, finding_id: &str) -> Result<()> {
+        let finding = self.compliance.open_findings.iter()
+            .find(|f| f.id == finding_id)
+            .cloned()
+            .ok_or(GameError::InvalidAction)?;
+
+        let severity = finding.severity.incident_severity();
+
+        match finding.status {
+            FindingStatus::Accepted => {
+                // Documented risk acceptance - defensible, if painful
+                self.narrative.record_accepted_risk_materialized(
+                    self.turn,
+                    finding.id.clone(),
+                    severity,
+                );
+            }
+            FindingStatus::Ignored => {
+                // Nobody signed anything, nobody told anyone
+                self.bury_incident(
+                    format!("finding_{}", finding.id),
+                    severity,
+                    IncidentSeverity::Low,
+                    "Known compliance finding ignored without documentation".to_string(),
+                );
+            }
+            FindingStatus::Open | FindingStatus::InProgress => {
+                self.narrative.record_inconsistency(
+                    self.turn,
+                    format!("Known finding '{}' materialized before remediation", finding.id),
+                    5.0,
+                );
+            }
+            FindingStatus::Resolved => return Err(GameError::InvalidAction),
+        }
+
+        let incident = ActiveIncident {
+            id: format!("finding_{}", finding.id),
+            title: format!("{:?} Finding Exploited", finding.framework),
+            description: format!("{} - the gap from the audit report was exploited.", finding.description),
+            severity,
+            turn_detected: self.turn,
+            turn_deadline: Some(self.turn + 2),
+            escalated_to_board: false,
+            escalation_turn: None,
+            response_status: IncidentResponseStatus::Detected,
+            assigned_team: Vec::new(),
+            capacity_consumed: 0.0,
+            containment_percent: 0.0,
+            root_cause_identified: true,  // It was in the audit report
+            public_disclosure_required: false,
+            customer_impact_count: None,
+            timeline: vec![
+                IncidentTimelineEntry {
+                    turn: self.turn,
+                    action: format!("Finding '{}' exploited", finding.id),
+                    actor: "Attacker".to_string(),
+                    visibility: EventVisibility::Internal,
+                }
+            ],
+        };
+        self.trigger_incident(incident);
+
+        Ok(())
+    }
+
+    /// Capture the state before committing a decision
+    pub fn snapshot(&self) -> StateSnapshot {
+        StateSnapshot { state: self.clone() }
+    }
+
+    /// Roll back to a snapshot - costs a rewind token and leaves a mark in the log
+    pub fn restore(&mut self, snapshot: StateSnapshot) -> Result<()> {
+        if self.rewind_tokens == 0 {
+            return Err(GameError::InvalidAction);
+        }
+
+        let rewound_decisions = self.decisions_made.len()
+            .saturating_sub(snapshot.state.decisions_made.len());
+        let remaining_tokens = self.rewind_tokens - 1;
+
+        *self = snapshot.state;
+        self.rewind_tokens = remaining_tokens;
+
+        self.add_event(
+            EventType::DecisionRewound,
+            format!(
+                "{} reconsidered and reversed {} decision(s) before it took effect. Rewinds remaining: {}",
+                self.player.name, rewound_decisions, remaining_tokens
+            ),
+            None,
+            EventVisibility::Internal,
+        );
+
+        Ok(())
+    }
+}
+
+/// Round to the precision the ending screen shows - NaN settles to zero
+fn settled(value: f64, precision: f64) -> f64 {
+    if value.is_nan() {
+        return 0.0;
+    }
+    (value / precision).round() * precision
+}
+
+/// Whole-point percentage, clamped to 0-100
+fn settled_percent(value: f64) -> f64 {
+    settled(value, 1.0).clamp(0.0, 100.0)
+}
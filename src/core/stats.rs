@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use crate::core::types::*;
+use crate::core::state::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Milestones that carry over between careers
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Achievement {
+    GoldenParachute,      // Reached the Golden CISO ending
+    NothingToHide,        // Finished with zero buried incidents
+    StraightStory,        // Finished with no narrative inconsistencies
+    Certified,            // Held a SOC2 certification at the end
+    Firefighter,          // Resolved three or more incidents in one run
+    SurvivedTheNightmare, // Finished despite a critical incident on the books
+}
+
+impl Achievement {
+    pub fn title(&self) -> &'static str {
+        match self {
+            Achievement::GoldenParachute => "Golden Parachute",
+            Achievement::NothingToHide => "Nothing To Hide",
+            Achievement::StraightStory => "Straight Story",
+            Achievement::Certified => "Certified",
+            Achievement::Firefighter => "Firefighter",
+            Achievement::SurvivedTheNightmare => "Survived the Nightmare",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Achievement::GoldenParachute => "Reach the Golden CISO ending",
+            Achievement::NothingToHide => "Finish a run with zero buried incidents",
+            Achievement::StraightStory => "Finish with no narrative inconsistencies",
+            Achievement::Certified => "End a run holding SOC2 certification",
+            Achievement::Firefighter => "Resolve three or more incidents in one run",
+            Achievement::SurvivedTheNightmare => "Avoid a criminal investigation despite a critical incident",
+        }
+    }
+}
+
+/// Career record across runs - persisted encrypted next to the save
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RunStats {
+    pub runs_completed: u32,
+    pub endings: BTreeMap<Ending, u32>,
+    pub best_score: Option<f64>,
+    pub last_score: Option<f64>,
+    pub achievements: BTreeSet<Achievement>,
+    #[serde(default)]
+    pub tutorial_completed: bool,
+}
+
+impl RunStats {
+    /// Fold a finished run into the record - returns achievements unlocked for the first time
+    pub fn record_run(&mut self, state: &GameState) -> Vec<Achievement> {
+        let ending = match &state.phase {
+            GamePhase::Ended(ending) => ending.clone(),
+            _ => state.calculate_ending(),
+        };
+        let score = state.final_score();
+
+        self.runs_completed += 1;
+        *self.endings.entry(ending).or_insert(0) += 1;
+        self.best_score = Some(self.best_score.map_or(score, |best| best.max(score)));
+        self.last_score = Some(score);
+        self.tutorial_completed = true;
+
+        state.earned_achievements().into_iter()
+            .filter(|a| self.achievements.insert(*a))
+            .collect()
+    }
+
+    /// First-timers get the guided overlays - one finished run and they're on their own
+    pub fn wants_tutorial(&self) -> bool {
+        !self.tutorial_completed
+    }
+
+    pub fn ending_count(&self, ending: &Ending) -> u32 {
+        self.endings.get(ending).copied().unwrap_or(0)
+    }
+}
+
+impl GameState {
+    /// Single 0-100 career score - integrity counts most, then the board, then residual risk
+    pub fn final_score(&self) -> f64 {
+        let risk_component = (100.0 - self.risk.total_exposure / 4.0).clamp(0.0, 100.0);
+        self.narrative.score * 0.4 + self.weighted_board_confidence() * 0.3 + risk_component * 0.3
+    }
+
+    /// Achievements this run qualifies for
+    pub fn earned_achievements(&self) -> Vec<Achievement> {
+        let ending = match &self.phase {
+            GamePhase::Ended(ending) => ending.clone(),
+            _ => self.calculate_ending(),
+        };
+        let had_critical = self.active_incidents.iter()
+            .any(|i| i.severity == IncidentSeverity::Critical)
+            || self.resolved_incidents.iter()
+                .any(|r| r.severity == Some(IncidentSeverity::Critical));
+        let soc2_certified = self.compliance.frameworks.get(&ComplianceFramework::SOC2)
+            .is_some_and(|f| f.is_certified());
+
+        let mut earned = Vec::new();
+        if ending == Ending::GoldenCISO {
+            earned.push(Achievement::GoldenParachute);
+        }
+        if self.narrative.buried_incidents.is_empty() {
+            earned.push(Achievement::NothingToHide);
+        }
+        if self.narrative.inconsistencies.is_empty() {
+            earned.push(Achievement::StraightStory);
+        }
+        if soc2_certified {
+            earned.push(Achievement::Certified);
+        }
+        if self.resolved_incidents.len() >= 3 {
+            earned.push(Achievement::Firefighter);
+        }
+        if had_critical && ending != Ending::CriminalInvestigation {
+            earned.push(Achievement::SurvivedTheNightmare);
+        }
+        earned
+    }
+}
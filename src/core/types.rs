@@ -1,15 +1,18 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use zeroize::Zeroize;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use crate::core::state::{ActiveIncident, DebtCategory};
 
 /// Player information - now with baggage
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Player {
     pub name: String,
     pub company_name: String,
     pub previous_role: String,
     pub reputation: Reputation,
+    #[serde(default)]
+    pub profile: CompanyProfile,
 }
 
 impl Player {
@@ -19,12 +22,187 @@ impl Player {
             company_name,
             previous_role,
             reputation: Reputation::new(),
+            profile: CompanyProfile::default(),
+        }
+    }
+
+    /// Same hire, different employer - the profile seeds the starting state
+    pub fn with_profile(mut self, profile: CompanyProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+}
+
+/// What the company sells decides who regulates it and what keeps the board up at night
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum CompanyProfile {
+    #[default]
+    B2bSaas,      // SOC2 and nothing else - the baseline
+    Fintech,      // Card data in scope, and the CFO reads the audit reports
+    Healthcare,   // Patient records - HIPAA, and a board that fears the regulator
+    ConsumerApp,  // Millions of users, so every breach is a headline
+}
+
+impl CompanyProfile {
+    pub const ALL: [CompanyProfile; 4] = [
+        CompanyProfile::B2bSaas,
+        CompanyProfile::Fintech,
+        CompanyProfile::Healthcare,
+        CompanyProfile::ConsumerApp,
+    ];
+
+    pub fn company_name(&self) -> &'static str {
+        match self {
+            CompanyProfile::B2bSaas => "TechFlow Solutions",
+            CompanyProfile::Fintech => "LedgerLine Payments",
+            CompanyProfile::Healthcare => "CarePoint Health",
+            CompanyProfile::ConsumerApp => "Snapshare",
+        }
+    }
+
+    /// The sector threat actors pick targets by
+    pub fn industry(&self) -> &'static str {
+        match self {
+            CompanyProfile::B2bSaas => "SaaS",
+            CompanyProfile::Fintech => "Financial Services",
+            CompanyProfile::Healthcare => "Healthcare",
+            CompanyProfile::ConsumerApp => "Consumer Tech",
+        }
+    }
+
+    pub fn summary(&self) -> &'static str {
+        match self {
+            CompanyProfile::B2bSaas => "B2B SaaS - SOC2 opens enterprise deals",
+            CompanyProfile::Fintech => "Fintech - PCI DSS in scope, compliance-minded CFO",
+            CompanyProfile::Healthcare => "Healthcare - HIPAA in scope, compliance-first board",
+            CompanyProfile::ConsumerApp => "Consumer app - CCPA, and every incident reaches more users",
+        }
+    }
+
+    /// Frameworks in scope on day one beyond SOC2, with where the last CISO left them
+    fn extra_frameworks(&self) -> &'static [(ComplianceFramework, f64, u32)] {
+        match self {
+            CompanyProfile::B2bSaas => &[],
+            CompanyProfile::Fintech => &[(ComplianceFramework::PciDss, 35.0, 10)],
+            CompanyProfile::Healthcare => &[(ComplianceFramework::HIPAA, 30.0, 10)],
+            CompanyProfile::ConsumerApp => &[(ComplianceFramework::CCPA, 50.0, 12)],
+        }
+    }
+
+    /// Board seats whose agenda the industry rewrites
+    pub fn board_priorities(&self) -> &'static [(BoardMemberRole, BoardPriority)] {
+        match self {
+            CompanyProfile::B2bSaas => &[],
+            CompanyProfile::Fintech => &[
+                (BoardMemberRole::CFO, BoardPriority::ComplianceFirst),
+            ],
+            CompanyProfile::Healthcare => &[
+                (BoardMemberRole::CEO, BoardPriority::ComplianceFirst),
+                (BoardMemberRole::COO, BoardPriority::ComplianceFirst),
+            ],
+            CompanyProfile::ConsumerApp => &[
+                (BoardMemberRole::CEO, BoardPriority::CustomerTrust),
+            ],
+        }
+    }
+
+    /// Starting risk on top of the inherited baseline
+    pub fn starting_risk(&self) -> RiskDelta {
+        let mut delta = RiskDelta::zero();
+        match self {
+            CompanyProfile::B2bSaas => {}
+            CompanyProfile::Fintech => {
+                delta.add_change(RiskVector::APIAbuse, 10.0, 0.0, 0.0);
+                delta.add_change(RiskVector::VendorRisk, 5.0, 0.0, 0.0);
+            }
+            CompanyProfile::Healthcare => {
+                delta.add_change(RiskVector::DataExposure, 10.0, 0.0, 0.0);
+                delta.add_change(RiskVector::InsiderThreat, 5.0, 0.0, 0.0);
+            }
+            CompanyProfile::ConsumerApp => {
+                delta.add_change(RiskVector::APIAbuse, 5.0, 0.0, 0.0);
+                delta.add_change(RiskVector::DataExposure, 5.0, 0.0, 0.0);
+            }
+        }
+        delta
+    }
+
+    /// Multiplier on how many customers an incident reaches
+    pub fn customer_reach(&self) -> f64 {
+        match self {
+            CompanyProfile::ConsumerApp => 3.0,
+            _ => 1.0,
+        }
+    }
+}
+
+/// How much of a mess the last CISO left behind - picked before the first turn
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    Easy,      // A predecessor who actually kept some notes
+    #[default]
+    Standard,  // The job as advertised, which is to say worse than advertised
+    Brutal,    // Half the budget went on the last breach, and the team knows it
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Standard, Difficulty::Brutal];
+
+    pub fn summary(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy - more budget, less debt, a rested team",
+            Difficulty::Standard => "Standard - the inheritance as designed",
+            Difficulty::Brutal => "Brutal - thin budget, deep debt, controls rotting fast",
+        }
+    }
+
+    /// Multiplier on every starting budget line - Standard starts with $1.7M available
+    pub fn budget_scale(&self) -> f64 {
+        match self {
+            Difficulty::Easy => 1.2,
+            Difficulty::Standard => 1.0,
+            Difficulty::Brutal => 0.8,
+        }
+    }
+
+    /// Multiplier on inherited technical debt - Standard starts at 180 points
+    pub fn debt_scale(&self) -> f64 {
+        match self {
+            Difficulty::Easy => 0.75,
+            Difficulty::Standard => 1.0,
+            Difficulty::Brutal => 1.25,
+        }
+    }
+
+    /// Starting morale and capacity on top of the inherited skeleton crew
+    pub fn team_adjustment(&self) -> (f64, f64) {
+        match self {
+            Difficulty::Easy => (10.0, 4.0),
+            Difficulty::Standard => (0.0, 0.0),
+            Difficulty::Brutal => (-10.0, -4.0),
+        }
+    }
+
+    /// How fast controls rot and ambient risk creeps, relative to Standard
+    pub fn risk_decay_rate(&self) -> f64 {
+        match self {
+            Difficulty::Easy => 0.5,
+            Difficulty::Standard => 1.0,
+            Difficulty::Brutal => 1.5,
+        }
+    }
+
+    /// Mulligans for the whole run - Brutal doesn't do second chances
+    pub fn rewind_tokens(&self) -> u32 {
+        match self {
+            Difficulty::Easy | Difficulty::Standard => 1,
+            Difficulty::Brutal => 0,
         }
     }
 }
 
 /// Reputation - what people think when they hear your name
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Reputation {
     pub industry_standing: f64,      // 0-100: Can you get another job after this?
     pub board_credibility: f64,       // 0-100: Do they believe you?
@@ -44,6 +222,9 @@ impl Reputation {
 }
 
 /// Opaque error types - never leak internal details
+///
+/// `ContentError` is the one exception: broken scenario data is the modder's
+/// own file, and they need the line number more than we need the secrecy.
 #[derive(Debug, Clone)]
 pub enum GameError {
     StateCorruption,
@@ -53,6 +234,7 @@ pub enum GameError {
     InsufficientPoliticalCapital,
     TeamCapacityExceeded,
     ComplianceViolation,
+    ContentError(String),
 }
 
 impl fmt::Display for GameError {
@@ -65,6 +247,7 @@ impl fmt::Display for GameError {
             GameError::InsufficientPoliticalCapital => write!(f, "Insufficient organizational capital"),
             GameError::TeamCapacityExceeded => write!(f, "Team bandwidth exceeded"),
             GameError::ComplianceViolation => write!(f, "Compliance framework violation"),
+            GameError::ContentError(detail) => write!(f, "Content error: {}", detail),
         }
     }
 }
@@ -81,7 +264,7 @@ impl From<std::io::Error> for GameError {
 pub type Result<T> = std::result::Result<T, GameError>;
 
 /// Risk vectors - now with cascading failures and interdependencies
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum RiskVector {
     DataExposure,
     AccessControl,
@@ -93,22 +276,53 @@ pub enum RiskVector {
     APIAbuse,
 }
 
+impl RiskVector {
+    /// Every vector in declaration order - iterate this instead of the map so
+    /// decay, cascades, and float sums happen in the same order every run
+    pub const ALL: [RiskVector; 8] = [
+        RiskVector::DataExposure,
+        RiskVector::AccessControl,
+        RiskVector::Detection,
+        RiskVector::VendorRisk,
+        RiskVector::InsiderThreat,
+        RiskVector::SupplyChain,
+        RiskVector::CloudMisconfiguration,
+        RiskVector::APIAbuse,
+    ];
+
+    /// Parse the variant name used in decision TOML files
+    pub fn from_name(name: &str) -> Option<Self> {
+        RiskVector::ALL.iter().copied().find(|v| format!("{:?}", v) == name)
+    }
+}
+
 /// Enhanced risk model - risks compound, decay, and cascade
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RiskLevel {
-    pub vectors: HashMap<RiskVector, RiskMetric>,
+    pub vectors: BTreeMap<RiskVector, RiskMetric>,
     pub total_exposure: f64,
     pub risk_velocity: f64,  // How fast risk is growing
     pub cascade_multiplier: f64,  // Interdependency effects
+    #[serde(default = "RiskLevel::blind_mttd")]
+    pub mttd_hours: f64,  // Mean time to detect - how long attackers go unnoticed
+    #[serde(default = "RiskLevel::standard_decay")]
+    pub decay_rate: f64,  // 1.0 is Standard - difficulty speeds or slows the rot
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+/// MTTD with zero detection coverage - three days of nobody looking
+pub const MTTD_BLIND_HOURS: f64 = 72.0;
+/// Best MTTD full coverage can buy - someone still has to read the alert
+pub const MTTD_FLOOR_HOURS: f64 = 1.0;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct RiskMetric {
     pub current_level: f64,      // 0-100
     pub trend: f64,              // Rate of change
     pub time_to_critical: Option<u32>,  // Turns until this explodes
     pub mitigation_coverage: f64,  // 0-100: How well is this managed?
     pub last_incident: Option<u32>,  // Turn of last materialization
+    #[serde(default)]
+    pub maintained_through: Option<u32>,  // Coverage won't decay up to this turn
 }
 
 impl RiskMetric {
@@ -119,6 +333,7 @@ impl RiskMetric {
             time_to_critical: None,
             mitigation_coverage: 0.0,
             last_incident: None,
+            maintained_through: None,
         }
     }
 
@@ -133,36 +348,53 @@ impl RiskMetric {
 
 impl RiskLevel {
     pub fn new() -> Self {
-        let mut vectors = HashMap::new();
-        vectors.insert(RiskVector::DataExposure, RiskMetric::new());
-        vectors.insert(RiskVector::AccessControl, RiskMetric::new());
-        vectors.insert(RiskVector::Detection, RiskMetric::new());
-        vectors.insert(RiskVector::VendorRisk, RiskMetric::new());
-        vectors.insert(RiskVector::InsiderThreat, RiskMetric::new());
-        vectors.insert(RiskVector::SupplyChain, RiskMetric::new());
-        vectors.insert(RiskVector::CloudMisconfiguration, RiskMetric::new());
-        vectors.insert(RiskVector::APIAbuse, RiskMetric::new());
+        let vectors = RiskVector::ALL.iter()
+            .map(|v| (*v, RiskMetric::new()))
+            .collect();
 
         Self {
             vectors,
             total_exposure: 0.0,
             risk_velocity: 0.0,
             cascade_multiplier: 1.0,
+            mttd_hours: MTTD_BLIND_HOURS,
+            decay_rate: 1.0,
         }
     }
 
+    fn blind_mttd() -> f64 {
+        MTTD_BLIND_HOURS
+    }
+
+    fn standard_decay() -> f64 {
+        1.0
+    }
+
+    /// MTTD from detection coverage alone - the last few points of coverage matter most
+    pub fn coverage_mttd(&self) -> f64 {
+        let coverage = self.vectors.get(&RiskVector::Detection)
+            .map(|m| m.mitigation_coverage)
+            .unwrap_or(0.0)
+            .clamp(0.0, 100.0);
+        let blind_fraction = 1.0 - coverage / 100.0;
+        MTTD_FLOOR_HOURS + (MTTD_BLIND_HOURS - MTTD_FLOOR_HOURS) * blind_fraction * blind_fraction
+    }
+
     /// Apply natural risk decay (some things get better with time)
+    ///
+    /// Vectors are visited in `RiskVector::ALL` order so results are reproducible.
     pub fn apply_decay(&mut self, turn: u32) {
-        for (vector, metric) in self.vectors.iter_mut() {
+        for vector in RiskVector::ALL {
+            let Some(metric) = self.vectors.get_mut(&vector) else { continue };
             // Controls degrade over time without maintenance
-            if metric.last_incident.is_none() || turn - metric.last_incident.unwrap() > 3 {
-                metric.mitigation_coverage *= 0.95; // 5% decay per turn
+            if metric.maintained_through.is_none_or(|through| through < turn) {
+                metric.mitigation_coverage *= 1.0 - 0.05 * self.decay_rate; // 5% decay per turn at Standard
             }
             
             // Some risks naturally increase (tech debt, complexity)
             match vector {
                 RiskVector::CloudMisconfiguration | RiskVector::APIAbuse => {
-                    metric.current_level = (metric.current_level * 1.02).min(100.0);
+                    metric.current_level = (metric.current_level * (1.0 + 0.02 * self.decay_rate)).min(100.0);
                     metric.trend = 2.0;
                 }
                 _ => {}
@@ -171,6 +403,9 @@ impl RiskLevel {
     }
 
     /// Calculate cascading effects - one failure enables others
+    ///
+    /// Cascades run in a fixed sequence (access -> data, detection -> multiplier,
+    /// vendor -> supply chain) and exposure is summed in `RiskVector::ALL` order.
     pub fn calculate_cascade_effects(&mut self) {
         // Access control failure amplifies data exposure
         let access_level = self.vectors.get(&RiskVector::AccessControl)
@@ -202,11 +437,34 @@ impl RiskLevel {
             }
         }
 
-        self.total_exposure = self.vectors.values()
+        self.total_exposure = RiskVector::ALL.iter()
+            .filter_map(|v| self.vectors.get(v))
             .map(|m| m.current_level * (1.0 - m.mitigation_coverage / 100.0))
             .sum::<f64>() * self.cascade_multiplier;
     }
 
+    /// Where each vector's level is headed `turns` from now, clamped to 0-100
+    ///
+    /// Runs the turn change on a copy - decay, trend, then cascades - with the
+    /// cascade multiplier amplifying growth. Assumes nobody maintains anything,
+    /// so the forecast leans pessimistic. Like every forecast.
+    pub fn forecast(&self, turns: u32) -> BTreeMap<RiskVector, f64> {
+        let mut projected = self.clone();
+        for _ in 0..turns {
+            projected.apply_decay(u32::MAX);
+            let multiplier = projected.cascade_multiplier;
+            for metric in projected.vectors.values_mut() {
+                let step = if metric.trend > 0.0 { metric.trend * multiplier } else { metric.trend };
+                metric.current_level = (metric.current_level + step).clamp(0.0, 100.0);
+            }
+            projected.calculate_cascade_effects();
+        }
+
+        projected.vectors.iter()
+            .map(|(vector, metric)| (*vector, metric.current_level.clamp(0.0, 100.0)))
+            .collect()
+    }
+
     pub fn apply_delta(&mut self, delta: &RiskDelta) {
         for (vector, change) in &delta.changes {
             if let Some(metric) = self.vectors.get_mut(vector) {
@@ -226,12 +484,12 @@ impl RiskLevel {
 }
 
 /// Risk deltas - now more granular with mitigation tracking
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RiskDelta {
-    pub changes: HashMap<RiskVector, RiskChange>,
+    pub changes: BTreeMap<RiskVector, RiskChange>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct RiskChange {
     pub level_delta: f64,        // Change in risk level
     pub mitigation_delta: f64,   // Change in mitigation coverage
@@ -241,7 +499,7 @@ pub struct RiskChange {
 impl RiskDelta {
     pub fn zero() -> Self {
         Self {
-            changes: HashMap::new(),
+            changes: BTreeMap::new(),
         }
     }
 
@@ -259,7 +517,7 @@ impl RiskDelta {
 }
 
 /// Business metrics - the only thing that actually matters
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct BusinessMetrics {
     pub arr_millions: f64,
     pub roadmap_velocity_percent: f64,
@@ -270,6 +528,19 @@ pub struct BusinessMetrics {
     pub regulatory_compliance_score: f64, // 0-100: Multi-framework compliance
 }
 
+/// Churn probability (%) customers tolerate before revenue starts walking out
+pub const CHURN_EROSION_THRESHOLD: f64 = 10.0;
+/// Share of excess churn that turns into lost ARR each turn
+const CHURN_ARR_EROSION_RATE: f64 = 0.25;
+/// Share of ARR a healthy pipeline adds per turn - full velocity, normal deal cycle
+const ORGANIC_GROWTH_RATE: f64 = 0.015;
+/// Deal cycle the sales forecast assumes - longer cycles close fewer deals per turn
+const BASELINE_DEAL_CYCLE_DAYS: f64 = 45.0;
+/// Differentiator score at which security neither helps nor hurts the pipeline
+const NEUTRAL_DIFFERENTIATOR: f64 = 30.0;
+/// Share of churn probability that quietly walks out each turn (erosion handles the excess)
+const ORGANIC_CHURN_RATE: f64 = 0.2;
+
 impl BusinessMetrics {
     pub fn new() -> Self {
         Self {
@@ -293,6 +564,48 @@ impl BusinessMetrics {
         self.regulatory_compliance_score = (self.regulatory_compliance_score + delta.compliance_change).max(0.0).min(100.0);
     }
 
+    /// Between decisions the business still moves - returns the net ARR change
+    ///
+    /// New revenue scales with roadmap velocity and security's pull in deals,
+    /// and slows as deal cycles stretch. Churn up to the erosion threshold
+    /// drags it back; anything above is `apply_churn_erosion`'s problem.
+    pub fn apply_organic_growth(&mut self) -> f64 {
+        let velocity = self.roadmap_velocity_percent / 100.0;
+        let deal_pace = BASELINE_DEAL_CYCLE_DAYS / self.deal_cycle_days.max(1.0);
+        let differentiator = 1.0 + (self.security_as_differentiator - NEUTRAL_DIFFERENTIATOR) / 100.0;
+        let growth = self.arr_millions * ORGANIC_GROWTH_RATE * velocity * deal_pace * differentiator;
+
+        let lost = self.organic_churn_loss();
+
+        let before = self.arr_millions;
+        self.arr_millions = (self.arr_millions + growth - lost).max(0.0);
+        self.arr_millions - before
+    }
+
+    /// Churn above the baseline bleeds revenue every turn it stays there
+    pub fn apply_churn_erosion(&mut self) -> f64 {
+        let loss = self.churn_erosion_loss();
+        self.arr_millions = (self.arr_millions - loss).max(0.0);
+        loss
+    }
+
+    /// ARR the everyday churn takes each turn - capped at the erosion threshold
+    fn organic_churn_loss(&self) -> f64 {
+        let churn = self.customer_churn_probability.min(CHURN_EROSION_THRESHOLD);
+        self.arr_millions * churn / 100.0 * ORGANIC_CHURN_RATE
+    }
+
+    /// ARR the churn above the threshold takes each turn
+    fn churn_erosion_loss(&self) -> f64 {
+        let excess = (self.customer_churn_probability - CHURN_EROSION_THRESHOLD).max(0.0);
+        self.arr_millions * excess / 100.0 * CHURN_ARR_EROSION_RATE
+    }
+
+    /// ARR walking out the door next turn at today's churn, before any new deals
+    pub fn churn_loss_per_turn(&self) -> f64 {
+        self.organic_churn_loss() + self.churn_erosion_loss()
+    }
+
     /// Calculate burn multiple - how efficiently are we growing?
     pub fn burn_multiple(&self, burn_rate: f64) -> f64 {
         if self.arr_millions == 0.0 { 
@@ -303,7 +616,7 @@ impl BusinessMetrics {
 }
 
 /// Business impact deltas
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct BusinessDelta {
     pub arr_change: f64,
     pub velocity_change: f64,
@@ -329,7 +642,7 @@ impl BusinessDelta {
 }
 
 /// Political capital - the hidden currency of corporate warfare
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct PoliticalCapital {
     pub total: f64,              // 0-100
     pub ceo_favor: f64,          // 0-100
@@ -388,7 +701,7 @@ impl PoliticalCapital {
 }
 
 /// Board members - they all want different things
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BoardMember {
     pub role: BoardMemberRole,
     pub name: String,
@@ -396,6 +709,8 @@ pub struct BoardMember {
     pub current_priority: BoardPriority,
     pub satisfaction: f64,  // 0-100
     pub influence: f64,     // 0-100: How much they sway decisions
+    #[serde(default = "BoardMember::calm_sensitivity")]
+    pub risk_sensitivity: f64,  // Multiplier on reactions to risk - spikes when a peer gets breached
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -408,16 +723,17 @@ pub enum BoardMemberRole {
     BoardChair,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum BoardPersonality {
     RiskAverse,        // Hates any bad news
     DataDriven,        // Wants metrics for everything
     PoliticallyShrewd, // Cares about optics
     TechnicallyMinded, // Understands the details
     BottomLineFocused, // Only cares about money
+    Fiduciary,         // Thinks in exit multiples and shareholder letters
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum BoardPriority {
     GrowthAtAllCosts,
     RiskMitigation,
@@ -428,7 +744,21 @@ pub enum BoardPriority {
 }
 
 impl BoardMember {
+    /// Sensitivity when nobody in the industry is in the news
+    pub const CALM_SENSITIVITY: f64 = 1.0;
+
+    fn calm_sensitivity() -> f64 {
+        Self::CALM_SENSITIVITY
+    }
+
     pub fn react_to_decision(&mut self, impact: &DecisionImpact) -> f64 {
+        let satisfaction_delta = self.predict_reaction(impact);
+        self.satisfaction = (self.satisfaction + satisfaction_delta).max(0.0).min(100.0);
+        satisfaction_delta
+    }
+
+    /// How much this member's satisfaction would move - without touching it
+    pub fn predict_reaction(&self, impact: &DecisionImpact) -> f64 {
         let mut satisfaction_delta = 0.0;
         
         match self.current_priority {
@@ -444,7 +774,7 @@ impl BoardMember {
                 let risk_improvement: f64 = impact.risk_delta.changes.values()
                     .map(|c| -c.level_delta + c.mitigation_delta * 0.5)
                     .sum();
-                satisfaction_delta += risk_improvement * 3.0;
+                satisfaction_delta += risk_improvement * 3.0 * self.risk_sensitivity;
             }
             BoardPriority::ComplianceFirst => {
                 satisfaction_delta += impact.business_delta.compliance_change * 4.0;
@@ -458,22 +788,56 @@ impl BoardMember {
             }
         }
 
-        self.satisfaction = (self.satisfaction + satisfaction_delta).max(0.0).min(100.0);
         satisfaction_delta
     }
+
+    /// How an incident landing on the board agenda moves this member
+    ///
+    /// Personality decides whether early warning reads as diligence or alarm;
+    /// priority tilts it; severity scales it. Late news is never welcome.
+    pub fn escalation_reaction(&self, timely: bool, severity: IncidentSeverity) -> f64 {
+        let (early, late) = match self.personality {
+            BoardPersonality::RiskAverse => (6.0, -10.0),         // Tell me everything, immediately
+            BoardPersonality::DataDriven => (3.0, -6.0),
+            BoardPersonality::PoliticallyShrewd => (1.0, -8.0),   // Late is an optics problem
+            BoardPersonality::TechnicallyMinded => (4.0, -5.0),
+            BoardPersonality::BottomLineFocused => (-3.0, -4.0),  // Every alarm has a price tag
+            BoardPersonality::Fiduciary => (4.0, -8.0),
+        };
+        let tilt: f64 = match self.current_priority {
+            BoardPriority::GrowthAtAllCosts => -4.0,  // Bad news slows the roadmap
+            BoardPriority::CostReduction => -2.0,
+            BoardPriority::RiskMitigation | BoardPriority::ComplianceFirst => 3.0,
+            BoardPriority::CustomerTrust => 2.0,
+            BoardPriority::IpoPreparation => 0.0,
+        };
+        let weight = match severity {
+            IncidentSeverity::Critical => 1.5,
+            IncidentSeverity::High => 1.0,
+            IncidentSeverity::Medium => 0.75,
+            IncidentSeverity::Low => 0.5,
+        };
+
+        let base = if timely { early + tilt } else { late + tilt.min(0.0) };
+        base * weight
+    }
 }
 
 /// Team management - you can't do this alone
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SecurityTeam {
     pub members: Vec<TeamMember>,
     pub total_capacity: f64,      // Story points per turn
     pub committed_capacity: f64,   // Already allocated
     pub morale: f64,              // 0-100
     pub attrition_risk: f64,      // 0-100: Probability of losing someone
+    #[serde(default)]
+    pub on_leave_capacity: f64,   // Out on time off - back next turn
+    #[serde(default)]
+    pub boosts_this_quarter: u32, // The third pizza party lands flat
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TeamMember {
     pub name: String,
     pub role: SecurityRole,
@@ -494,6 +858,65 @@ pub enum SecurityRole {
     CloudSecurity,
 }
 
+/// Capacity sent home by a round of time off - it comes back next turn
+pub const TIME_OFF_CAPACITY: f64 = 4.0;
+/// Each boost in the same quarter lands at this fraction of the previous one
+const MORALE_BOOST_FALLOFF: f64 = 0.5;
+/// Perks only get morale so far - the rest has to come from the work
+pub const MORALE_BOOST_CEILING: f64 = 85.0;
+/// Below this share of capacity committed, people actually get to breathe
+pub const BURNOUT_SLACK_UTILIZATION: f64 = 0.6;
+/// Burnout a member picks up in a turn at 100% committed capacity - scales with utilization
+pub const BURNOUT_PER_FULL_UTILIZATION: f64 = 6.0;
+/// Burnout shed in a slack turn - recovery is slower than the damage
+pub const BURNOUT_RECOVERY_PER_TURN: f64 = 2.0;
+/// Morale under this starts showing up in recruiter inboxes
+pub const LOW_MORALE_THRESHOLD: f64 = 40.0;
+/// Attrition risk added per point of morale below the threshold, every turn
+pub const ATTRITION_PER_MISSING_MORALE: f64 = 0.25;
+/// Attrition risk shed each turn morale holds at or above the threshold
+pub const ATTRITION_RECOVERY_PER_TURN: f64 = 1.0;
+/// Per-turn odds of leaving at 100 attrition risk with no burnout at all - people get poached
+pub const BASELINE_DEPARTURE_ODDS: f64 = 0.02;
+
+/// Ways to buy the team some goodwill - each with its own catch
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MoraleBoost {
+    TimeOff,   // Burnout drops, but the capacity leaves with them for a turn
+    Bonus,     // Expensive, and attrition cools off
+    Training,  // Skills go up, morale a little
+}
+
+impl MoraleBoost {
+    pub const ALL: [MoraleBoost; 3] = [MoraleBoost::TimeOff, MoraleBoost::Bonus, MoraleBoost::Training];
+
+    /// Headcount budget it costs ($M)
+    pub fn budget_cost(&self) -> f64 {
+        match self {
+            MoraleBoost::TimeOff => 0.03,
+            MoraleBoost::Bonus => 0.08,
+            MoraleBoost::Training => 0.05,
+        }
+    }
+
+    /// Morale gained at full effectiveness
+    fn base_morale(&self) -> f64 {
+        match self {
+            MoraleBoost::TimeOff => 8.0,
+            MoraleBoost::Bonus => 12.0,
+            MoraleBoost::Training => 4.0,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MoraleBoost::TimeOff => "Mandatory time off",
+            MoraleBoost::Bonus => "Retention bonuses",
+            MoraleBoost::Training => "Paid training and certifications",
+        }
+    }
+}
+
 impl SecurityTeam {
     pub fn new() -> Self {
         // You inherit a skeleton crew
@@ -521,11 +944,65 @@ impl SecurityTeam {
             morale: 45.0,
             attrition_risk: 35.0,
             members,
+            on_leave_capacity: 0.0,
+            boosts_this_quarter: 0,
         }
     }
 
     pub fn available_capacity(&self) -> f64 {
-        self.total_capacity - self.committed_capacity
+        self.total_capacity - self.committed_capacity - self.on_leave_capacity
+    }
+
+    /// Spend headcount budget on morale - returns the morale actually gained
+    ///
+    /// Every boost in the same quarter is worth half the last one, and none of
+    /// them lift morale past `MORALE_BOOST_CEILING`.
+    pub fn boost_morale(&mut self, budget: &mut Budget, kind: MoraleBoost) -> Result<f64> {
+        let cost = kind.budget_cost();
+        if !budget.can_spend(cost, BudgetCategory::Headcount) {
+            return Err(GameError::InsufficientBudget);
+        }
+        if kind == MoraleBoost::TimeOff && self.available_capacity() < TIME_OFF_CAPACITY {
+            return Err(GameError::TeamCapacityExceeded);
+        }
+        budget.spend(cost, BudgetCategory::Headcount);
+
+        let effectiveness = MORALE_BOOST_FALLOFF.powi(self.boosts_this_quarter as i32);
+        self.boosts_this_quarter += 1;
+
+        match kind {
+            MoraleBoost::TimeOff => {
+                self.on_leave_capacity += TIME_OFF_CAPACITY;
+                for member in &mut self.members {
+                    member.burnout_level = (member.burnout_level - 15.0 * effectiveness).max(0.0);
+                }
+            }
+            MoraleBoost::Bonus => {
+                self.attrition_risk = (self.attrition_risk - 8.0 * effectiveness).max(0.0);
+            }
+            MoraleBoost::Training => {
+                for member in &mut self.members {
+                    member.skill_level = (member.skill_level + 5.0 * effectiveness).min(100.0);
+                }
+            }
+        }
+
+        let before = self.morale;
+        if before < MORALE_BOOST_CEILING {
+            self.morale = (before + kind.base_morale() * effectiveness).min(MORALE_BOOST_CEILING);
+        }
+        Ok(self.morale - before)
+    }
+
+    /// Time off is over - everyone's back at their desk
+    pub fn return_from_leave(&mut self) {
+        self.on_leave_capacity = 0.0;
+    }
+
+    /// Someone new starts - their capacity joins the team's
+    pub fn hire(&mut self, member: TeamMember) {
+        self.total_capacity += member.capacity;
+        self.members.push(member);
     }
 
     pub fn allocate_capacity(&mut self, amount: f64) -> bool {
@@ -537,12 +1014,51 @@ impl SecurityTeam {
         }
     }
 
-    pub fn check_attrition(&mut self, _turn: u32) -> Vec<String> {
+    /// Share of the team's capacity already spoken for - can run past 1.0 after departures
+    pub fn utilization(&self) -> f64 {
+        if self.total_capacity <= 0.0 {
+            return 1.0;
+        }
+        self.committed_capacity / self.total_capacity
+    }
+
+    /// A turn's worth of wear - burnout tracks utilization, and low morale feeds attrition
+    pub fn accumulate_strain(&mut self) {
+        let utilization = self.utilization();
+        let delta = if utilization >= BURNOUT_SLACK_UTILIZATION {
+            utilization * BURNOUT_PER_FULL_UTILIZATION
+        } else {
+            -BURNOUT_RECOVERY_PER_TURN
+        };
+        for member in &mut self.members {
+            member.burnout_level = (member.burnout_level + delta).clamp(0.0, 100.0);
+        }
+
+        if self.morale < LOW_MORALE_THRESHOLD {
+            let missing = LOW_MORALE_THRESHOLD - self.morale;
+            self.attrition_risk = (self.attrition_risk + missing * ATTRITION_PER_MISSING_MORALE).min(100.0);
+        } else {
+            self.attrition_risk = (self.attrition_risk - ATTRITION_RECOVERY_PER_TURN).max(0.0);
+        }
+    }
+
+    /// Chance this member resigns this turn - the most burned out go first
+    pub fn leave_probability(&self, member: &TeamMember) -> f64 {
+        Self::departure_odds(member.burnout_level, self.attrition_risk)
+    }
+
+    fn departure_odds(burnout: f64, attrition_risk: f64) -> f64 {
+        let baseline = (attrition_risk / 100.0) * BASELINE_DEPARTURE_ODDS;
+        baseline + (burnout / 100.0) * (burnout + attrition_risk) / 150.0
+    }
+
+    pub fn check_attrition(&mut self, _turn: u32, rng: &mut impl rand::Rng) -> Vec<String> {
         let mut departed = Vec::new();
-        
+        let attrition_risk = self.attrition_risk;
+
         self.members.retain(|member| {
-            let leave_probability = (member.burnout_level + self.attrition_risk) / 200.0;
-            let roll: f64 = rand::random();
+            let leave_probability = Self::departure_odds(member.burnout_level, attrition_risk);
+            let roll: f64 = rng.gen_range(0.0..1.0);
             
             if roll < leave_probability {
                 departed.push(member.name.clone());
@@ -557,14 +1073,13 @@ impl SecurityTeam {
 }
 
 /// Compliance frameworks - because one is never enough
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ComplianceStatus {
-    pub frameworks: HashMap<ComplianceFramework, FrameworkStatus>,
-    pub audit_schedule: Vec<ScheduledAudit>,
+    pub frameworks: BTreeMap<ComplianceFramework, FrameworkStatus>,
     pub open_findings: Vec<ComplianceFinding>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ComplianceFramework {
     SOC2,
     ISO27001,
@@ -575,22 +1090,119 @@ pub enum ComplianceFramework {
     StateBreachLaws,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl ComplianceFramework {
+    pub const ALL: [ComplianceFramework; 7] = [
+        ComplianceFramework::SOC2,
+        ComplianceFramework::ISO27001,
+        ComplianceFramework::GDPR,
+        ComplianceFramework::HIPAA,
+        ComplianceFramework::PciDss,
+        ComplianceFramework::CCPA,
+        ComplianceFramework::StateBreachLaws,
+    ];
+
+    /// Who shows up to audit it
+    pub fn auditor(&self) -> &'static str {
+        match self {
+            ComplianceFramework::SOC2 => "External SOC2 auditor",
+            ComplianceFramework::ISO27001 => "ISO certification body",
+            ComplianceFramework::GDPR => "EU data protection assessor",
+            ComplianceFramework::HIPAA => "HIPAA compliance assessor",
+            ComplianceFramework::PciDss => "Qualified Security Assessor",
+            ComplianceFramework::CCPA => "Privacy counsel",
+            ComplianceFramework::StateBreachLaws => "Outside counsel",
+        }
+    }
+
+    /// The risk that grows when this framework's findings go unfixed
+    pub fn risk_vector(&self) -> RiskVector {
+        match self {
+            ComplianceFramework::SOC2 | ComplianceFramework::ISO27001 => RiskVector::AccessControl,
+            _ => RiskVector::DataExposure,
+        }
+    }
+
+    /// What the first gap assessment turns up the day a framework lands in scope
+    pub fn scope_gaps(&self) -> Vec<String> {
+        let gaps: &[&str] = match self {
+            ComplianceFramework::SOC2 => &["Access reviews not performed"],
+            ComplianceFramework::ISO27001 => &["No information security management system", "Statement of applicability missing"],
+            ComplianceFramework::GDPR => &["No records of processing activities", "DPAs missing for EU sub-processors", "No DPO appointed"],
+            ComplianceFramework::HIPAA => &["No business associate agreements", "PHI access not logged"],
+            ComplianceFramework::PciDss => &["Cardholder data environment not segmented", "No quarterly ASV scans"],
+            ComplianceFramework::CCPA => &["No 'Do Not Sell' mechanism", "Consumer request process undocumented"],
+            ComplianceFramework::StateBreachLaws => &["Breach notification runbook missing"],
+        };
+        gaps.iter().map(|g| g.to_string()).collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct FrameworkStatus {
     pub compliance_percent: f64,  // 0-100
     pub certification_date: Option<u32>,  // Turn when certified
     pub next_audit: u32,          // Turn of next audit
     pub control_gaps: Vec<String>,
+    #[serde(default)]
+    pub unsubstantiated_percent: f64,  // Part of compliance_percent that only exists on paper
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ScheduledAudit {
-    pub framework: ComplianceFramework,
-    pub turn: u32,
-    pub auditor: String,
+impl FrameworkStatus {
+    /// Compliance needed at audit time to walk out with a certificate
+    pub const CERTIFICATION_THRESHOLD: f64 = 90.0;
+    /// Turns between a certification and the surveillance audit that renews it
+    pub const RECERTIFICATION_INTERVAL: u32 = 8;
+    /// Turns the auditor gives you before coming back after a failed audit
+    pub const FOLLOW_UP_INTERVAL: u32 = 4;
+
+    /// Compliance a framework starts at when the business drags it into scope
+    pub const SCOPE_BASELINE: f64 = 20.0;
+    /// Turns between a framework landing in scope and its first audit
+    pub const FIRST_AUDIT_LEAD: u32 = 6;
+
+    /// Share of papered-over compliance that evaporates each turn - the docs drift from reality
+    pub const PAPER_DECAY_RATE: f64 = 0.15;
+    /// Paper compliance below this is rounding error, not a liability
+    const PAPER_FLOOR: f64 = 0.5;
+
+    pub fn is_certified(&self) -> bool {
+        self.certification_date.is_some()
+    }
+
+    /// Move compliance - only progress without a toxic audit trail is real
+    pub fn add_progress(&mut self, progress: f64, substantiated: bool) {
+        let before = self.compliance_percent;
+        self.compliance_percent = (self.compliance_percent + progress).clamp(0.0, 100.0);
+        let gained = self.compliance_percent - before;
+        if !substantiated && gained > 0.0 {
+            self.unsubstantiated_percent += gained;
+        }
+        self.unsubstantiated_percent = self.unsubstantiated_percent.clamp(0.0, self.compliance_percent);
+    }
+
+    /// Compliance that would survive someone actually checking
+    pub fn substantiated_percent(&self) -> f64 {
+        (self.compliance_percent - self.unsubstantiated_percent).max(0.0)
+    }
+
+    /// One turn of drift - returns the points lost
+    pub fn decay_unsubstantiated(&mut self) -> f64 {
+        if self.unsubstantiated_percent <= 0.0 {
+            return 0.0;
+        }
+
+        let lost = if self.unsubstantiated_percent < Self::PAPER_FLOOR {
+            self.unsubstantiated_percent
+        } else {
+            self.unsubstantiated_percent * Self::PAPER_DECAY_RATE
+        };
+        self.unsubstantiated_percent -= lost;
+        self.compliance_percent = (self.compliance_percent - lost).max(0.0);
+        lost
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ComplianceFinding {
     pub id: String,
     pub framework: ComplianceFramework,
@@ -599,6 +1211,10 @@ pub struct ComplianceFinding {
     pub discovered_turn: u32,
     pub remediation_deadline: u32,
     pub status: FindingStatus,
+    #[serde(default)]
+    pub accepted_by: Option<String>,   // Whose signature is on the risk acceptance?
+    #[serde(default)]
+    pub accepted_turn: Option<u32>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -610,6 +1226,50 @@ pub enum FindingSeverity {
     Informational,
 }
 
+impl FindingSeverity {
+    /// How bad a finding is, going by how far short of the certification bar the audit found you
+    pub fn for_gap(gap: f64) -> Self {
+        match gap {
+            g if g >= 50.0 => FindingSeverity::Critical,
+            g if g >= 30.0 => FindingSeverity::High,
+            g if g >= 15.0 => FindingSeverity::Medium,
+            g if g > 0.0 => FindingSeverity::Low,
+            _ => FindingSeverity::Informational,
+        }
+    }
+
+    /// What fixing it properly takes - ($M budget, team capacity)
+    pub fn remediation_cost(&self) -> (f64, f64) {
+        match self {
+            FindingSeverity::Critical => (0.15, 10.0),
+            FindingSeverity::High => (0.10, 8.0),
+            FindingSeverity::Medium => (0.05, 5.0),
+            FindingSeverity::Low | FindingSeverity::Informational => (0.02, 3.0),
+        }
+    }
+
+    /// Board confidence lost when a finding like this blows through its remediation deadline
+    pub fn missed_deadline_confidence(&self) -> f64 {
+        match self {
+            FindingSeverity::Critical => 8.0,
+            FindingSeverity::High => 5.0,
+            FindingSeverity::Medium => 3.0,
+            FindingSeverity::Low => 1.0,
+            FindingSeverity::Informational => 0.0,
+        }
+    }
+
+    /// How bad it gets if this finding turns into a real incident
+    pub fn incident_severity(&self) -> IncidentSeverity {
+        match self {
+            FindingSeverity::Critical => IncidentSeverity::Critical,
+            FindingSeverity::High => IncidentSeverity::High,
+            FindingSeverity::Medium => IncidentSeverity::Medium,
+            FindingSeverity::Low | FindingSeverity::Informational => IncidentSeverity::Low,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum FindingStatus {
     Open,
@@ -621,7 +1281,7 @@ pub enum FindingStatus {
 
 impl ComplianceStatus {
     pub fn new() -> Self {
-        let mut frameworks = HashMap::new();
+        let mut frameworks = BTreeMap::new();
         
         // You need SOC2 to sell to enterprises
         frameworks.insert(ComplianceFramework::SOC2, FrameworkStatus {
@@ -633,18 +1293,50 @@ impl ComplianceStatus {
                 "Change management process incomplete".to_string(),
                 "Incident response plan not tested".to_string(),
             ],
+            unsubstantiated_percent: 0.0,
         });
 
         Self {
             frameworks,
-            audit_schedule: Vec::new(),
             open_findings: Vec::new(),
         }
     }
+
+    /// Start tracking `framework` with its first audit `FIRST_AUDIT_LEAD` turns after `turn`
+    ///
+    /// Returns false if it was already in scope - the existing status stands.
+    pub fn bring_into_scope(&mut self, framework: ComplianceFramework, turn: u32) -> bool {
+        if self.frameworks.contains_key(&framework) {
+            return false;
+        }
+        self.frameworks.insert(framework, FrameworkStatus {
+            compliance_percent: FrameworkStatus::SCOPE_BASELINE,
+            certification_date: None,
+            next_audit: turn + FrameworkStatus::FIRST_AUDIT_LEAD,
+            control_gaps: framework.scope_gaps(),
+            unsubstantiated_percent: 0.0,
+        });
+        true
+    }
+
+    /// SOC2 plus whatever the industry drags into scope
+    pub fn for_profile(profile: CompanyProfile) -> Self {
+        let mut status = Self::new();
+        for &(framework, compliance_percent, next_audit) in profile.extra_frameworks() {
+            status.frameworks.insert(framework, FrameworkStatus {
+                compliance_percent,
+                certification_date: None,
+                next_audit,
+                control_gaps: vec![format!("No {:?} gap assessment on file", framework)],
+                unsubstantiated_percent: 0.0,
+            });
+        }
+        status
+    }
 }
 
 /// Narrative integrity - does your story survive discovery?
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NarrativeIntegrity {
     pub score: f64,
     pub inconsistencies: Vec<NarrativeInconsistency>,
@@ -653,7 +1345,7 @@ pub struct NarrativeIntegrity {
     pub timeline_gaps: Vec<TimelineGap>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NarrativeInconsistency {
     pub turn: u32,
     pub description: String,
@@ -661,7 +1353,7 @@ pub struct NarrativeInconsistency {
     pub evidence_trail: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BuriedIncident {
     pub incident_id: String,
     pub actual_severity: IncidentSeverity,
@@ -671,7 +1363,7 @@ pub struct BuriedIncident {
     pub burial_method: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DelayedEscalation {
     pub incident_id: String,
     pub should_have_escalated_turn: u32,
@@ -679,7 +1371,7 @@ pub struct DelayedEscalation {
     pub delay_justification: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TimelineGap {
     pub start_turn: u32,
     pub end_turn: u32,
@@ -694,6 +1386,18 @@ pub enum IncidentSeverity {
     Critical,
 }
 
+impl IncidentSeverity {
+    /// $M an open incident like this burns on its first turn - overtime, forensics, outside counsel
+    pub fn carrying_cost(&self) -> f64 {
+        match self {
+            IncidentSeverity::Critical => 0.05,
+            IncidentSeverity::High => 0.02,
+            IncidentSeverity::Medium => 0.005,
+            IncidentSeverity::Low => 0.001,
+        }
+    }
+}
+
 impl NarrativeIntegrity {
     pub fn new() -> Self {
         Self {
@@ -743,6 +1447,17 @@ impl NarrativeIntegrity {
         });
     }
 
+    /// An accepted risk blew up - it hurts, but the paper trail shows you made a call
+    pub fn record_accepted_risk_materialized(&mut self, turn: u32, finding_id: String,
+                                             severity: IncidentSeverity) {
+        let penalty = self.severity_to_score(severity) * 2.0;
+        self.record_inconsistency(
+            turn,
+            format!("Accepted risk '{}' materialized. Acceptance was documented and signed off.", finding_id),
+            penalty,
+        );
+    }
+
     fn severity_to_score(&self, sev: IncidentSeverity) -> f64 {
         match sev {
             IncidentSeverity::Low => 1.0,
@@ -763,14 +1478,14 @@ impl NarrativeIntegrity {
         }
     }
 
-    /// Are you going to prison?
+    /// Are you going to prison? Integrity counts at the whole point the ending screen shows
     pub fn criminal_exposure(&self) -> bool {
-        self.score < 30.0 && self.buried_incidents.len() > 2
+        self.score.round() < 30.0 && self.buried_incidents.len() > 2
     }
 }
 
 /// Budget - always insufficient
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct Budget {
     pub total_annual: f64,
     pub spent: f64,
@@ -798,15 +1513,27 @@ impl Budget {
         self.total_annual - self.spent - self.committed
     }
 
-    pub fn can_spend(&self, amount: f64, category: BudgetCategory) -> bool {
-        let category_budget = match category {
+    /// Money left in one category
+    pub fn balance(&self, category: BudgetCategory) -> f64 {
+        match category {
             BudgetCategory::Headcount => self.headcount_budget,
             BudgetCategory::Tooling => self.tooling_budget,
             BudgetCategory::Project => self.project_budget,
             BudgetCategory::Emergency => self.emergency_reserve,
-        };
-        
-        self.available() >= amount && category_budget >= amount
+        }
+    }
+
+    fn balance_mut(&mut self, category: BudgetCategory) -> &mut f64 {
+        match category {
+            BudgetCategory::Headcount => &mut self.headcount_budget,
+            BudgetCategory::Tooling => &mut self.tooling_budget,
+            BudgetCategory::Project => &mut self.project_budget,
+            BudgetCategory::Emergency => &mut self.emergency_reserve,
+        }
+    }
+
+    pub fn can_spend(&self, amount: f64, category: BudgetCategory) -> bool {
+        self.available() >= amount && self.balance(category) >= amount
     }
 
     pub fn spend(&mut self, amount: f64, category: BudgetCategory) -> bool {
@@ -815,19 +1542,38 @@ impl Budget {
         }
         
         self.spent += amount;
+        *self.balance_mut(category) -= amount;
         
-        match category {
-            BudgetCategory::Headcount => self.headcount_budget -= amount,
-            BudgetCategory::Tooling => self.tooling_budget -= amount,
-            BudgetCategory::Project => self.project_budget -= amount,
-            BudgetCategory::Emergency => self.emergency_reserve -= amount,
+        true
+    }
+
+    /// Pay an unplanned bill - emergency reserve first, then whatever's left of the general budget
+    ///
+    /// Returns the part nobody could cover.
+    pub fn draw_down(&mut self, amount: f64) -> f64 {
+        let from_reserve = amount.min(self.emergency_reserve).min(self.available().max(0.0));
+        self.emergency_reserve -= from_reserve;
+        self.spent += from_reserve;
+
+        let from_general = (amount - from_reserve).min(self.available().max(0.0));
+        self.spent += from_general;
+
+        amount - from_reserve - from_general
+    }
+
+    /// Move unspent money between categories - total spend is unchanged
+    pub fn reallocate(&mut self, from: BudgetCategory, to: BudgetCategory, amount: f64) -> bool {
+        if from == to || amount <= 0.0 || self.balance(from) < amount {
+            return false;
         }
-        
+
+        *self.balance_mut(from) -= amount;
+        *self.balance_mut(to) += amount;
         true
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum BudgetCategory {
     Headcount,
     Tooling,
@@ -835,13 +1581,22 @@ pub enum BudgetCategory {
     Emergency,
 }
 
+impl BudgetCategory {
+    pub const ALL: [BudgetCategory; 4] = [
+        BudgetCategory::Headcount,
+        BudgetCategory::Tooling,
+        BudgetCategory::Project,
+        BudgetCategory::Emergency,
+    ];
+}
+
 /// Threat landscape - the world outside is hostile
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ThreatLandscape {
     pub current_threat_level: ThreatLevel,
     pub active_campaigns: Vec<ThreatCampaign>,
     pub industry_breaches: Vec<IndustryBreach>,
-    pub exploit_availability: HashMap<String, ExploitStatus>,
+    pub exploit_availability: BTreeMap<String, ExploitStatus>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -852,16 +1607,41 @@ pub enum ThreatLevel {
     Severe,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl ThreatLevel {
+    /// How far below its usual threshold a risk can sit and still blow up - attackers are looking
+    pub fn threshold_discount(&self) -> f64 {
+        match self {
+            ThreatLevel::Baseline | ThreatLevel::Elevated => 0.0,
+            ThreatLevel::High => 10.0,
+            ThreatLevel::Severe => 20.0,
+        }
+    }
+
+    /// Turns shaved off a fresh incident's deadline - active attackers don't wait for your sprint
+    pub fn deadline_cut(&self) -> u32 {
+        match self {
+            ThreatLevel::Baseline | ThreatLevel::Elevated => 0,
+            ThreatLevel::High => 1,
+            ThreatLevel::Severe => 2,
+        }
+    }
+}
+
+/// Risk level a campaign aimed at your industry adds to its vector each turn
+pub const CAMPAIGN_PRESSURE: f64 = 3.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ThreatCampaign {
     pub id: String,
     pub threat_actor: String,
     pub target_industry: String,
     pub active_since_turn: u32,
     pub techniques: Vec<String>,
+    #[serde(default)]
+    pub target_vector: Option<RiskVector>,  // What they go after - pressure lands here
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IndustryBreach {
     pub company: String,
     pub turn: u32,
@@ -869,7 +1649,7 @@ pub struct IndustryBreach {
     pub root_cause: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ExploitStatus {
     PoCAvailable,
     ActivelyExploited,
@@ -882,21 +1662,47 @@ impl ThreatLandscape {
             current_threat_level: ThreatLevel::Baseline,
             active_campaigns: Vec::new(),
             industry_breaches: Vec::new(),
-            exploit_availability: HashMap::new(),
+            exploit_availability: BTreeMap::new(),
         }
     }
 
-    pub fn evolve(&mut self, turn: u32) {
+    pub fn evolve(&mut self, turn: u32, rng: &mut impl rand::Rng) {
         // Threat level can change
         if turn % 4 == 0 {
-            self.current_threat_level = match rand::random::<f64>() {
+            self.current_threat_level = match rng.gen_range(0.0..1.0) {
                 x if x < 0.5 => ThreatLevel::Baseline,
                 x if x < 0.8 => ThreatLevel::Elevated,
                 x if x < 0.95 => ThreatLevel::High,
                 _ => ThreatLevel::Severe,
             };
+
+            // Hot periods come with somebody behind them; quiet ones send them elsewhere
+            match self.current_threat_level {
+                ThreatLevel::Baseline => self.active_campaigns.clear(),
+                ThreatLevel::High | ThreatLevel::Severe => {
+                    let profile = CompanyProfile::ALL[rng.gen_range(0..CompanyProfile::ALL.len())];
+                    let vector = RiskVector::ALL[rng.gen_range(0..RiskVector::ALL.len())];
+                    self.active_campaigns.push(ThreatCampaign {
+                        id: format!("campaign_{}", turn),
+                        threat_actor: format!("UNC-{}", rng.gen_range(1000..10000)),
+                        target_industry: profile.industry().to_string(),
+                        active_since_turn: turn,
+                        techniques: vec![format!("{:?}", vector)],
+                        target_vector: Some(vector),
+                    });
+                }
+                ThreatLevel::Elevated => {}
+            }
         }
     }
+
+    /// Vectors under a campaign aimed at this industry - one entry per campaign
+    pub fn targeted_vectors(&self, industry: &str) -> Vec<RiskVector> {
+        self.active_campaigns.iter()
+            .filter(|c| c.target_industry.eq_ignore_ascii_case(industry))
+            .filter_map(|c| c.target_vector)
+            .collect()
+    }
 }
 
 /// Audit trail quality - do you want discovery to find this?
@@ -925,7 +1731,7 @@ impl SessionToken {
 }
 
 /// Decision impact - now tracks everything
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DecisionImpact {
     pub decision_id: String,
     pub risk_delta: RiskDelta,
@@ -939,9 +1745,19 @@ pub struct DecisionImpact {
     pub compliance_impact: ComplianceImpact,
     pub narrative_impact: Option<NarrativeImpact>,
     pub audit_trail: AuditTrail,
+    #[serde(default)]
+    pub spawns_incident: Option<ActiveIncident>,  // Deadline is relative to the turn it spawns
+    #[serde(default)]
+    pub debt_paydown: BTreeMap<DebtCategory, f64>,  // Points cleared per category
+    #[serde(default)]
+    pub works_incident: Option<(String, f64)>,  // Containment points put into an incident
+    #[serde(default)]
+    pub brings_into_scope: Vec<ComplianceFramework>,  // New regulatory obligations
+    #[serde(default)]
+    pub hires: Vec<TeamMember>,  // People who start the day this lands
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub struct ReputationDelta {
     pub industry_delta: f64,
     pub board_delta: f64,
@@ -949,14 +1765,16 @@ pub struct ReputationDelta {
     pub vendor_delta: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct ComplianceImpact {
-    pub framework_progress: HashMap<ComplianceFramework, f64>,
+    pub framework_progress: BTreeMap<ComplianceFramework, f64>,
     pub new_findings: Vec<ComplianceFinding>,
     pub resolved_findings: Vec<String>,
+    #[serde(default)]
+    pub accepted_findings: Vec<String>,  // Findings formally accepted as risk
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NarrativeImpact {
     pub integrity_penalty: f64,
     pub creates_inconsistency: bool,
@@ -983,12 +1801,18 @@ impl DecisionImpact {
                 vendor_delta: 0.0,
             },
             compliance_impact: ComplianceImpact {
-                framework_progress: HashMap::new(),
+                framework_progress: BTreeMap::new(),
                 new_findings: Vec::new(),
                 resolved_findings: Vec::new(),
+                accepted_findings: Vec::new(),
             },
             narrative_impact: None,
             audit_trail: AuditTrail::Clean,
+            spawns_incident: None,
+            debt_paydown: BTreeMap::new(),
+            works_incident: None,
+            brings_into_scope: Vec::new(),
+            hires: Vec::new(),
         }
     }
 }
\ No newline at end of file
@@ -1,36 +1,164 @@
-use ciso_simulator::core::{DecisionFactory, DecisionLoader, GameError, GamePhase, GameState, ImpactPreview, Player, Result};
+use ciso_simulator::core::{BoardMemberRole, Budget, BudgetCategory, Campaign, Choice, ChoiceAvailability, ChoiceConfirmation, CompanyProfile, ComplianceFramework, Difficulty, ConsultationReport, Decision, DecisionLoader, DecisionSlot, Ending, EventType, GameError, GameEngine, GamePhase, GameState, ImpactPreview, IncidentCatalog, MoraleBoost, OperationalAction, Player, ReplayAction, ReplayLog, ProjectedImpact, ResponseOption, Result, RiskIndicator, RiskVector, TimePressure, CONSULT_CAPACITY_COST, EMERGENCY_BUDGET_CAPITAL_COST, EMERGENCY_BUDGET_MAX_SHARE};
 use ciso_simulator::narrative::display_ending;
 use ciso_simulator::ui::*;
-use ciso_simulator::GamePersistence;
-use std::path::PathBuf;
+use ciso_simulator::{load_with_password_retry, AutosavePolicy, GamePersistence, MAX_PASSWORD_ATTEMPTS};
+use std::path::{Path, PathBuf};
 
 fn main() -> Result<()> {
+    // Plain ASCII for terminals and screen readers that choke on box-drawing - --ascii or CISO_ASCII=1
+    let charset = Charset::select(std::env::args().skip(1));
+
     // Initialize terminal with RAII cleanup
-    let mut term = Terminal::new().map_err(|_| GameError::SystemFailure)?;
+    let mut term = Terminal::new(charset).map_err(|_| GameError::SystemFailure)?;
+
+    // Streamer-safe display - set CISO_PRIVACY_MODE=1 to show initials only
+    let privacy = PrivacyMode::from_env();
 
     // Display intro
     display_intro(&mut term)?;
 
-    // Get player name and company
-    let player = create_player(&mut term)?;
+    // One instance for the whole session - the save key is derived once, not every turn
+    let persistence = GamePersistence::new("ciso-game-2026")?;
+    // How often progress hits disk - set CISO_AUTOSAVE=turn|quarter|manual
+    let autosave = AutosavePolicy::from_env();
+
+    // Career record across runs
+    let stats_path = PathBuf::from("./ciso_stats.enc");
+    // Quitting from the pause menu comes back here; finishing or quitting at a checkpoint exits
+    while main_menu(&persistence, &stats_path, &mut term)? {
+        if !play_session(&persistence, autosave, privacy, &stats_path, &mut term)? {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// One run from resume or character creation to its end - true if the player quit back to the title menu
+fn play_session(
+    persistence: &GamePersistence,
+    autosave: AutosavePolicy,
+    privacy: PrivacyMode,
+    stats_path: &Path,
+    term: &mut Terminal,
+) -> Result<bool> {
+    let charset = term.charset();
 
-    // Initialize game state
-    let mut state = GameState::new(player.clone());
+    // Resume the previous run if there is one, otherwise start fresh
     let save_path = PathBuf::from("./ciso_save.enc");
+    let resumed = if save_path.exists() {
+        resume_saved_game(&save_path, privacy, term)?
+    } else {
+        None
+    };
+    let mut state = match resumed {
+        Some(state) => state,
+        None => {
+            let (player, difficulty) = create_player(privacy, term)?;
+            let mut state = GameState::new_with_difficulty(player, Campaign::from_env(), difficulty);
+            state.tutorial = persistence
+                .load_stats(stats_path)
+                .map(|stats| stats.wants_tutorial())
+                .unwrap_or(true);
+            state
+        }
+    };
+    // Risk-materialization incidents - data/incidents.toml overrides the built-in catalog
+    state.incident_catalog = match IncidentCatalog::load() {
+        Ok(catalog) => catalog,
+        Err(problem) => {
+            display_box(
+                "INCIDENT CATALOG NOT LOADED",
+                &format!("{}\n\nPlaying with the built-in incidents instead.", problem),
+                BoxKind::Warning,
+                term,
+            )?;
+            IncidentCatalog::builtin()
+        }
+    };
+
+    // Seed plus choices, kept next to the save - only a log that starts at turn 1 can replay
+    let replay_path = PathBuf::from("./ciso_replay.json");
+    let mut replay = ReplayRecorder {
+        log: if state.turn == 1 {
+            Some(ReplayLog::new(&state))
+        } else {
+            ReplayLog::read(&replay_path).ok().filter(|log| log.matches(&state))
+        },
+        path: replay_path,
+    };
+    let report_path = PathBuf::from("./ciso_report.json");
+    let audit_log_path = PathBuf::from("./ciso_audit_log.jsonl");
+    let event_log_path = PathBuf::from("./ciso_events.json");
+    let metrics_path = PathBuf::from("./ciso_metrics.csv");
 
     // Load decision data from TOML files (falls back to hardcoded decisions if not found)
-    let decision_loader = DecisionLoader::new().unwrap_or_else(|_| {
-        // Fallback to empty loader - will use hardcoded decisions from DecisionFactory
-        DecisionLoader {
-            decisions: Default::default(),
+    let decision_loader = match DecisionLoader::new() {
+        Ok((loader, skipped)) => {
+            // One bad file costs its own decisions, not everyone else's
+            if !skipped.is_empty() {
+                let problems: Vec<String> = skipped.iter().map(|p| p.to_string()).collect();
+                display_box(
+                    "SOME DECISION FILES SKIPPED",
+                    &format!("{}\n\nThe rest loaded; those turns use the built-in decisions.", problems.join("\n")),
+                    BoxKind::Warning,
+                    term,
+                )?;
+            }
+            loader
+        }
+        Err(problem) => {
+            // Broken mod data shouldn't end the run - but the modder needs to know
+            display_box(
+                "DECISION DATA NOT LOADED",
+                &format!("{}\n\nPlaying with the built-in decisions instead.", problem),
+                BoxKind::Warning,
+                term,
+            )?;
+            DecisionLoader::fallback()
         }
-    });
+    };
+
+    // Countdown on time-sensitive decisions - disabled unless CISO_TIME_PRESSURE_SECS is set
+    let time_pressure = TimePressure::from_env();
+    // "Are you sure?" before a choice commits - CISO_CONFIRM_CHOICES=0 skips it
+    let confirmation = ChoiceConfirmation::from_env();
+
+    // Skip-to-next-decision - armed from the turn hub, disarmed at the next real call
+    let mut fast_forward = FastForward::default();
+
+    // The engine runs the turn order; everything below is presentation
+    let mut engine = GameEngine::new(state, decision_loader);
+    let mut alerts = Vec::new();
 
     // Main game loop
     loop {
+        let state = engine.state();
+
         // Check if game is over
-        if matches!(state.phase, GamePhase::Ended(_)) {
-            display_ending(&state);
+        if engine.ending().is_some() {
+            display_ending(&privacy.redact(state));
+            wait_for_enter()?;
+            // What the next CISO inherits from you
+            display_box("HANDOFF REPORT", &state.generate_handoff(), BoxKind::Info, term)?;
+            // Machine-readable results for post-game analysis
+            let _ = state.write_report_json(&report_path);
+            // Signed transcript - discovery gets everything, including what you buried
+            let _ = persistence.export_audit_log(state, &save_path, &audit_log_path, false);
+            // The whole event trail, unsigned and unfiltered, for anyone who just wants to read it
+            if let Ok(trail) = persistence.event_trail(state, &save_path) {
+                let _ = state.write_audit_log(&trail, &event_log_path, None);
+            }
+            // Turn-by-turn numbers for anyone who wants the chart
+            let _ = state.write_metrics_csv(&metrics_path);
+
+            let mut stats = persistence.load_stats(stats_path).unwrap_or_default();
+            let unlocked = stats.record_run(state);
+            let _ = persistence.save_stats(&stats, stats_path);
+            for achievement in unlocked {
+                let line = format!("🏆 Achievement unlocked: {} - {}", achievement.title(), achievement.description());
+                println!("{}", charset.text(&line));
+            }
             wait_for_enter()?;
             break;
         }
@@ -43,92 +171,772 @@ fn main() -> Result<()> {
             GamePhase::Ended(_) => "Ended",
         };
 
-        display_chapter_header(state.turn, state.quarter, phase_name, &mut term)?;
-        display_status(&state, &mut term)?;
+        if fast_forward.show(TurnBeat::ChapterHeader) {
+            display_chapter_header(state.turn, state.quarter, phase_name, term)?;
+        }
+        if state.tutorial {
+            show_tutorial("TUTORIAL: READING THE DASHBOARD", TUTORIAL_METRICS, engine.state_mut(), term)?;
+        }
+        // Turn hub - Enter moves on, shortcuts open everything else
+        while fast_forward.show(TurnBeat::Status) {
+            let Some(action) = display_status(&privacy.redact(engine.state()), term)? else {
+                break;
+            };
+            if action == QuickAction::FastForward {
+                fast_forward.engage();
+                continue;
+            }
+            if action == QuickAction::Pause {
+                if !run_pause_menu(engine.state_mut(), privacy, persistence, &save_path, term)? {
+                    return Ok(true);
+                }
+                continue;
+            }
+            run_quick_action(action, engine.state_mut(), privacy, persistence, &save_path, &mut replay, term)?;
+        }
+
+        #[cfg(feature = "debug-console")]
+        run_debug_console(engine.state_mut(), term)?;
 
-        // Check for risk materialization
-        let materialized = state.materialize_risks();
-        if !materialized.is_empty() {
-            clear_screen(&mut term)?;
+        // Check for risk materialization - on top of whatever the turn change set off
+        alerts.extend(engine.check_risks());
+        if !alerts.is_empty() && fast_forward.show(TurnBeat::IncidentAlert) {
+            clear_screen(term)?;
 
             let mut incident_text = String::from("⚠ RISK MATERIALIZED ⚠\n\n");
-            for incident in &materialized {
+            for incident in &alerts {
                 incident_text.push_str(incident);
                 incident_text.push_str("\n\n");
             }
 
-            display_box("INCIDENT ALERT", &incident_text, &mut term)?;
+            display_box("INCIDENT ALERT", &incident_text, BoxKind::Incident, term)?;
         }
+        alerts.clear();
 
         // Get decision for this turn
-        if let Some(mut decision) = decision_loader
-            .get_decision(state.turn)
-            .cloned()
-            .or_else(|| DecisionFactory::generate_decision(&state, &decision_loader))
-        {
-            // Prepare choices for UI - only show business info
-            let choice_data: Vec<(String, String, String)> = decision
-                .choices
-                .iter()
-                .map(|c| {
-                    (
-                        c.label.clone(),
-                        c.description.clone(),
-                        format_simple_preview(&c.impact_preview),
-                    )
-                })
-                .collect();
-
-            // Display decision and get choice
-            let chosen_idx = display_decision_menu(
-                &decision.title,
-                &decision.context,
-                &choice_data,
-                &mut term,
-            )?;
-
-            let choice_id = decision.choices[chosen_idx].id.clone();
-            let choice_label = decision.choices[chosen_idx].label.clone();
-
-            // Apply the choice
-            let impact = decision.apply_choice(&choice_id, &mut state)?;
-
-            // NOW show the full outcome
-            show_decision_outcome(&choice_label, &impact, &mut term)?;
-
-            // Show alternate outcomes with what they would have gotten
-            show_alternate_outcomes_with_impacts(chosen_idx, &decision.choices, &mut term)?;
-
-            // Confirmation message
-            display_box(
-                "DECISION RECORDED",
-                "✓ Decision recorded in audit log.\n\nAll decisions are permanent and will be examined during discovery.",
-                &mut term,
-            )?;
-        } else {
-            clear_screen(&mut term)?;
+        if engine.next_decision().is_some_and(|(slot, _)| slot == DecisionSlot::Turn) {
+            if engine.state().tutorial {
+                show_tutorial("TUTORIAL: MAKING DECISIONS", TUTORIAL_DECISIONS, engine.state_mut(), term)?;
+                engine.state_mut().tutorial = false;
+            }
+            fast_forward.show(TurnBeat::Decision);
+        } else if fast_forward.show(TurnBeat::Notice) {
+            clear_screen(term)?;
             display_box(
                 "OPERATIONAL TEMPO",
                 "No major decisions this turn. Operations continue normally.\n\n\
                 Your team handles day-to-day security operations while you prepare for the next board meeting.",
-                &mut term,
+                BoxKind::Info,
+                term,
             )?;
+            run_operational_turn(engine.state_mut(), &mut replay, term)?;
+        }
+
+        // The turn's call, the open incident's, then the board's if the quarter closes next turn
+        while let Some((slot, _)) = engine.next_decision() {
+            if slot == DecisionSlot::BoardMeeting {
+                fast_forward.show(TurnBeat::QuarterlyReview);
+            }
+            let turn = engine.state().turn;
+            let resolved = engine.resolve(|decision, state| {
+                let (chosen, timed_out) = run_decision(decision, state, &time_pressure, confirmation, &mut replay, term)?;
+                Ok((decision.id.clone(), chosen, timed_out))
+            })?;
+            if let Some((decision_id, chosen_index, timed_out)) = resolved {
+                replay.record(turn, ReplayAction::Decision { decision_id, chosen_index, timed_out });
+            }
         }
 
         // Advance to next turn
-        state.advance_turn();
+        alerts = engine.advance();
+        let state = engine.state();
 
-        // Auto-save after each turn
-        let persistence = GamePersistence::new("ciso-game-2026")?;
-        if persistence.save(&state, &save_path).is_err() {
+        // Anything you buried that surfaced overnight
+        let leaks: Vec<String> = state
+            .narrative
+            .buried_incidents
+            .iter()
+            .filter(|b| b.turn_disclosed == Some(state.turn))
+            .map(|b| format!("{} (really {:?})", b.incident_id, b.actual_severity))
+            .collect();
+        if !leaks.is_empty() && fast_forward.show(TurnBeat::IncidentAlert) {
             display_box(
-                "WARNING",
-                "⚠ Failed to save game progress",
-                &mut term,
+                "LEAK",
+                &format!(
+                    "Something you buried just went public:\n\n{}\n\nThe board is asking why they heard it from a reporter.",
+                    leaks.join("\n")
+                ),
+                BoxKind::Danger,
+                term,
             )?;
         }
+
+        // Auto-save on the chosen cadence
+        if autosave.saves_after(state) {
+            save_progress(persistence, engine.state_mut(), &save_path, term)?;
+        }
+
+        // Manual saves - the player decides when, and we make sure they meant to walk away
+        if autosave == AutosavePolicy::Manual
+            && fast_forward.show(TurnBeat::Confirmation)
+            && !manual_save_checkpoint(persistence, engine.state_mut(), &save_path, term)? {
+            break;
+        }
+    }
+
+    Ok(false)
+}
+
+/// Write the run to disk - a failed save is worth a warning, not a crash
+fn save_progress(persistence: &GamePersistence, state: &mut GameState, save_path: &Path, term: &mut Terminal) -> Result<bool> {
+    if persistence.save(state, save_path).is_ok() {
+        return Ok(true);
+    }
+
+    display_box(
+        "WARNING",
+        "⚠ Failed to save game progress",
+        BoxKind::Warning,
+        term,
+    )?;
+    Ok(false)
+}
+
+/// End-of-turn menu in manual-save mode - returns false when the player quits
+fn manual_save_checkpoint(persistence: &GamePersistence, state: &mut GameState, save_path: &Path, term: &mut Terminal) -> Result<bool> {
+    // Every turn played since the last save is at stake
+    let mut unsaved = true;
+
+    loop {
+        let options = vec![
+            "Continue".to_string(),
+            "Save game".to_string(),
+            "Quit".to_string(),
+        ];
+        match display_menu("END OF TURN", &options, term)? {
+            0 => return Ok(true),
+            1 => {
+                if save_progress(persistence, state, save_path, term)? {
+                    unsaved = false;
+                }
+            }
+            _ => {
+                if !unsaved {
+                    return Ok(false);
+                }
+
+                let confirm = vec![
+                    "Keep playing".to_string(),
+                    "Save and quit".to_string(),
+                    "Quit anyway".to_string(),
+                ];
+                match display_menu("UNSAVED PROGRESS - quit without saving?", &confirm, term)? {
+                    0 => continue,
+                    1 => {
+                        if save_progress(persistence, state, save_path, term)? {
+                            return Ok(false);
+                        }
+                    }
+                    _ => return Ok(false),
+                }
+            }
+        }
+    }
+}
+
+/// Developer console - only compiled with the `debug-console` feature
+#[cfg(feature = "debug-console")]
+fn run_debug_console(state: &mut GameState, term: &mut Terminal) -> Result<()> {
+    use ciso_simulator::core::DebugCommand;
+
+    let options = vec!["Continue".to_string(), "Open debug console".to_string()];
+    if display_menu("DEBUG BUILD", &options, term)? == 0 {
+        return Ok(());
+    }
+
+    loop {
+        let line = get_input("debug> (type 'help', or 'exit' to resume)", term)?;
+        if line.trim() == "exit" || line.is_empty() {
+            return Ok(());
+        }
+
+        let output = match DebugCommand::parse(&line) {
+            Ok(command) => state.run_debug_command(&command)
+                .unwrap_or_else(|e| format!("Command failed: {:?}", e)),
+            Err(_) => format!("Unrecognized command.\n{}", DebugCommand::USAGE),
+        };
+        display_paginated_text(&output, term)?;
+    }
+}
+
+/// The run's replay log and the file it's kept in - `None` when this run can't be replayed
+struct ReplayRecorder {
+    log: Option<ReplayLog>,
+    path: PathBuf,
+}
+
+impl ReplayRecorder {
+    /// Append what the player did and rewrite the replay file - a lost replay never stops the game
+    fn record(&mut self, turn: u32, action: ReplayAction) {
+        if let Some(log) = &mut self.log {
+            log.record_action(turn, action);
+            let _ = log.write(&self.path);
+        }
+    }
+}
+
+/// Present a decision until the player commits - rewinds loop back to the menu
+///
+/// Returns the committed choice index and whether the clock picked it.
+fn run_decision(
+    decision: &mut Decision,
+    state: &mut GameState,
+    time_pressure: &TimePressure,
+    confirmation: ChoiceConfirmation,
+    replay: &mut ReplayRecorder,
+    term: &mut Terminal,
+) -> Result<(usize, bool)> {
+    // Prepare choices for UI - only show business info
+    let choice_data: Vec<(String, String, String)> = decision
+        .choices
+        .iter()
+        .map(|c| {
+            let mut preview = format_simple_preview(&c.impact_preview);
+            if let Some(line) = format_board_reaction(&state.predict_board_reaction(c)) {
+                preview.push_str(&format!("\n\n{}", line));
+            }
+            (c.label.clone(), c.description.clone(), preview)
+        })
+        .collect();
+
+    loop {
+        // Greyed out with the reason, rather than refused after the fact
+        let unavailable: Vec<Option<String>> = decision
+            .available_choices(state)
+            .into_iter()
+            .map(|(_, availability)| match availability {
+                ChoiceAvailability::Available => None,
+                ChoiceAvailability::Unavailable(unmet) => {
+                    Some(unmet.iter().map(|u| u.to_string()).collect::<Vec<_>>().join("; "))
+                }
+            })
+            .collect();
+
+        // Display decision and get choice
+        let choices = decision.choices.clone();
+        let mut consult = |idx: usize| match state.consult_team(&choices[idx]) {
+            Ok(report) => {
+                replay.record(state.turn, ReplayAction::Consult { decision_id: decision.id.clone(), choice_index: idx });
+                format_consultation(&report)
+            }
+            Err(_) => "Your team is at capacity. Nobody has time to dig into this.".to_string(),
+        };
+        let picked = display_decision_menu(
+            &decision.title,
+            &decision.context,
+            &choice_data,
+            &unavailable,
+            &mut consult,
+            time_pressure.limit_for(decision),
+            term,
+        )?;
+
+        // Snapshot before any timeout penalty so a rewind undoes it too
+        let snapshot = state.snapshot();
+
+        let chosen_idx = match picked {
+            Some(idx) => idx,
+            None => {
+                let idx = decision.default_available_choice(state).ok_or(GameError::InvalidAction)?;
+                state.record_decision_timeout(decision, &decision.choices[idx].label);
+                display_box(
+                    "TIME'S UP",
+                    &format!(
+                        "You ran out of time. The default went ahead without you:\n\n{}\n\nThe board noticed the hesitation.",
+                        decision.choices[idx].label
+                    ),
+                    BoxKind::Warning,
+                    term,
+                )?;
+                idx
+            }
+        };
+
+        // Don't let the player commit to something they can't afford
+        if let Some((_, ChoiceAvailability::Unavailable(unmet))) =
+            decision.available_choices(state).into_iter().nth(chosen_idx)
+        {
+            let reasons: Vec<String> = unmet.iter().map(|u| format!("• {}", u)).collect();
+            display_box(
+                "NOT AN OPTION",
+                &format!("You can't choose this right now:\n\n{}", reasons.join("\n")),
+                BoxKind::Warning,
+                term,
+            )?;
+            continue;
+        }
+
+        let choice_label = decision.choices[chosen_idx].label.clone();
+
+        // Apply the choice - the clock doesn't ask twice
+        let gate = if picked.is_some() { confirmation } else { ChoiceConfirmation::new(false) };
+        let confirm = |choice: &Choice| -> Result<bool> {
+            let options = vec!["Commit".to_string(), "Go back".to_string()];
+            let title = format!("Commit to \"{}\"? This goes in the record.", choice.label);
+            Ok(display_menu(&title, &options, term)? == 0)
+        };
+        let Some(impact) = gate.commit(decision, chosen_idx, state, confirm)? else {
+            continue;
+        };
+
+        // NOW show the full outcome
+        show_decision_outcome(&choice_label, &impact, term)?;
+
+        // Show alternate outcomes with what they would have gotten
+        show_alternate_outcomes_with_impacts(chosen_idx, &decision.choices, term)?;
+
+        if state.rewind_tokens > 0 {
+            let options = vec![
+                "Stand by this decision".to_string(),
+                format!("Rewind and decide again ({} left)", state.rewind_tokens),
+            ];
+            if display_menu("Commit this decision?", &options, term)? == 1 {
+                state.restore(snapshot)?;
+                continue;
+            }
+        }
+
+        // Confirmation message
+        display_box(
+            "DECISION RECORDED",
+            "✓ Decision recorded in audit log.\n\nAll decisions are permanent and will be examined during discovery.",
+            BoxKind::Success,
+            term,
+        )?;
+        return Ok((chosen_idx, picked.is_none()));
+    }
+}
+
+/// Esc from the turn hub - returns false when the player quits to the title menu
+fn run_pause_menu(
+    state: &mut GameState,
+    privacy: PrivacyMode,
+    persistence: &GamePersistence,
+    save_path: &Path,
+    term: &mut Terminal,
+) -> Result<bool> {
+    loop {
+        match display_pause_menu(term)? {
+            PauseAction::Resume => return Ok(true),
+            PauseAction::SaveNow => {
+                if save_progress(persistence, state, save_path, term)? {
+                    display_box("SAVED", &format!("Progress saved to {}.", save_path.display()), BoxKind::Success, term)?;
+                }
+            }
+            PauseAction::ViewAuditLog => display_event_log(&privacy.redact(state), term)?,
+            PauseAction::QuitToMenu => return Ok(false),
+        }
+    }
+}
+
+/// Dispatch a shortcut from the turn hub
+fn run_quick_action(
+    action: QuickAction,
+    state: &mut GameState,
+    privacy: PrivacyMode,
+    persistence: &GamePersistence,
+    save_path: &Path,
+    replay: &mut ReplayRecorder,
+    term: &mut Terminal,
+) -> Result<()> {
+    match action {
+        QuickAction::Risk => review_risk_dashboard(state, replay, term)?,
+        QuickAction::Heatmap => display_risk_heatmap(&privacy.redact(state), term)?,
+        QuickAction::Board => display_box("BOARD", &board_text(state), BoxKind::Info, term)?,
+        QuickAction::Incidents => {
+            if state.active_incidents.is_empty() {
+                display_box("INCIDENTS", "No open incidents. Savor it.", BoxKind::Success, term)?;
+            }
+            review_incident_timelines(state, privacy, replay, term)?;
+        }
+        QuickAction::Log => display_event_log(&privacy.redact(state), term)?,
+        QuickAction::History => display_paginated_text(&history_text(state), term)?,
+        // Handled by the hub itself - never dispatched here
+        QuickAction::FastForward | QuickAction::Pause => {}
+        QuickAction::Save => {
+            if save_progress(persistence, state, save_path, term)? {
+                display_box("SAVED", "Progress saved.", BoxKind::Success, term)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Who sits at the table, what they want, and how they feel about you
+fn board_text(state: &GameState) -> String {
+    let mut lines: Vec<String> = state
+        .board
+        .iter()
+        .map(|m| {
+            format!(
+                "{} ({:?}) - wants {:?}, satisfaction {:.0}%, influence {:.0}",
+                m.name, m.role, m.current_priority, m.satisfaction, m.influence
+            )
+        })
+        .collect();
+    if let Some(priority) = state.dominant_board_priority() {
+        lines.push(format!("\nThe room leans toward {:?}.", priority));
+    }
+    lines.push(format!("Weighted confidence: {:.0}%", state.weighted_board_confidence()));
+    lines.join("\n")
+}
+
+/// Every call you've made so far, in order
+fn history_text(state: &GameState) -> String {
+    let lines: Vec<String> = state
+        .events
+        .iter()
+        .filter(|e| e.event_type == EventType::DecisionMade)
+        .map(|e| format!("Turn {:>2}: {}", e.turn, e.description))
+        .collect();
+    if lines.is_empty() {
+        return "DECISION HISTORY\n\nNo decisions yet.".to_string();
     }
+    format!("DECISION HISTORY\n\n{}", lines.join("\n"))
+}
+
+/// Let the player look back over open incidents before deciding anything
+fn review_incident_timelines(state: &mut GameState, privacy: PrivacyMode, replay: &mut ReplayRecorder, term: &mut Terminal) -> Result<()> {
+    loop {
+        if state.active_incidents.is_empty() {
+            return Ok(());
+        }
+
+        let mut options: Vec<String> = state
+            .active_incidents
+            .iter()
+            .map(|i| format!("{} [{:?}] - {:.0}% contained", i.title, i.severity, i.containment_percent))
+            .collect();
+        options.push("Continue".to_string());
+
+        let idx = display_menu("Open incidents", &options, term)?;
+        let Some(incident) = state.active_incidents.get(idx) else {
+            return Ok(());
+        };
+        let incident_id = incident.id.clone();
+
+        let escalation = match incident.escalation_turn {
+            Some(turn) if incident.escalated_to_board => format!("Escalated to the board on turn {}", turn),
+            _ if incident.escalation_is_timely(state.turn) => "Escalate to the board (timely)".to_string(),
+            _ => "Escalate to the board (late - expect questions)".to_string(),
+        };
+        let desk = vec![
+            "View timeline".to_string(),
+            "Forecast responses".to_string(),
+            "Assign a team member".to_string(),
+            escalation,
+            "Back".to_string(),
+        ];
+        match display_menu(&incident.title, &desk, term)? {
+            0 => {
+                let shown = privacy.redact(state);
+                if let Some(incident) = shown.active_incidents.get(idx) {
+                    display_incident_timeline(incident, term)?;
+                }
+            }
+            1 => display_box(
+                "RESPONSE FORECAST",
+                &forecast_text(&incident.forecast_outcomes(state)),
+                BoxKind::Incident,
+                term,
+            )?,
+            2 => assign_responder(state, &incident_id, replay, term)?,
+            3 => escalate_incident(state, &incident_id, replay, term)?,
+            _ => {}
+        }
+    }
+}
+
+/// Take an incident to the board - already-escalated ones just say so
+fn escalate_incident(state: &mut GameState, incident_id: &str, replay: &mut ReplayRecorder, term: &mut Terminal) -> Result<()> {
+    let Some(incident) = state.active_incidents.iter().find(|i| i.id == incident_id) else {
+        return Ok(());
+    };
+    if incident.escalated_to_board {
+        display_box(
+            "ALREADY ESCALATED",
+            "The board already knows. Telling them twice won't make it better.",
+            BoxKind::Info,
+            term,
+        )?;
+        return Ok(());
+    }
+
+    let timely = incident.escalation_is_timely(state.turn);
+    let capital_before = state.political_capital.total;
+    state.escalate_incident_to_board(incident_id)?;
+    replay.record(state.turn, ReplayAction::Escalate { incident_id: incident_id.to_string() });
+    let delta = state.political_capital.total - capital_before;
+
+    let (kind, message) = if timely {
+        (BoxKind::Success, "The board appreciates hearing it from you first.")
+    } else {
+        (BoxKind::Warning, "The board wants to know why this took so long. So will discovery.")
+    };
+    display_box(
+        "BOARD ESCALATION",
+        &format!("{}\n\nPolitical capital: {:+.0}", message, delta),
+        kind,
+        term,
+    )?;
+    Ok(())
+}
+
+/// One paragraph per response path - estimates, so they're rounded like estimates
+fn forecast_text(forecast: &[(ResponseOption, ProjectedImpact)]) -> String {
+    let mut paragraphs: Vec<String> = forecast
+        .iter()
+        .map(|(option, impact)| {
+            format!(
+                "{}\n  Reputation {:+.0} | ARR {:+.2}M | Integrity {:+.0} | {:.0}% chance it goes public",
+                option.label(),
+                impact.reputation_delta,
+                impact.arr_delta_millions,
+                impact.integrity_delta,
+                impact.disclosure_likelihood * 100.0
+            )
+        })
+        .collect();
+    paragraphs.push("Projections assume nothing else goes wrong. Something else will go wrong.".to_string());
+    paragraphs.join("\n\n")
+}
+
+/// Pick a responder by name - skill speeds containment, the responder pays in burnout
+fn assign_responder(state: &mut GameState, incident_id: &str, replay: &mut ReplayRecorder, term: &mut Terminal) -> Result<()> {
+    let mut options: Vec<String> = state
+        .team
+        .members
+        .iter()
+        .map(|m| format!("{} ({:?}) - skill {:.0}, burnout {:.0}%", m.name, m.role, m.skill_level, m.burnout_level))
+        .collect();
+    options.push("Cancel".to_string());
 
+    let idx = display_menu("Assign to incident", &options, term)?;
+    let Some(name) = state.team.members.get(idx).map(|m| m.name.clone()) else {
+        return Ok(());
+    };
+
+    let message = match state.assign_to_incident(incident_id, &name) {
+        Ok(()) => {
+            replay.record(state.turn, ReplayAction::AssignResponder { incident_id: incident_id.to_string(), member: name.clone() });
+            format!("{} is now working the incident.", name)
+        }
+        Err(GameError::InvalidAction) => format!("{} is already on this incident.", name),
+        Err(e) => return Err(e),
+    };
+    display_box("INCIDENT RESPONSE", &message, BoxKind::Info, term)?;
+    Ok(())
+}
+
+/// Team time put into one mitigation push from the risk dashboard
+const MITIGATION_PACKAGE_CAPACITY: f64 = 2.0;
+/// Tooling spend for one mitigation push
+const MITIGATION_PACKAGE_BUDGET: f64 = 0.1;
+
+/// Every vector at a glance - pick one to throw money and people at
+fn review_risk_dashboard(state: &mut GameState, replay: &mut ReplayRecorder, term: &mut Terminal) -> Result<()> {
+    let mut options: Vec<String> = RiskVector::ALL
+        .iter()
+        .map(|vector| {
+            let (level, coverage) = state
+                .risk
+                .vectors
+                .get(vector)
+                .map(|m| (m.current_level, m.mitigation_coverage))
+                .unwrap_or((0.0, 0.0));
+            format!("{:?}: level {:.0}, coverage {:.0}%", vector, level, coverage)
+        })
+        .collect();
+    options.push("Continue".to_string());
+
+    let title = format!(
+        "Risk dashboard - invest in a vector ({:.0} capacity, ${:.2}M tooling)",
+        MITIGATION_PACKAGE_CAPACITY, MITIGATION_PACKAGE_BUDGET
+    );
+    let idx = display_menu(&title, &options, term)?;
+    let Some(&vector) = RiskVector::ALL.get(idx) else {
+        return Ok(());
+    };
+
+    let (message, kind) = match state.invest_mitigation(vector, MITIGATION_PACKAGE_BUDGET, MITIGATION_PACKAGE_CAPACITY) {
+        Ok(()) => {
+            replay.record(state.turn, ReplayAction::Mitigate {
+                vector,
+                budget: MITIGATION_PACKAGE_BUDGET,
+                capacity: MITIGATION_PACKAGE_CAPACITY,
+            });
+            let coverage = state.risk.vectors.get(&vector).map(|m| m.mitigation_coverage).unwrap_or(0.0);
+            (format!("{:?} coverage now {:.0}%.", vector, coverage), BoxKind::Success)
+        }
+        Err(GameError::TeamCapacityExceeded) => ("Your team has no capacity left for this.".to_string(), BoxKind::Warning),
+        Err(GameError::InsufficientBudget) => ("The tooling budget can't cover it.".to_string(), BoxKind::Warning),
+        Err(e) => return Err(e),
+    };
+    display_box("RISK DASHBOARD", &message, kind, term)?;
+    Ok(())
+}
+
+/// Quiet turn - offer one optional operational action
+fn run_operational_turn(state: &mut GameState, replay: &mut ReplayRecorder, term: &mut Terminal) -> Result<()> {
+    let riskiest = RiskVector::ALL
+        .iter()
+        .copied()
+        .max_by(|a, b| {
+            let level = |v: &RiskVector| state.risk.vectors.get(v).map(|m| m.current_level).unwrap_or(0.0);
+            level(a).total_cmp(&level(b))
+        })
+        .unwrap_or(RiskVector::DataExposure);
+    let covered: Vec<RiskVector> = RiskVector::ALL
+        .iter()
+        .copied()
+        .filter(|v| state.risk.vectors.get(v).is_some_and(|m| m.mitigation_coverage > 0.0))
+        .collect();
+
+    // Prep goes to whichever audit comes up first
+    let next_audit = state
+        .compliance
+        .frameworks
+        .iter()
+        .min_by_key(|(_, status)| status.next_audit)
+        .map(|(framework, _)| *framework)
+        .unwrap_or(ComplianceFramework::SOC2);
+
+    let mut actions = vec![
+        OperationalAction::PayDownDebt,
+        OperationalAction::PrepareAudit(next_audit),
+        OperationalAction::PatchVector(riskiest),
+    ];
+    actions.extend(MoraleBoost::ALL.iter().map(|kind| OperationalAction::BoostMorale(*kind)));
+    if !covered.is_empty() {
+        actions.push(OperationalAction::MaintainControls(covered));
+    }
+    if let Some(rebalance) = suggested_rebalance(&state.budget) {
+        actions.push(rebalance);
+    }
+
+    let mut options: Vec<String> = actions.iter().map(|a| a.label()).collect();
+    let emergency_ask = emergency_budget_ask(state);
+    if let Some(amount) = emergency_ask {
+        options.push(format!(
+            "Ask the board for ${:.2}M emergency budget ({:.0} capital)",
+            amount, EMERGENCY_BUDGET_CAPITAL_COST
+        ));
+    }
+    options.push("Let operations run".to_string());
+
+    let idx = display_menu("Use the quiet turn?", &options, term)?;
+    let Some(action) = actions.get(idx) else {
+        if let (Some(amount), true) = (emergency_ask, idx == actions.len()) {
+            let outcome = state.request_emergency_budget(amount);
+            if outcome.is_ok() {
+                replay.record(state.turn, ReplayAction::EmergencyBudget { amount });
+            }
+            let (message, kind) = match outcome {
+                Ok(true) => (format!("Approved. ${:.2}M lands in the emergency reserve.", amount), BoxKind::Success),
+                Ok(false) => ("Denied. The CFO asks whether security has heard of prioritization.".to_string(), BoxKind::Danger),
+                Err(GameError::InsufficientPoliticalCapital) => {
+                    ("You don't have the capital to get on the agenda.".to_string(), BoxKind::Warning)
+                }
+                Err(e) => return Err(e),
+            };
+            display_box("BOARD", &message, kind, term)?;
+        }
+        return Ok(());
+    };
+
+    let message = match state.perform_operational_action(action) {
+        Ok(summary) => {
+            replay.record(state.turn, ReplayAction::Operational(action.clone()));
+            summary
+        }
+        Err(GameError::InsufficientBudget) => "Finance declined the spend. Maybe next quarter.".to_string(),
+        Err(GameError::TeamCapacityExceeded) => "Your team has no capacity left for this.".to_string(),
+        Err(GameError::InsufficientPoliticalCapital) => "The CFO won't take the meeting. You're out of favors.".to_string(),
+        Err(GameError::InvalidAction) => "Nothing there for the team to work on.".to_string(),
+        Err(e) => return Err(e),
+    };
+    display_box("OPERATIONS", &message, BoxKind::Info, term)?;
+
+    Ok(())
+}
+
+/// Running dry - the largest ask the board will hear, offered only when money is tight
+fn emergency_budget_ask(state: &GameState) -> Option<f64> {
+    let amount = (state.budget.total_annual * EMERGENCY_BUDGET_MAX_SHARE * 100.0).floor() / 100.0;
+    (state.budget.available() < amount && amount > 0.0).then_some(amount)
+}
+
+/// Even out the flushest and leanest spendable categories - the reserve stays put
+fn suggested_rebalance(budget: &Budget) -> Option<OperationalAction> {
+    let spendable: Vec<BudgetCategory> = BudgetCategory::ALL
+        .into_iter()
+        .filter(|c| *c != BudgetCategory::Emergency)
+        .collect();
+    let by_balance = |a: &BudgetCategory, b: &BudgetCategory| budget.balance(*a).total_cmp(&budget.balance(*b));
+    let from = spendable.iter().copied().max_by(by_balance)?;
+    let to = spendable.iter().copied().min_by(by_balance)?;
+
+    let amount = ((budget.balance(from) - budget.balance(to)) / 2.0 * 100.0).floor() / 100.0;
+    (amount >= 0.05).then_some(OperationalAction::RebalanceBudget { from, to, amount })
+}
+
+/// Title menu - returns false if the player quits
+fn main_menu(persistence: &GamePersistence, stats_path: &Path, term: &mut Terminal) -> Result<bool> {
+    let options = vec![
+        "New Game".to_string(),
+        "Statistics & Achievements".to_string(),
+        "Glossary".to_string(),
+        "Quit".to_string(),
+    ];
+
+    loop {
+        match display_menu("CISO JUDGMENT SIMULATOR", &options, term)? {
+            0 => return Ok(true),
+            1 => display_stats(persistence, stats_path, term)?,
+            2 => {
+                let query = get_input("Look up a term:", term)?;
+                display_glossary(&query, term)?;
+            }
+            _ => return Ok(false),
+        }
+    }
+}
+
+fn display_stats(persistence: &GamePersistence, stats_path: &Path, term: &mut Terminal) -> Result<()> {
+    let stats = persistence.load_stats(stats_path).unwrap_or_default();
+
+    let endings = [
+        Ending::GoldenCISO,
+        Ending::LawsuitSurvivor,
+        Ending::PostBreachCleanup,
+        Ending::CriminalInvestigation,
+    ];
+    let mut lines = vec![format!("Runs completed: {}", stats.runs_completed)];
+    if let Some(best) = stats.best_score {
+        lines.push(format!("Best score: {:.0}", best));
+    }
+    lines.push(String::new());
+    lines.push("Endings reached:".to_string());
+    for ending in &endings {
+        lines.push(format!("  {:?}: {}", ending, stats.ending_count(ending)));
+    }
+    lines.push(String::new());
+    lines.push(format!("Achievements ({}):", stats.achievements.len()));
+    for achievement in &stats.achievements {
+        lines.push(format!("  🏆 {} - {}", achievement.title(), achievement.description()));
+    }
+
+    display_box("CAREER RECORD", &lines.join("\n"), BoxKind::Info, term)?;
     Ok(())
 }
 
@@ -158,22 +966,108 @@ Just like reality."#;
     Ok(())
 }
 
-fn create_player(term: &mut Terminal) -> Result<Player> {
+/// Load the autosave - if it was written under another password, ask for it before giving up
+fn resume_saved_game(save_path: &Path, privacy: PrivacyMode, term: &mut Terminal) -> Result<Option<GameState>> {
+    let mut prompt_failed = false;
+    let loaded = load_with_password_retry(
+        "ciso-game-2026",
+        MAX_PASSWORD_ATTEMPTS,
+        |password| GamePersistence::new(password)?.load(save_path),
+        |attempt| {
+            let prompt = format!(
+                "Save file is locked - enter its password (attempt {}/{}):",
+                attempt, MAX_PASSWORD_ATTEMPTS
+            );
+            let password = if privacy.enabled {
+                get_hidden_input(&prompt, term)
+            } else {
+                get_secret_input(&prompt, term)
+            };
+            prompt_failed |= password.is_err();
+            password.ok()
+        },
+    );
+    if prompt_failed {
+        return Err(GameError::SystemFailure);
+    }
+
+    match loaded {
+        Ok(state) => {
+            display_box(
+                "WELCOME BACK",
+                &format!(
+                    "Resuming {} at {} - turn {}.",
+                    privacy.display_name(&state.player.name), state.player.company_name, state.turn
+                ),
+                BoxKind::Success,
+                term,
+            )?;
+            Ok(Some(state))
+        }
+        Err(_) => {
+            display_box(
+                "SAVE UNREADABLE",
+                "⚠ Couldn't open the existing save. Starting a new game - it will be overwritten.",
+                BoxKind::Danger,
+                term,
+            )?;
+            Ok(None)
+        }
+    }
+}
+
+const TUTORIAL_METRICS: &str = "The status screen is your dashboard. What each number means:\n\n\
+    Board Confidence - how much the board trusts you. Let it collapse and you're the scapegoat.\n\n\
+    Integrity - narrative integrity: how well what you said matches what happened. \
+    Every buried incident and spun report chips away at it, and discovery reads all of it.\n\n\
+    Political Capital - favors you can spend to push unpopular decisions through. \
+    Earned by meeting objectives, burned by fighting the board.\n\n\
+    Risk Total - accumulated exposure across every risk vector. It grows quietly until something breaks.\n\n\
+    Quarterly objectives sit underneath. Red means a Critical objective is behind - the board will notice.";
+
+const TUTORIAL_DECISIONS: &str = "Each decision shows a preview: estimated ARR, budget, timeline, and the board's likely mood.\n\n\
+    That preview is what you'd know in real life. The real consequences - risk, compliance, \
+    narrative - only show up after you commit.\n\n\
+    Press 'c' to have your team investigate a choice first (it costs capacity), \
+    and 'g' to look up any term marked [?].\n\n\
+    Choose carefully. The audit log keeps everything.";
+
+/// First-run overlay - skipping drops the rest of the tutorial too
+fn show_tutorial(title: &str, text: &str, state: &mut GameState, term: &mut Terminal) -> Result<()> {
+    display_box(title, text, BoxKind::Info, term)?;
+
+    let options = vec!["Continue".to_string(), "Skip the rest of the tutorial".to_string()];
+    if display_menu("Tutorial", &options, term)? == 1 {
+        state.tutorial = false;
+    }
+    Ok(())
+}
+
+fn create_player(privacy: PrivacyMode, term: &mut Terminal) -> Result<(Player, Difficulty)> {
     clear_screen(term)?;
 
-    let name = get_input("Enter your name:", term).map_err(|_| GameError::SystemFailure)?;
+    // In privacy mode the name never hits the screen, not even while typing
+    let name = if privacy.enabled {
+        get_secret_input("Enter your name:", term).map(|n| n.to_string())
+    } else {
+        get_input("Enter your name:", term)
+    }
+    .map_err(|_| GameError::SystemFailure)?;
 
-    // Generate company name options
-    let companies = vec![
-        "TechFlow Solutions".to_string(),
-        "DataSync Inc.".to_string(),
-        "CloudVault Systems".to_string(),
-        "NexGen Analytics".to_string(),
-        "SecureStack Technologies".to_string(),
-    ];
+    // The industry decides the regulators, the board's agenda and the blast radius
+    let companies: Vec<String> = CompanyProfile::ALL
+        .iter()
+        .map(|p| format!("{} ({})", p.company_name(), p.summary()))
+        .collect();
 
     let company_idx = display_menu("Select your company:", &companies, term)?;
-    let company_name = companies[company_idx].clone();
+    let profile = CompanyProfile::ALL[company_idx];
+    let company_name = profile.company_name().to_string();
+
+    // How deep a hole the last CISO left
+    let difficulties: Vec<String> = Difficulty::ALL.iter().map(|d| d.summary().to_string()).collect();
+    let difficulty_idx = display_menu("Select difficulty:", &difficulties, term)?;
+    let difficulty = Difficulty::ALL[difficulty_idx];
 
     clear_screen(term)?;
     display_box(
@@ -183,30 +1077,22 @@ fn create_player(term: &mut Terminal) -> Result<Player> {
             You are now the CISO of {}\n\n\
             The board has high expectations.\n\
             Your predecessor's documentation: 'Good luck'",
-            name, company_name
+            privacy.display_name(&name), company_name
         ),
+        BoxKind::Info,
         term,
     )?;
 
-    Ok(Player::new(name, company_name, "CISO".to_string()))
-}
-
-fn display_status(state: &GameState, term: &mut Terminal) -> Result<()> {
-    let status_text = format!(
-        "CISO: {} | Company: {}\n\
-         ARR: ${:.1}M | Board Confidence: {:.0}% | Integrity: {:.0}%\n\
-         Risk Total: {:.0} | Budget Available: ${:.2}M",
-        state.player.name,
-        state.player.company_name,
-        state.business.arr_millions,
-        state.business.board_confidence_percent,
-        state.narrative.score,
-        state.risk.total_exposure,
-        state.budget.available()
-    );
+    Ok((Player::new(name, company_name, "CISO".to_string()).with_profile(profile), difficulty))
+}
 
-    display_box("CURRENT STATUS", &status_text, term)?;
-    Ok(())
+fn display_status(state: &GameState, term: &mut Terminal) -> Result<Option<QuickAction>> {
+    let charset = term.charset();
+    let status_text = status_text(state, charset);
+
+    let history: Vec<f64> = state.exposure_history.iter().copied().collect();
+    let action = display_status_screen(&status_text, &objective_summary(&state.quarterly_objectives, charset), &history, term)?;
+    Ok(action)
 }
 
 fn format_simple_preview(preview: &ImpactPreview) -> String {
@@ -237,4 +1123,48 @@ fn format_simple_preview(preview: &ImpactPreview) -> String {
     }
 
     lines.join("\n")
-}
\ No newline at end of file
+}
+
+fn format_board_reaction(reactions: &[(BoardMemberRole, f64)]) -> Option<String> {
+    if reactions.is_empty() {
+        return None;
+    }
+
+    let parts: Vec<String> = reactions
+        .iter()
+        .map(|(role, delta)| {
+            let role = match role {
+                BoardMemberRole::CEO => "CEO",
+                BoardMemberRole::CFO => "CFO",
+                BoardMemberRole::CTO => "CTO",
+                BoardMemberRole::COO => "COO",
+                BoardMemberRole::GeneralCounsel => "GC",
+                BoardMemberRole::BoardChair => "Chair",
+            };
+            let sign = if *delta >= 0.5 {
+                "+"
+            } else if *delta <= -0.5 {
+                "−"
+            } else {
+                "="
+            };
+            format!("{} {}", role, sign)
+        })
+        .collect();
+
+    Some(format!("Board reaction: {}", parts.join(", ")))
+}
+
+fn format_consultation(report: &ConsultationReport) -> String {
+    let risk = match report.risk_indicator {
+        RiskIndicator::Reduces => "Risk goes DOWN",
+        RiskIndicator::Neutral => "Risk roughly unchanged",
+        RiskIndicator::Increases => "Risk goes UP",
+        RiskIndicator::Significant => "Risk goes UP significantly",
+    };
+
+    format!(
+        "{}\nOn the audit trail: {}\n(Consultation used {:.0} capacity)",
+        risk, report.audit_hint, CONSULT_CAPACITY_COST
+    )
+}
@@ -1,5 +1,5 @@
-use crate::core::state::{GameState, Ending, EventType, GamePhase};
-use crate::core::types::{RiskVector, IncidentSeverity};
+use crate::core::state::{GameState, Ending, EventType, GamePhase, ScoreReport};
+use crate::core::types::RiskVector;
 use colored::*;
 
 pub fn display_ending(state: &GameState) {
@@ -10,6 +10,51 @@ pub fn display_ending(state: &GameState) {
             Ending::PostBreachCleanup => display_post_breach_cleanup(state),
             Ending::CriminalInvestigation => display_criminal_investigation(state),
         }
+        display_ending_breakdown(state);
+        display_score_report(state);
+    }
+}
+
+/// The report card - five sub-scores, the weighted total, and the letter
+fn display_score_report(state: &GameState) {
+    let report = state.score_report();
+
+    println!();
+    println!("{}", "═══ SCORE REPORT ═══".white().bold());
+    for ((label, score), (_, weight)) in report.components().iter().zip(ScoreReport::WEIGHTS) {
+        let line = format!("  {:<20} {:>3.0}/100  (x{:.2})", label, score, weight);
+        match *score {
+            s if s >= 70.0 => println!("{}", line.green()),
+            s if s >= 40.0 => println!("{}", line.yellow()),
+            _ => println!("{}", line.red()),
+        }
+    }
+    println!("  {:<20} {:>3.0}/100", "Total".bold(), report.total);
+    println!("  {} {}", "Grade:".bold(), format!("{:?}", report.grade).bright_cyan().bold());
+}
+
+/// The audit behind the story - every gate, what you had, and what it needed
+fn display_ending_breakdown(state: &GameState) {
+    let gates = state.ending_gates();
+    let sections = [
+        (Ending::CriminalInvestigation, "Avoiding a criminal investigation"),
+        (Ending::GoldenCISO, "Earning the Golden CISO ending"),
+        (Ending::PostBreachCleanup, "Avoiding post-breach cleanup"),
+    ];
+
+    println!();
+    println!("{}", "═══ WHY THIS ENDING ═══".white().bold());
+    for (tier, heading) in sections {
+        println!();
+        println!("{}", heading.white().bold());
+        for gate in gates.iter().filter(|g| g.tier == tier) {
+            let line = format!("{}: {} (needs {})", gate.label, gate.actual, gate.requirement);
+            if gate.passed {
+                println!("  {} {}", "✓".green(), line);
+            } else {
+                println!("  {} {}", "✗".red(), line.red());
+            }
+        }
     }
 }
 
@@ -23,7 +68,7 @@ fn display_golden_ciso(state: &GameState) {
     println!();
     
     println!("I'm proud to announce that {} has been promoted to VP of Security,", state.player.name.bright_cyan());
-    println!("reporting directly to me. Over the past 16 months, they've transformed");
+    println!("reporting directly to me. Over the past {} months, they've transformed", state.campaign.final_turn);
     println!("our security posture while enabling our fastest growth period ever.");
     println!();
     println!("Key achievements:");
@@ -274,10 +319,17 @@ fn display_final_stats(state: &GameState) {
     println!();
     
     println!("{}", "Material Incidents:".red().bold());
-    let critical = state.active_incidents.iter().filter(|i| i.severity == IncidentSeverity::Critical).count();
-    let high = state.active_incidents.iter().filter(|i| i.severity == IncidentSeverity::High).count();
-    println!("  Critical:               {}", critical);
-    println!("  High:                   {}", high);
+    let scoreboard = state.incident_scoreboard();
+    println!("                          Open / Resolved");
+    println!("  Critical:               {:>4} / {}", scoreboard.active.critical, scoreboard.resolved.critical);
+    println!("  High:                   {:>4} / {}", scoreboard.active.high, scoreboard.resolved.high);
+    println!("  Medium:                 {:>4} / {}", scoreboard.active.medium, scoreboard.resolved.medium);
+    println!("  Low:                    {:>4} / {}", scoreboard.active.low, scoreboard.resolved.low);
+    match scoreboard.average_turns_to_resolve {
+        Some(avg) => println!("  Avg Time to Resolve:    {:.1} turns", avg),
+        None => println!("  Avg Time to Resolve:    n/a (nothing resolved)"),
+    }
+    println!("  Total Incident Cost:    ${:.2}M", scoreboard.total_cost_millions);
     println!();
     
     println!("{}", "Budget Management:".green().bold());
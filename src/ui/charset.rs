@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+
+/// Environment variable that turns on ASCII-only rendering at launch
+pub const ASCII_MODE_ENV: &str = "CISO_ASCII";
+/// Command-line flag that does the same
+pub const ASCII_FLAG: &str = "--ascii";
+
+/// What the screen is allowed to draw with - box-drawing and emoji, or plain ASCII
+///
+/// Some terminals render the fancy glyphs as tofu, and screen readers read them
+/// out as noise. ASCII mode swaps every glyph for one plain character, so
+/// layouts keep their widths.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Charset {
+    #[default]
+    Unicode,
+    Ascii,
+}
+
+impl Charset {
+    /// ASCII when `--ascii` is on the command line or `CISO_ASCII` is set to anything but empty, `0`, or `false`
+    pub fn select(args: impl IntoIterator<Item = String>) -> Self {
+        let flagged = args.into_iter().any(|arg| arg == ASCII_FLAG);
+        let from_env = std::env::var(ASCII_MODE_ENV)
+            .map(|v| !matches!(v.trim().to_ascii_lowercase().as_str(), "" | "0" | "false"))
+            .unwrap_or(false);
+
+        if flagged || from_env { Charset::Ascii } else { Charset::Unicode }
+    }
+
+    /// The text as it should reach the screen - borrowed untouched unless something needs swapping
+    pub fn text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match self {
+            Charset::Ascii if !text.is_ascii() => Cow::Owned(text.chars().map(ascii_glyph).collect()),
+            _ => Cow::Borrowed(text),
+        }
+    }
+
+    /// One character, swapped if this charset can't draw it
+    pub fn glyph(&self, c: char) -> char {
+        match self {
+            Charset::Ascii => ascii_glyph(c),
+            Charset::Unicode => c,
+        }
+    }
+}
+
+/// Plain stand-in for a glyph - always exactly one character, so cell widths hold
+fn ascii_glyph(c: char) -> char {
+    if c.is_ascii() {
+        return c;
+    }
+    match c {
+        '═' | '━' => '=',
+        '─' | '—' | '–' | '−' | '·' => '-',
+        '│' | '║' | '┃' => '|',
+        '┌' | '┐' | '└' | '┘' | '├' | '┤' | '┬' | '┴' | '┼'
+        | '╔' | '╗' | '╚' | '╝' | '╠' | '╣' | '╦' | '╩' | '╬'
+        | '╭' | '╮' | '╯' | '╰' => '+',
+        '▶' | '▸' | '►' | '→' => '>',
+        '←' => '<',
+        '↑' => '^',
+        '↓' => 'v',
+        '•' | '⏱' | '🏆' => '*',
+        '⚠' | '‼' => '!',
+        'ℹ' => 'i',
+        '✓' | '✔' => '+',
+        '✗' | '✖' | '×' => 'x',
+        '█' => '#',
+        '▓' => '%',
+        '▇' | '▆' | '▅' => '|',
+        '▄' | '▃' => ':',
+        '▂' | '▁' | '░' | '…' => '.',
+        '‘' | '’' => '\'',
+        '“' | '”' => '"',
+        _ => '?',
+    }
+}
@@ -0,0 +1,42 @@
+/// The screens a turn walks through, from boilerplate to the ones that matter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnBeat {
+    ChapterHeader,
+    Status,
+    Notice,        // "Operations continue normally" and friends
+    Confirmation,  // End-of-turn prompts that only ask you to carry on
+    Decision,
+    IncidentAlert,
+    QuarterlyReview,
+}
+
+impl TurnBeat {
+    /// Beats that need the player - fast-forward never skips these
+    pub fn requires_stop(&self) -> bool {
+        matches!(self, TurnBeat::Decision | TurnBeat::IncidentAlert | TurnBeat::QuarterlyReview)
+    }
+}
+
+/// Skip to the next real call - turns still run, the Enter presses don't
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FastForward {
+    pub active: bool,
+}
+
+impl FastForward {
+    pub fn engage(&mut self) {
+        self.active = true;
+    }
+
+    /// Whether to show this beat - reaching a stop hands control back to the player
+    pub fn show(&mut self, beat: TurnBeat) -> bool {
+        if !self.active {
+            return true;
+        }
+        if beat.requires_stop() {
+            self.active = false;
+            return true;
+        }
+        false
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+/// Plain-language definitions for the jargon the board throws at you
+pub fn glossary() -> HashMap<&'static str, &'static str> {
+    HashMap::from([
+        ("API", "Application Programming Interface - how other software talks to yours. Also how attackers do."),
+        ("ARR", "Annual Recurring Revenue - the number the board actually reads."),
+        ("BAA", "Business Associate Agreement - HIPAA contract making vendors liable for health data they touch."),
+        ("CCPA", "California Consumer Privacy Act - state privacy law with per-record fines."),
+        ("CEO", "Chief Executive Officer - wants growth, hates surprises."),
+        ("CFO", "Chief Financial Officer - every control is a cost center until proven otherwise."),
+        ("CISO", "Chief Information Security Officer - you. Responsible for everything, in control of little."),
+        ("CTO", "Chief Technology Officer - owns the systems you're trying to secure."),
+        ("credential stuffing", "Attackers replaying passwords leaked elsewhere against your login page."),
+        ("DLP", "Data Loss Prevention - tooling that watches data leaving the company."),
+        ("DPO", "Data Protection Officer - the person GDPR requires you to name. Usually whoever was out sick that day."),
+        ("DPA", "Data Processing Agreement - contract terms for how a vendor handles personal data."),
+        ("EDR", "Endpoint Detection and Response - agents on laptops and servers that spot and stop attacks."),
+        ("EMEA", "Europe, Middle East and Africa - one sales region, dozens of regulators."),
+        ("EU", "European Union - a market worth entering, with a privacy regulator attached."),
+        ("FTC", "Federal Trade Commission - US regulator that pursues deceptive security claims."),
+        ("GDPR", "General Data Protection Regulation - EU privacy law, fines up to 4% of global revenue."),
+        ("HIPAA", "Health Insurance Portability and Accountability Act - US rules for protecting health data."),
+        ("IPO", "Initial Public Offering - going public. Every past incident becomes a disclosure."),
+        ("IR", "Incident Response - the plan, people, and process for handling a breach."),
+        ("MFA", "Multi-Factor Authentication - a second proof of identity beyond the password."),
+        ("MTTD", "Mean Time To Detect - how long attackers are inside before anyone notices."),
+        ("PCI", "Payment Card Industry Data Security Standard (PCI DSS) - the rules for anyone who touches card numbers."),
+        ("PII", "Personally Identifiable Information - data that identifies a real person."),
+        ("ransomware", "Malware that encrypts your systems and demands payment to unlock them."),
+        ("ROI", "Return On Investment - what the CFO asks about every security purchase."),
+        ("SIEM", "Security Information and Event Management - central log collection and alerting."),
+        ("SOC2", "Service Organization Control 2 - the audit report enterprise customers demand before buying."),
+        ("zero-day", "A vulnerability with no patch available yet."),
+    ])
+}
+
+/// Glossary entries whose term contains `query` (case-insensitive), alphabetized
+pub fn glossary_lookup(query: &str) -> Vec<(&'static str, &'static str)> {
+    let query = query.trim().to_lowercase();
+    let mut entries: Vec<_> = glossary()
+        .into_iter()
+        .filter(|(term, _)| term.to_lowercase().contains(&query))
+        .collect();
+    entries.sort_by_key(|(term, _)| term.to_lowercase());
+    entries
+}
+
+/// Glossary terms mentioned in `text`, in order of first appearance
+pub fn find_terms(text: &str) -> Vec<&'static str> {
+    let mut found: Vec<(usize, &'static str)> = glossary()
+        .into_keys()
+        .filter_map(|term| find_word(text, term).map(|pos| (pos, term)))
+        .collect();
+    found.sort();
+    found.into_iter().map(|(_, term)| term).collect()
+}
+
+/// Mark the first mention of each glossary term with `[?]`
+pub fn annotate_terms(text: &str) -> String {
+    let mut marks: Vec<usize> = find_terms(text)
+        .into_iter()
+        .filter_map(|term| find_word(text, term).map(|pos| pos + term.len()))
+        .collect();
+    marks.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut annotated = text.to_string();
+    for end in marks {
+        annotated.insert_str(end, "[?]");
+    }
+    annotated
+}
+
+/// Byte offset of `term` as a whole word - acronyms match exactly, phrases ignore case
+fn find_word(text: &str, term: &str) -> Option<usize> {
+    let is_acronym = term.chars().all(|c| !c.is_lowercase());
+    // ASCII lowering keeps byte offsets valid for the original text
+    let haystack = if is_acronym { text.to_string() } else { text.to_ascii_lowercase() };
+    let needle = if is_acronym { term.to_string() } else { term.to_ascii_lowercase() };
+
+    let is_word_char = |c: char| c.is_alphanumeric();
+    haystack.match_indices(&needle).map(|(pos, _)| pos).find(|&pos| {
+        let before = haystack[..pos].chars().next_back();
+        let after = haystack[pos + needle.len()..].chars().next();
+        !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char)
+    })
+}
@@ -8,30 +8,48 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Sparkline, Wrap},
     Frame, Terminal as RatatuiTerminal,
 };
 use std::io;
+use std::time::{Duration, Instant};
 use textwrap::wrap;
+use zeroize::{Zeroize, Zeroizing};
+
+pub mod charset;
+pub mod fast_forward;
+pub mod glossary;
+pub mod privacy;
+pub use charset::*;
+pub use fast_forward::*;
+pub use glossary::*;
+pub use privacy::*;
 
 // Import types needed for the UI logic
 use crate::core::decisions::Choice;
+use crate::core::state::{ActiveIncident, Event as StateEvent, EventVisibility, GameState, IncidentTimelineEntry, Objective, ObjectivePriority};
 use crate::core::types::{DecisionImpact, RiskVector};
 
 /// RAII Terminal wrapper - ensures cleanup on drop
 pub struct Terminal {
     terminal: RatatuiTerminal<CrosstermBackend<io::Stdout>>,
+    charset: Charset,
 }
 
 impl Terminal {
-    pub fn new() -> io::Result<Self> {
+    /// Pick the charset first - every frame drawn through this terminal honours it
+    pub fn new(charset: Charset) -> io::Result<Self> {
         enable_raw_mode()?;
         let mut stdout = io::stdout();
         execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
         let backend = CrosstermBackend::new(stdout);
         let terminal = RatatuiTerminal::new(backend)?;
 
-        Ok(Self { terminal })
+        Ok(Self { terminal, charset })
+    }
+
+    pub fn charset(&self) -> Charset {
+        self.charset
     }
 
     pub fn width(&self) -> usize {
@@ -46,11 +64,24 @@ impl Terminal {
     }
 
     /// Draw a frame with the given render function
+    ///
+    /// In ASCII mode the finished frame is swept cell by cell, so borders,
+    /// highlight symbols and sparklines get swapped along with the text.
     fn draw<F>(&mut self, f: F) -> io::Result<()>
     where
         F: FnOnce(&mut Frame),
     {
-        self.terminal.draw(f)?;
+        let charset = self.charset;
+        self.terminal.draw(|frame| {
+            f(frame);
+            if charset == Charset::Ascii {
+                for cell in &mut frame.buffer_mut().content {
+                    if let Some(c) = cell.symbol().chars().next().filter(|c| !c.is_ascii()) {
+                        cell.set_char(charset.glyph(c));
+                    }
+                }
+            }
+        })?;
         Ok(())
     }
 
@@ -87,13 +118,229 @@ pub fn wait_for_enter() -> io::Result<()> {
     }
 }
 
+/// Screens one keypress away from the turn hub
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuickAction {
+    Risk,
+    Heatmap,
+    Board,
+    Incidents,
+    Log,
+    History,
+    Save,
+    Pause,
+    FastForward,
+}
+
+impl QuickAction {
+    pub const ALL: [QuickAction; 9] = [
+        QuickAction::Risk,
+        QuickAction::Heatmap,
+        QuickAction::Board,
+        QuickAction::Incidents,
+        QuickAction::Log,
+        QuickAction::History,
+        QuickAction::Save,
+        QuickAction::Pause,
+        QuickAction::FastForward,
+    ];
+
+    pub fn key(&self) -> char {
+        match self {
+            QuickAction::Risk => 'r',
+            QuickAction::Heatmap => 'm',
+            QuickAction::Board => 'b',
+            QuickAction::Incidents => 'i',
+            QuickAction::Log => 'l',
+            QuickAction::History => 'h',
+            QuickAction::Save => 's',
+            QuickAction::Pause => 'p',
+            QuickAction::FastForward => 'f',
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            QuickAction::Risk => "Risk",
+            QuickAction::Heatmap => "Heatmap",
+            QuickAction::Board => "Board",
+            QuickAction::Incidents => "Incidents",
+            QuickAction::Log => "Log",
+            QuickAction::History => "History",
+            QuickAction::Save => "Save",
+            QuickAction::Pause => "Pause",
+            QuickAction::FastForward => "Skip to next decision",
+        }
+    }
+
+    /// Case doesn't matter - caps lock shouldn't cost you the risk dashboard
+    pub fn from_key(key: char) -> Option<Self> {
+        let key = key.to_ascii_lowercase();
+        Self::ALL.into_iter().find(|action| action.key() == key)
+    }
+}
+
+/// "R: Risk | B: Board | ..." - the bottom bar on the turn screen
+pub fn quick_action_bar() -> String {
+    QuickAction::ALL
+        .iter()
+        .map(|action| format!("{}: {}", action.key().to_ascii_uppercase(), action.label()))
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+/// Wait for Enter (`None`) or a quick-action shortcut - Esc pauses too
+pub fn wait_for_quick_action() -> io::Result<Option<QuickAction>> {
+    loop {
+        match event::read()? {
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter,
+                kind: KeyEventKind::Press,
+                ..
+            }) => return Ok(None),
+            Event::Key(KeyEvent {
+                code: KeyCode::Esc,
+                kind: KeyEventKind::Press,
+                ..
+            }) => return Ok(Some(QuickAction::Pause)),
+            Event::Key(KeyEvent {
+                code: KeyCode::Char(c),
+                kind: KeyEventKind::Press,
+                ..
+            }) => {
+                if let Some(action) = QuickAction::from_key(c) {
+                    return Ok(Some(action));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// What the player picked from the pause menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseAction {
+    Resume,
+    SaveNow,
+    ViewAuditLog,
+    QuitToMenu,
+}
+
+impl PauseAction {
+    pub const ALL: [PauseAction; 4] = [
+        PauseAction::Resume,
+        PauseAction::SaveNow,
+        PauseAction::ViewAuditLog,
+        PauseAction::QuitToMenu,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PauseAction::Resume => "Resume",
+            PauseAction::SaveNow => "Save Now",
+            PauseAction::ViewAuditLog => "View Audit Log",
+            PauseAction::QuitToMenu => "Quit to Menu",
+        }
+    }
+}
+
+/// Cursor over the pause menu - keys go in, an action comes out once one is picked
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PauseMenu {
+    pub selected: usize,
+}
+
+impl PauseMenu {
+    /// Arrows move, Enter picks, digits jump straight to an entry, Esc backs out to the game
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<PauseAction> {
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+        let count = PauseAction::ALL.len();
+        match key.code {
+            KeyCode::Esc => Some(PauseAction::Resume),
+            KeyCode::Enter => Some(PauseAction::ALL[self.selected]),
+            KeyCode::Up => {
+                self.selected = (self.selected + count - 1) % count;
+                None
+            }
+            KeyCode::Down => {
+                self.selected = (self.selected + 1) % count;
+                None
+            }
+            KeyCode::Char(c) => c.to_digit(10)
+                .and_then(|d| (d as usize).checked_sub(1))
+                .and_then(|i| PauseAction::ALL.get(i).copied()),
+            _ => None,
+        }
+    }
+}
+
+/// The Esc menu - starts from a cleared screen, so a half-drawn frame underneath can't bleed through
+pub fn display_pause_menu(term: &mut Terminal) -> io::Result<PauseAction> {
+    term.clear()?;
+    let mut menu = PauseMenu::default();
+
+    loop {
+        let mut list_state = ListState::default();
+        list_state.select(Some(menu.selected));
+
+        term.draw(|f| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Min(1), Constraint::Length(3)])
+                .split(f.area());
+
+            let title = Paragraph::new("PAUSED")
+                .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(Color::Cyan)))
+                .style(Style::default().fg(Color::White).add_modifier(Modifier::BOLD))
+                .alignment(Alignment::Center);
+
+            f.render_widget(title, chunks[0]);
+
+            let items: Vec<ListItem> = PauseAction::ALL.iter()
+                .enumerate()
+                .map(|(i, action)| ListItem::new(format!("{}. {}", i + 1, action.label())))
+                .collect();
+            let list = List::new(items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(Color::Green)),
+                )
+                .highlight_style(
+                    Style::default()
+                        .bg(Color::Cyan)
+                        .fg(Color::Black)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .highlight_symbol("▶ ");
+
+            f.render_stateful_widget(list, chunks[1], &mut list_state);
+
+            let help = Paragraph::new("↑↓ to navigate | Enter to select | Esc to resume")
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center);
+
+            f.render_widget(help, chunks[2]);
+        })?;
+
+        if let Event::Key(key) = event::read()?
+            && let Some(action) = menu.handle_key(key)
+        {
+            term.clear()?;
+            return Ok(action);
+        }
+    }
+}
+
 /// Display paginated text with proper scrolling
 pub fn display_paginated_text(text: &str, term: &mut Terminal) -> io::Result<()> {
     let mut scroll: u16 = 0;
 
     loop {
         let size = term.terminal.size()?;
-        let max_scroll = text.lines().count().saturating_sub(size.height as usize - 4);
+        let max_scroll = max_scroll_for(text.lines().count(), size.height as usize);
 
         term.draw(|f| {
             let chunks = Layout::default()
@@ -124,57 +371,70 @@ pub fn display_paginated_text(text: &str, term: &mut Terminal) -> io::Result<()>
         })?;
 
         // Handle input
-        match event::read()? {
-            Event::Key(KeyEvent {
-                code: KeyCode::Enter,
-                kind: KeyEventKind::Press,
-                ..
-            }) => break,
-            Event::Key(KeyEvent {
-                code: KeyCode::Char('q'),
-                kind: KeyEventKind::Press,
-                ..
-            }) => break,
-            Event::Key(KeyEvent {
-                code: KeyCode::Up,
-                kind: KeyEventKind::Press,
-                ..
-            }) => {
-                scroll = scroll.saturating_sub(1);
-            }
-            Event::Key(KeyEvent {
-                code: KeyCode::Down,
-                kind: KeyEventKind::Press,
-                ..
-            }) => {
-                if (scroll as usize) < max_scroll {
-                    scroll += 1;
+        if let Event::Key(KeyEvent { code, kind: KeyEventKind::Press, .. }) = event::read()? {
+            match code {
+                KeyCode::Enter | KeyCode::Char('q') => break,
+                code => {
+                    scroll_with_key(code, &mut scroll, max_scroll);
                 }
             }
-            Event::Key(KeyEvent {
-                code: KeyCode::PageUp,
-                kind: KeyEventKind::Press,
-                ..
-            }) => {
-                scroll = scroll.saturating_sub(10);
-            }
-            Event::Key(KeyEvent {
-                code: KeyCode::PageDown,
-                kind: KeyEventKind::Press,
-                ..
-            }) => {
-                scroll = (scroll + 10).min(max_scroll as u16);
-            }
-            _ => {}
         }
     }
 
     Ok(())
 }
 
+/// Furthest a list of `line_count` lines can scroll in a `height`-row terminal - borders and help bar eat four rows
+pub fn max_scroll_for(line_count: usize, height: usize) -> usize {
+    line_count.saturating_sub(height.saturating_sub(4))
+}
+
+/// Arrow and page keys move the view, clamped to `max_scroll` - returns false for any other key
+fn scroll_with_key(code: KeyCode, scroll: &mut u16, max_scroll: usize) -> bool {
+    let max_scroll = max_scroll.min(u16::MAX as usize) as u16;
+    match code {
+        KeyCode::Up => *scroll = scroll.saturating_sub(1),
+        KeyCode::Down => *scroll = (*scroll + 1).min(max_scroll),
+        KeyCode::PageUp => *scroll = scroll.saturating_sub(10),
+        KeyCode::PageDown => *scroll = scroll.saturating_add(10).min(max_scroll),
+        _ => return false,
+    }
+    true
+}
+
+/// How typed characters are echoed back
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InputEcho {
+    Plain,
+    Masked,  // One bullet per character
+    Hidden,  // Nothing at all - not even the length
+}
+
 /// Get string input from user with proper echo and editing
 pub fn get_input(prompt: &str, term: &mut Terminal) -> io::Result<String> {
-    let mut input = String::new();
+    Ok(read_input(prompt, InputEcho::Plain, term)?.to_string())
+}
+
+/// Password entry - echoes bullets, and the secret is wiped when the caller drops it
+pub fn get_secret_input(prompt: &str, term: &mut Terminal) -> io::Result<Zeroizing<String>> {
+    read_input(prompt, InputEcho::Masked, term)
+}
+
+/// Like `get_secret_input`, but with no echo at all - for privacy mode
+pub fn get_hidden_input(prompt: &str, term: &mut Terminal) -> io::Result<Zeroizing<String>> {
+    read_input(prompt, InputEcho::Hidden, term)
+}
+
+/// What the input field shows for a masked entry - one bullet per character
+pub fn mask_input(input: &str) -> String {
+    "•".repeat(input.chars().count())
+}
+
+/// Capacity reserved up front so typing never reallocates (and strands copies of) a secret
+const INPUT_CAPACITY: usize = 256;
+
+fn read_input(prompt: &str, echo: InputEcho, term: &mut Terminal) -> io::Result<Zeroizing<String>> {
+    let mut input = Zeroizing::new(String::with_capacity(INPUT_CAPACITY));
 
     loop {
         term.draw(|f| {
@@ -195,7 +455,12 @@ pub fn get_input(prompt: &str, term: &mut Terminal) -> io::Result<String> {
             f.render_widget(prompt_widget, chunks[0]);
 
             // Input field
-            let input_widget = Paragraph::new(input.as_str())
+            let shown = match echo {
+                InputEcho::Plain => input.to_string(),
+                InputEcho::Masked => mask_input(&input),
+                InputEcho::Hidden => String::new(),
+            };
+            let input_widget = Paragraph::new(shown)
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
@@ -235,7 +500,7 @@ pub fn get_input(prompt: &str, term: &mut Terminal) -> io::Result<String> {
                 code: KeyCode::Char(c),
                 kind: KeyEventKind::Press,
                 ..
-            }) => {
+            }) if input.len() + c.len_utf8() <= INPUT_CAPACITY => {
                 input.push(c);
             }
             Event::Key(KeyEvent {
@@ -243,7 +508,7 @@ pub fn get_input(prompt: &str, term: &mut Terminal) -> io::Result<String> {
                 kind: KeyEventKind::Press,
                 ..
             }) => {
-                input.clear();
+                input.zeroize();
                 break;
             }
             _ => {}
@@ -359,18 +624,28 @@ pub fn display_menu(title: &str, options: &[String], term: &mut Terminal) -> io:
 }
 
 /// Display decision menu with preview panel
+///
+/// `consult` is called at most once per choice when the player presses `c`,
+/// and returns the team's assessment to show in the preview panel.
+/// With a `time_limit`, returns `None` once the clock runs out.
 pub fn display_decision_menu(
     title: &str,
     context: &str,
     choices: &[(String, String, String)],
+    unavailable: &[Option<String>],
+    consult: &mut dyn FnMut(usize) -> String,
+    time_limit: Option<Duration>,
     term: &mut Terminal,
-) -> io::Result<usize> {
+) -> io::Result<Option<usize>> {
     let mut list_state = ListState::default();
     list_state.select(Some(0));
     let mut context_scroll: u16 = 0;
+    let mut consulted: Vec<Option<String>> = vec![None; choices.len()];
+    let started = Instant::now();
 
     loop {
         let selected = list_state.selected().unwrap_or(0);
+        let remaining = time_limit.map(|limit| limit.saturating_sub(started.elapsed()));
         let size = term.terminal.size()?;
         
         // Calculate max scroll for context
@@ -389,7 +664,7 @@ pub fn display_decision_menu(
                 .split(f.area());
 
             // Title and context with scroll support
-            let title_text = format!("━━━ {} ━━━\n\n{}", title, context);
+            let title_text = format!("━━━ {} ━━━\n\n{}", title, annotate_terms(context));
             let title_widget = Paragraph::new(title_text)
                 .block(Block::default()
                     .borders(Borders::ALL)
@@ -410,8 +685,11 @@ pub fn display_decision_menu(
             let items: Vec<ListItem> = choices
                 .iter()
                 .enumerate()
-                .map(|(i, (label, _, _))| {
-                    ListItem::new(format!("[{}] {}", i + 1, label))
+                .map(|(i, (label, _, _))| match unavailable.get(i).cloned().flatten() {
+                    // Still listed so the player sees what they're missing - just not pickable
+                    Some(reason) => ListItem::new(format!("[{}] {} ({})", i + 1, label, reason))
+                        .style(Style::default().fg(Color::DarkGray)),
+                    None => ListItem::new(format!("[{}] {}", i + 1, label)),
                 })
                 .collect();
 
@@ -434,7 +712,13 @@ pub fn display_decision_menu(
 
             // Preview panel
             let (_label, description, preview) = &choices[selected];
-            let preview_text = format!("{}\n\n{}", description, preview);
+            let mut preview_text = format!("{}\n\n{}", annotate_terms(description), preview);
+            if let Some(Some(reason)) = unavailable.get(selected) {
+                preview_text = format!("✗ NOT AVAILABLE: {}\n\n{}", reason, preview_text);
+            }
+            if let Some(assessment) = &consulted[selected] {
+                preview_text.push_str(&format!("\n\n═══ TEAM ASSESSMENT ═══\n{}", assessment));
+            }
 
             let preview_widget = Paragraph::new(preview_text)
                 .block(
@@ -450,8 +734,12 @@ pub fn display_decision_menu(
 
             // Help text
             let help_lines = vec![
-                Line::from("Tab/Shift+Tab: switch focus | ↑↓: navigate/scroll | Enter: decide | q: quit"),
-                Line::from("(Real consequences unknown until after you commit)").style(Style::default().fg(Color::Red)),
+                Line::from("Tab/Shift+Tab: switch focus | ↑↓: navigate/scroll | c: consult team | g: glossary [?] | Enter: decide | q: quit"),
+                match remaining {
+                    Some(left) => Line::from(format!("⏱ {}s left before the default is chosen for you", left.as_secs()))
+                        .style(Style::default().fg(Color::Red)),
+                    None => Line::from("(Real consequences unknown until after you commit)").style(Style::default().fg(Color::Red)),
+                },
             ];
 
             let help = Paragraph::new(help_lines)
@@ -462,6 +750,16 @@ pub fn display_decision_menu(
             f.render_widget(help, main_chunks[2]);
         })?;
 
+        // Out of time - the caller picks the default; otherwise wake up to redraw the countdown
+        if let Some(left) = remaining {
+            if left.is_zero() {
+                return Ok(None);
+            }
+            if !event::poll(left.min(Duration::from_millis(500)))? {
+                continue;
+            }
+        }
+
         // Handle input with context scrolling
         match event::read()? {
             Event::Key(KeyEvent {
@@ -532,19 +830,36 @@ pub fn display_decision_menu(
                 };
                 list_state.select(Some(i));
             }
+            // Due diligence costs capacity - only pay once per option
             Event::Key(KeyEvent {
-                code: KeyCode::Enter,
+                code: KeyCode::Char('c'),
+                kind: KeyEventKind::Press,
+                ..
+            }) if consulted[selected].is_none() => {
+                consulted[selected] = Some(consult(selected));
+            }
+            // Explain the [?] terms in view
+            Event::Key(KeyEvent {
+                code: KeyCode::Char('g'),
                 kind: KeyEventKind::Press,
                 ..
             }) => {
-                return Ok(selected);
+                let in_view = format!("{}\n{}", context, choices[selected].1);
+                display_glossary_entries(&find_terms(&in_view), term)?;
+            }
+            Event::Key(KeyEvent {
+                code: KeyCode::Enter,
+                kind: KeyEventKind::Press,
+                ..
+            }) if unavailable.get(selected).is_none_or(|reason| reason.is_none()) => {
+                return Ok(Some(selected));
             }
             Event::Key(KeyEvent {
                 code: KeyCode::Char('q') | KeyCode::Esc,
                 kind: KeyEventKind::Press,
                 ..
             }) => {
-                return Ok(selected);
+                return Ok(Some(selected));
             }
             _ => {}
         }
@@ -700,8 +1015,58 @@ pub fn show_alternate_outcomes_with_impacts(
     Ok(())
 }
 
+/// How loud a box should be - severity readable before the first word is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoxKind {
+    Info,
+    Warning,
+    Incident,
+    Success,
+    Danger,
+}
+
+impl BoxKind {
+    pub const ALL: [BoxKind; 5] = [
+        BoxKind::Info,
+        BoxKind::Warning,
+        BoxKind::Incident,
+        BoxKind::Success,
+        BoxKind::Danger,
+    ];
+
+    pub fn color(&self) -> Color {
+        match self {
+            BoxKind::Info => Color::Cyan,
+            BoxKind::Warning => Color::Yellow,
+            BoxKind::Incident => Color::Magenta,
+            BoxKind::Success => Color::Green,
+            BoxKind::Danger => Color::Red,
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            BoxKind::Info => "ℹ",
+            BoxKind::Warning => "⚠",
+            BoxKind::Incident => "‼",
+            BoxKind::Success => "✓",
+            BoxKind::Danger => "✖",
+        }
+    }
+
+    /// Anything that isn't routine gets a bold title
+    fn title_style(&self) -> Style {
+        match self {
+            BoxKind::Info | BoxKind::Success => Style::default().fg(self.color()),
+            BoxKind::Warning | BoxKind::Incident | BoxKind::Danger => {
+                Style::default().fg(self.color()).add_modifier(Modifier::BOLD)
+            }
+        }
+    }
+}
+
 /// Display a status box with game information
-pub fn display_box(title: &str, content: &str, term: &mut Terminal) -> io::Result<()> {
+pub fn display_box(title: &str, content: &str, kind: BoxKind, term: &mut Terminal) -> io::Result<()> {
     term.draw(|f| {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -712,7 +1077,465 @@ pub fn display_box(title: &str, content: &str, term: &mut Terminal) -> io::Resul
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(title)
+                    .title(Span::styled(format!(" {} {} ", kind.icon(), title), kind.title_style()))
+                    .border_style(Style::default().fg(kind.color())),
+            )
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(widget, chunks[0]);
+
+        let help = Paragraph::new("Press Enter to continue...")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+
+        f.render_widget(help, chunks[1]);
+    })?;
+
+    wait_for_enter()?;
+    Ok(())
+}
+
+/// Width of the objective progress bars, in cells
+const PROGRESS_BAR_WIDTH: usize = 20;
+
+/// One objective row on the status screen - `at_risk` rows are drawn in red
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectiveLine {
+    pub text: String,
+    pub at_risk: bool,
+}
+
+/// Text progress bar - `[██████░░░░]`, or `[######....]` in ASCII
+pub fn progress_bar(percent: f64, width: usize, charset: Charset) -> String {
+    let filled = ((percent.clamp(0.0, 100.0) / 100.0) * width as f64).round() as usize;
+    let full = charset.glyph('█').to_string();
+    let empty = charset.glyph('░').to_string();
+    format!("[{}{}]", full.repeat(filled), empty.repeat(width - filled))
+}
+
+/// Open objectives, most important first - Critical ones under 50% are flagged,
+/// since that's exactly what the quarterly review punishes
+pub fn objective_summary(objectives: &[Objective], charset: Charset) -> Vec<ObjectiveLine> {
+    let rank = |p: ObjectivePriority| match p {
+        ObjectivePriority::Critical => 0,
+        ObjectivePriority::High => 1,
+        ObjectivePriority::Medium => 2,
+        ObjectivePriority::Low => 3,
+    };
+
+    let mut open: Vec<&Objective> = objectives.iter()
+        .filter(|o| o.completion_turn.is_none())
+        .collect();
+    open.sort_by_key(|o| rank(o.priority));
+
+    open.into_iter()
+        .map(|o| ObjectiveLine {
+            text: format!(
+                "{} {:>3.0}% [{:?}] {}",
+                progress_bar(o.progress, PROGRESS_BAR_WIDTH, charset),
+                o.progress.clamp(0.0, 100.0),
+                o.priority,
+                o.description
+            ),
+            at_risk: o.priority == ObjectivePriority::Critical && o.progress < 50.0,
+        })
+        .collect()
+}
+
+/// How far ahead the status screen's risk outlook looks
+pub const RISK_FORECAST_TURNS: u32 = 3;
+
+/// The headline numbers for the status screen - only ASCII leaves it in ASCII mode
+pub fn status_text(state: &GameState, charset: Charset) -> String {
+    let mut certified: Vec<String> = state
+        .compliance
+        .frameworks
+        .iter()
+        .filter_map(|(framework, status)| {
+            status
+                .certification_date
+                .map(|turn| format!("{:?} (turn {})", framework, turn))
+        })
+        .collect();
+    certified.sort();
+
+    let mut status_text = format!(
+        "CISO: {} | Company: {}\n\
+         ARR: ${:.1}M | Board Confidence: {:.0}% | Integrity: {:.0}%\n\
+         Risk Total: {:.0} | MTTD: {:.1}h | Budget Available: ${:.2}M\n\
+         Customer Churn: {:.1}% (${:.2}M ARR/turn walking out)\n\
+         Certified: {}",
+        state.player.name,
+        state.player.company_name,
+        state.business.arr_millions,
+        state.business.board_confidence_percent,
+        state.narrative.score,
+        state.risk.total_exposure,
+        state.risk.mttd_hours,
+        state.budget.available(),
+        state.business.customer_churn_probability,
+        state.business.churn_loss_per_turn(),
+        if certified.is_empty() { "none".to_string() } else { certified.join(", ") }
+    );
+
+    // Where risk is creeping - only the vectors that move, or are about to blow
+    let forecast = state.risk.forecast(RISK_FORECAST_TURNS);
+    let outlook: Vec<String> = RiskVector::ALL.iter()
+        .filter_map(|vector| {
+            let metric = state.risk.vectors.get(vector)?;
+            let projected = forecast.get(vector).copied()?;
+            let critical = metric.time_to_critical
+                .map(|turns| format!(" ⚠ critical in {} turns", turns))
+                .unwrap_or_default();
+            if (projected - metric.current_level).abs() < 1.0 && critical.is_empty() {
+                return None;
+            }
+            Some(format!(
+                "  {:?} {:.0} → ~{:.0} in {} turns{}",
+                vector, metric.current_level, projected, RISK_FORECAST_TURNS, critical
+            ))
+        })
+        .collect();
+    if !outlook.is_empty() {
+        status_text.push_str(&format!("\nRisk outlook:\n{}", outlook.join("\n")));
+    }
+
+    // One-line ticker - the newest peer breach still in the headlines
+    if let Some(breach) = state.breaches_in_news().first() {
+        status_text.push_str(&format!("\nIndustry news: {} - {}", breach.company, breach.impact));
+    }
+
+    charset.text(&status_text).into_owned()
+}
+
+/// Status box with the quarter's objectives underneath - returns the shortcut pressed, if any
+pub fn display_status_screen(
+    status: &str,
+    objectives: &[ObjectiveLine],
+    exposure_history: &[f64],
+    term: &mut Terminal,
+) -> io::Result<Option<QuickAction>> {
+    let trend: Vec<u64> = exposure_history.iter().map(|e| e.max(0.0).round() as u64).collect();
+
+    let mut lines: Vec<Line> = status.lines().map(|l| Line::from(l.to_string())).collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from("QUARTERLY OBJECTIVES").style(Style::default().add_modifier(Modifier::BOLD)));
+    if objectives.is_empty() {
+        lines.push(Line::from("All objectives met. The board will invent new ones shortly."));
+    }
+    for objective in objectives {
+        let style = if objective.at_risk {
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(objective.text.clone()).style(style));
+    }
+
+    term.draw(|f| {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(5), Constraint::Length(3)])
+            .split(f.area());
+
+        let widget = Paragraph::new(Text::from(lines))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("CURRENT STATUS")
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .wrap(Wrap { trim: true });
+
+        f.render_widget(widget, chunks[0]);
+
+        // Risk rarely jumps - it creeps. The trend shows the creep.
+        let sparkline = Sparkline::default()
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("RISK TREND (last {} turns)", trend.len()))
+                    .border_style(Style::default().fg(Color::Cyan)),
+            )
+            .data(&trend)
+            .style(Style::default().fg(Color::Red));
+
+        f.render_widget(sparkline, chunks[1]);
+
+        let help = Paragraph::new(format!("{} | Enter: continue", quick_action_bar()))
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+
+        f.render_widget(help, chunks[2]);
+    })?;
+
+    wait_for_quick_action()
+}
+
+/// Widest a vector name gets on the heatmap before it's cut - keeps 80 columns honest
+pub const HEATMAP_LABEL_WIDTH: usize = 14;
+/// Bars never shrink below this, however narrow the terminal
+const HEATMAP_MIN_BAR_WIDTH: usize = 10;
+/// Room taken by borders, gaps, brackets and the " 100 cov 100%" tail
+const HEATMAP_CHROME_WIDTH: usize = 2 + 1 + 2 + 13;
+
+/// One vector on the heatmap - the bar is `█` for exposure, `▓` where mitigation covers it
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeatmapRow {
+    pub label: String,
+    pub bar: String,
+    pub detail: String,
+    pub level: f64,
+}
+
+/// Green under 40, yellow under 60, orange under 80, red from there on
+pub fn risk_heat_color(level: f64) -> Color {
+    match level {
+        l if l >= 80.0 => Color::Red,
+        l if l >= 60.0 => Color::Rgb(255, 140, 0),
+        l if l >= 40.0 => Color::Yellow,
+        _ => Color::Green,
+    }
+}
+
+/// Cut a label to `width` characters, marking the cut with `…`
+fn truncate_label(label: &str, width: usize) -> String {
+    if label.chars().count() <= width {
+        return format!("{:<width$}", label);
+    }
+    let kept: String = label.chars().take(width.saturating_sub(1)).collect();
+    format!("{}…", kept)
+}
+
+/// Every risk vector as a bar sized to fit a `width`-column terminal
+pub fn risk_heatmap_rows(state: &GameState, width: usize) -> Vec<HeatmapRow> {
+    let bar_width = width
+        .saturating_sub(HEATMAP_LABEL_WIDTH + HEATMAP_CHROME_WIDTH)
+        .max(HEATMAP_MIN_BAR_WIDTH);
+
+    RiskVector::ALL.iter()
+        .map(|vector| {
+            let (level, coverage) = state.risk.vectors.get(vector)
+                .map(|m| (m.current_level.clamp(0.0, 100.0), m.mitigation_coverage.clamp(0.0, 100.0)))
+                .unwrap_or((0.0, 0.0));
+            let filled = ((level / 100.0) * bar_width as f64).round() as usize;
+            let covered = ((coverage / 100.0) * filled as f64).round() as usize;
+
+            HeatmapRow {
+                label: truncate_label(&format!("{:?}", vector), HEATMAP_LABEL_WIDTH),
+                bar: format!("[{}{}{}]", "▓".repeat(covered), "█".repeat(filled - covered), "░".repeat(bar_width - filled)),
+                detail: format!("{:>4.0} cov {:>3.0}%", level, coverage),
+                level,
+            }
+        })
+        .collect()
+}
+
+/// All eight vectors at a glance, coloured by how hot they run - Enter to go back
+pub fn display_risk_heatmap(state: &GameState, term: &mut Terminal) -> io::Result<()> {
+    let rows = risk_heatmap_rows(state, term.width());
+    let mut lines: Vec<Line> = rows.into_iter()
+        .map(|row| Line::from(vec![
+            Span::raw(format!("{} ", row.label)),
+            Span::styled(row.bar, Style::default().fg(risk_heat_color(row.level))),
+            Span::raw(row.detail),
+        ]))
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from("▓ mitigated  █ exposed  ░ headroom").style(Style::default().fg(Color::DarkGray)));
+    lines.push(Line::from(format!(
+        "Cascade multiplier: x{:.2} | Total exposure: {:.0}",
+        state.risk.cascade_multiplier, state.risk.total_exposure
+    )).style(Style::default().add_modifier(Modifier::BOLD)));
+
+    term.draw(|f| {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(3)])
+            .split(f.area());
+
+        let widget = Paragraph::new(Text::from(lines))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("RISK HEATMAP")
+                    .border_style(Style::default().fg(Color::Cyan)),
+            );
+
+        f.render_widget(widget, chunks[0]);
+
+        let help = Paragraph::new("Press Enter to continue...")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(Alignment::Center);
+
+        f.render_widget(help, chunks[1]);
+    })?;
+
+    wait_for_enter()
+}
+
+/// Short tag for who could see a timeline entry
+pub fn visibility_badge(visibility: EventVisibility) -> &'static str {
+    match visibility {
+        EventVisibility::Internal => "[INTERNAL]",
+        EventVisibility::Management => "[C-SUITE] ",
+        EventVisibility::Board => "[BOARD]   ",
+        EventVisibility::Public => "[PUBLIC]  ",
+        EventVisibility::Buried => "[BURIED]  ",
+    }
+}
+
+fn visibility_color(visibility: EventVisibility) -> Color {
+    match visibility {
+        EventVisibility::Internal => Color::Gray,
+        EventVisibility::Management => Color::Cyan,
+        EventVisibility::Board => Color::Yellow,
+        EventVisibility::Public => Color::Green,
+        EventVisibility::Buried => Color::Red,
+    }
+}
+
+/// Next stop when cycling the log filter - everything, then each audience in turn, then everything again
+pub fn next_log_filter(filter: Option<EventVisibility>) -> Option<EventVisibility> {
+    match filter {
+        None => Some(EventVisibility::Internal),
+        Some(EventVisibility::Internal) => Some(EventVisibility::Management),
+        Some(EventVisibility::Management) => Some(EventVisibility::Board),
+        Some(EventVisibility::Board) => Some(EventVisibility::Public),
+        Some(EventVisibility::Public) => Some(EventVisibility::Buried),
+        Some(EventVisibility::Buried) => None,
+    }
+}
+
+/// The event log newest-first, badged by visibility - only the level `filter` names, or all of it
+pub fn event_log_lines(events: &[StateEvent], filter: Option<EventVisibility>) -> Vec<(String, EventVisibility)> {
+    events.iter()
+        .rev()
+        .filter(|e| filter.is_none_or(|v| e.visibility == v))
+        .map(|e| (
+            format!("Turn {:>2} {} {:?}: {}", e.turn, visibility_badge(e.visibility), e.event_type, e.description),
+            e.visibility,
+        ))
+        .collect()
+}
+
+/// Everything that happened this run, scrollable - `v` cycles which audience's events are shown
+pub fn display_event_log(state: &GameState, term: &mut Terminal) -> io::Result<()> {
+    let mut filter: Option<EventVisibility> = None;
+    let mut scroll: u16 = 0;
+
+    loop {
+        let entries = event_log_lines(&state.events, filter);
+        let size = term.terminal.size()?;
+        let max_scroll = max_scroll_for(entries.len(), size.height as usize);
+        scroll = scroll.min(max_scroll.min(u16::MAX as usize) as u16);
+
+        let shown = filter.map(|v| format!("{:?} only", v)).unwrap_or_else(|| "all".to_string());
+        let title = format!("EVENT LOG ({} - {} events)", shown, entries.len());
+        let lines: Vec<Line> = if entries.is_empty() {
+            vec![Line::from("Nothing logged at this level. Yet.")]
+        } else {
+            entries.into_iter()
+                .map(|(text, visibility)| {
+                    let style = Style::default().fg(visibility_color(visibility));
+                    // Buried entries are the ones you'll be asked about later
+                    let style = if visibility == EventVisibility::Buried { style.add_modifier(Modifier::BOLD) } else { style };
+                    Line::from(text).style(style)
+                })
+                .collect()
+        };
+
+        term.draw(|f| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(1), Constraint::Length(3)])
+                .split(f.area());
+
+            let paragraph = Paragraph::new(Text::from(lines))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(title)
+                        .border_style(Style::default().fg(Color::Cyan)),
+                )
+                .scroll((scroll, 0));
+
+            f.render_widget(paragraph, chunks[0]);
+
+            let help = Paragraph::new("↑↓ to scroll | v: cycle visibility | Enter to continue")
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(Color::DarkGray));
+
+            f.render_widget(help, chunks[1]);
+        })?;
+
+        if let Event::Key(KeyEvent { code, kind: KeyEventKind::Press, .. }) = event::read()? {
+            match code {
+                KeyCode::Enter | KeyCode::Char('q') => break,
+                KeyCode::Char('v') | KeyCode::Char('V') => {
+                    filter = next_log_filter(filter);
+                    scroll = 0;
+                }
+                code => {
+                    scroll_with_key(code, &mut scroll, max_scroll);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Timeline entries in turn order, each badged with who knew - the last line
+/// counts what never made it past the security team
+pub fn incident_timeline_lines(incident: &ActiveIncident) -> Vec<(String, EventVisibility)> {
+    let mut entries: Vec<&IncidentTimelineEntry> = incident.timeline.iter().collect();
+    entries.sort_by_key(|e| e.turn);
+
+    let mut lines: Vec<(String, EventVisibility)> = entries.iter()
+        .map(|e| (
+            format!("Turn {:>2} {} {} - {}", e.turn, visibility_badge(e.visibility), e.action, e.actor),
+            e.visibility,
+        ))
+        .collect();
+
+    let hidden = entries.iter()
+        .filter(|e| matches!(e.visibility, EventVisibility::Internal | EventVisibility::Buried))
+        .count();
+    if hidden > 0 {
+        lines.push((
+            format!("{} of {} entries never reached management or the board.", hidden, entries.len()),
+            EventVisibility::Internal,
+        ));
+    }
+    lines
+}
+
+/// What happened versus who was told - one incident's history
+pub fn display_incident_timeline(incident: &ActiveIncident, term: &mut Terminal) -> io::Result<()> {
+    let mut lines = vec![
+        Line::from(format!("{} [{:?}]", incident.description, incident.severity)),
+        Line::from(""),
+    ];
+    let entries = incident_timeline_lines(incident);
+    if entries.is_empty() {
+        lines.push(Line::from("No timeline recorded. Nobody wrote anything down - which will also be noticed."));
+    }
+    for (text, visibility) in entries {
+        lines.push(Line::from(text).style(Style::default().fg(visibility_color(visibility))));
+    }
+
+    term.draw(|f| {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(3)])
+            .split(f.area());
+
+        let widget = Paragraph::new(Text::from(lines))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("INCIDENT TIMELINE: {}", incident.title))
                     .border_style(Style::default().fg(Color::Cyan)),
             )
             .wrap(Wrap { trim: true });
@@ -730,6 +1553,29 @@ pub fn display_box(title: &str, content: &str, term: &mut Terminal) -> io::Resul
     Ok(())
 }
 
+/// Look up security jargon - partial matches welcome
+pub fn display_glossary(query: &str, term: &mut Terminal) -> io::Result<()> {
+    let terms: Vec<&str> = glossary_lookup(query).into_iter().map(|(t, _)| t).collect();
+    if terms.is_empty() {
+        return display_box("GLOSSARY", &format!("No entry for '{}'.", query), BoxKind::Info, term);
+    }
+    display_glossary_entries(&terms, term)
+}
+
+fn display_glossary_entries(terms: &[&str], term: &mut Terminal) -> io::Result<()> {
+    let definitions = glossary();
+    let content = if terms.is_empty() {
+        "No jargon here. Enjoy it while it lasts.".to_string()
+    } else {
+        terms.iter()
+            .filter_map(|t| definitions.get(t).map(|d| format!("{}\n  {}", t, d)))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    };
+
+    display_box("GLOSSARY", &content, BoxKind::Info, term)
+}
+
 /// Display chapter/turn header
 pub fn display_chapter_header(
     turn: u32,
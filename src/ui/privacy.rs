@@ -0,0 +1,63 @@
+use std::borrow::Cow;
+
+use crate::core::state::GameState;
+
+/// Environment variable that turns on privacy mode at launch
+pub const PRIVACY_MODE_ENV: &str = "CISO_PRIVACY_MODE";
+
+/// Streamer-safe display - the player's name only ever appears as initials
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PrivacyMode {
+    pub enabled: bool,
+}
+
+impl PrivacyMode {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    /// On when `CISO_PRIVACY_MODE` is set to anything but empty, `0`, or `false`
+    pub fn from_env() -> Self {
+        let enabled = std::env::var(PRIVACY_MODE_ENV)
+            .map(|v| !matches!(v.trim().to_ascii_lowercase().as_str(), "" | "0" | "false"))
+            .unwrap_or(false);
+
+        Self { enabled }
+    }
+
+    /// The name as it should appear on screen
+    pub fn display_name(&self, name: &str) -> String {
+        if self.enabled { initials(name) } else { name.to_string() }
+    }
+
+    /// The state as it should appear on screen - a redacted copy only when needed
+    pub fn redact<'a>(&self, state: &'a GameState) -> Cow<'a, GameState> {
+        if !self.enabled {
+            return Cow::Borrowed(state);
+        }
+
+        let mut shown = state.clone();
+        let name = state.player.name.as_str();
+        let masked = initials(name);
+        shown.player.name = masked.clone();
+        if !name.trim().is_empty() {
+            for incident in &mut shown.active_incidents {
+                for entry in &mut incident.timeline {
+                    entry.actor = entry.actor.replace(name, &masked);
+                }
+            }
+        }
+        Cow::Owned(shown)
+    }
+}
+
+/// "Jane van der Berg" -> "J.V.D.B." - one letter per word, `?` if there's nothing to go on
+pub fn initials(name: &str) -> String {
+    let letters: String = name
+        .split_whitespace()
+        .filter_map(|word| word.chars().find(|c| c.is_alphanumeric()))
+        .flat_map(|c| c.to_uppercase().chain(std::iter::once('.')))
+        .collect();
+
+    if letters.is_empty() { "?".to_string() } else { letters }
+}